@@ -17,6 +17,10 @@
  *
  ***************************************************************************/
 
+mod posix_port;
+
+pub use posix_port::{FreeRtosPosixPort, FREERTOS_KERNEL_PATH};
+
 use std::env;
 use std::fs;
 use std::path::PathBuf;
@@ -25,6 +29,37 @@ use std::process::Command;
 pub struct FreeRtosTypeGenerator {
     out_dir: PathBuf,
     config_path: Option<PathBuf>,
+    include_dirs: Vec<PathBuf>,
+}
+
+/// Sizes in bytes of the buffers backing statically allocated kernel objects
+struct StaticSizes {
+    task: usize,
+    queue: usize,
+    event_group: usize,
+    timer: usize,
+}
+
+/// Size in bytes of a FreeRTOS type and the Rust type it maps to
+struct TypeMapping {
+    size: u16,
+    rust_type: &'static str,
+}
+
+/// Mappings of `TickType_t`, `UBaseType_t`, `BaseType_t` and `StackType_t`
+struct TypeMappings {
+    tick: TypeMapping,
+    ubase: TypeMapping,
+    base: TypeMapping,
+    stack: TypeMapping,
+}
+
+/// Same values as `OSAL_RS_STATIC_*_SIZE` in `osal_rs_freertos.h`, where the
+/// control blocks of the target are checked against them
+impl Default for StaticSizes {
+    fn default() -> Self {
+        Self { task: 512, queue: 256, event_group: 128, timer: 128 }
+    }
 }
 
 impl FreeRtosTypeGenerator {
@@ -33,6 +68,7 @@ impl FreeRtosTypeGenerator {
         Self { 
             out_dir,
             config_path: None,
+            include_dirs: Vec::new(),
         }
     }
 
@@ -42,9 +78,16 @@ impl FreeRtosTypeGenerator {
         Self {
             out_dir,
             config_path: Some(config_path.into()),
+            include_dirs: Vec::new(),
         }
     }
 
+    /// Add a header directory of the FreeRTOS build: when `FreeRTOS.h` can be
+    /// compiled, the type sizes are read from it instead of assumed
+    pub fn add_include_dir<P: Into<PathBuf>>(&mut self, dir: P) {
+        self.include_dirs.push(dir.into());
+    }
+
     /// Set the FreeRTOSConfig.h path
     pub fn set_config_path<P: Into<PathBuf>>(&mut self, config_path: P) {
         self.config_path = Some(config_path.into());
@@ -52,18 +95,19 @@ impl FreeRtosTypeGenerator {
 
     /// Query FreeRTOS type sizes and generate Rust type mappings
     pub fn generate_types(&self) {
-        let (tick_size, ubase_size, base_size, base_signed, stack_size) = self.query_type_sizes();
+        let (tick_size, ubase_size, base_size, base_signed, stack_size, static_sizes) = self.query_type_sizes();
         
-        let tick_type = Self::size_to_type(tick_size, false);
-        let ubase_type = Self::size_to_type(ubase_size, false);
-        let base_type = Self::size_to_type(base_size, base_signed);
-        let stack_type = Self::size_to_type(stack_size, true);
+        let types = TypeMappings {
+            tick: TypeMapping { size: tick_size, rust_type: Self::size_to_type(tick_size, false) },
+            ubase: TypeMapping { size: ubase_size, rust_type: Self::size_to_type(ubase_size, false) },
+            base: TypeMapping { size: base_size, rust_type: Self::size_to_type(base_size, base_signed) },
+            stack: TypeMapping { size: stack_size, rust_type: Self::size_to_type(stack_size, true) },
+        };
 
-        
-        self.write_generated_types(tick_size, tick_type, ubase_size, ubase_type, base_size, base_type, stack_size, stack_type);
+        self.write_generated_types(&types, &static_sizes);
         
         println!("cargo:warning=Generated FreeRTOS types: TickType={}, UBaseType={}, BaseType={} StackType={}", 
-                 tick_type, ubase_type, base_type, stack_type);
+                 types.tick.rust_type, types.ubase.rust_type, types.base.rust_type, types.stack.rust_type);
     }
 
     /// Query FreeRTOS configuration values and generate Rust constants
@@ -83,7 +127,7 @@ impl FreeRtosTypeGenerator {
     }
 
     /// Query the sizes of FreeRTOS types
-    fn query_type_sizes(&self) -> (u16, u16, u16, bool, u16) {
+    fn query_type_sizes(&self) -> (u16, u16, u16, bool, u16, StaticSizes) {
         // Create a small C program to query the type sizes
         let query_program = r#"
 #include <stdio.h>
@@ -109,21 +153,67 @@ int main() {
     printf("BASE_TYPE_SIZE=%d\n", 4);
     printf("BASE_TYPE_SIGNED=1\n");
     printf("STACK_TYPE_SIZE=%d\n", 4);
+
+    // The static buffers are opaque to Rust: oversized, so that any
+    // 32-bit configuration fits in them. osal_rs_freertos.c, compiled with
+    // the target headers, asserts that the control blocks fit
+    printf("STATIC_TASK_SIZE=%d\n", 512);
+    printf("STATIC_QUEUE_SIZE=%d\n", 256);
+    printf("STATIC_EVENT_GROUP_SIZE=%d\n", 128);
+    printf("STATIC_TIMER_SIZE=%d\n", 128);
     
     return 0;
 }
 "#;
         
+        // Sizes of the actual port, for a kernel compiled on this host
+        let header_query_program = r#"
+#include <stdio.h>
+#include "FreeRTOS.h"
+
+int main() {
+    printf("TICK_TYPE_SIZE=%d\n", (int)sizeof(TickType_t));
+    printf("UBASE_TYPE_SIZE=%d\n", (int)sizeof(UBaseType_t));
+    printf("BASE_TYPE_SIZE=%d\n", (int)sizeof(BaseType_t));
+    printf("BASE_TYPE_SIGNED=%d\n", (BaseType_t)-1 < 0);
+    printf("STACK_TYPE_SIZE=%d\n", (int)sizeof(StackType_t));
+    printf("STATIC_TASK_SIZE=%d\n", (int)sizeof(StaticTask_t));
+    printf("STATIC_QUEUE_SIZE=%d\n", (int)sizeof(StaticQueue_t));
+    printf("STATIC_EVENT_GROUP_SIZE=%d\n", (int)sizeof(StaticEventGroup_t));
+    printf("STATIC_TIMER_SIZE=%d\n", (int)sizeof(StaticTimer_t));
+
+    return 0;
+}
+"#;
+
         let query_c = self.out_dir.join("query_types.c");
-        fs::write(&query_c, query_program).expect("Failed to write query program");
-        
-        // Compile the query program
         let query_exe = self.out_dir.join("query_types");
-        let compile_status = Command::new("gcc")
-            .arg(&query_c)
-            .arg("-o")
-            .arg(&query_exe)
-            .status();
+
+        let mut compile_status = Err(std::io::Error::other("no FreeRTOS headers"));
+        if !self.include_dirs.is_empty() {
+            fs::write(&query_c, header_query_program).expect("Failed to write query program");
+            compile_status = Command::new("gcc")
+                .args(self.include_dirs.iter().map(|dir| format!("-I{}", dir.display())))
+                .arg(&query_c)
+                .arg("-o")
+                .arg(&query_exe)
+                .status();
+
+            if !matches!(compile_status, Ok(status) if status.success()) {
+                println!("cargo:warning=Failed to compile against the FreeRTOS headers, using default type sizes");
+            }
+        }
+
+        if !matches!(compile_status, Ok(status) if status.success()) {
+            fs::write(&query_c, query_program).expect("Failed to write query program");
+
+            // Compile the query program
+            compile_status = Command::new("gcc")
+                .arg(&query_c)
+                .arg("-o")
+                .arg(&query_exe)
+                .status();
+        }
         
         if compile_status.is_ok() && compile_status.unwrap().success() {
             // Run the query program
@@ -137,6 +227,7 @@ int main() {
             let mut base_size = 4u16;
             let mut base_signed = true;
             let mut stack_type = 4u16;
+            let mut static_sizes = StaticSizes::default();
             
             for line in stdout.lines() {
                 if let Some(val) = line.strip_prefix("TICK_TYPE_SIZE=") {
@@ -149,13 +240,21 @@ int main() {
                     base_signed = val.parse::<u8>().unwrap_or(1) == 1;
                 } else if let Some(val) = line.strip_prefix("STACK_TYPE_SIZE=") {
                     stack_type = val.parse().unwrap_or(4);
-                } 
+                } else if let Some(val) = line.strip_prefix("STATIC_TASK_SIZE=") {
+                    static_sizes.task = val.parse().unwrap_or(static_sizes.task);
+                } else if let Some(val) = line.strip_prefix("STATIC_QUEUE_SIZE=") {
+                    static_sizes.queue = val.parse().unwrap_or(static_sizes.queue);
+                } else if let Some(val) = line.strip_prefix("STATIC_EVENT_GROUP_SIZE=") {
+                    static_sizes.event_group = val.parse().unwrap_or(static_sizes.event_group);
+                } else if let Some(val) = line.strip_prefix("STATIC_TIMER_SIZE=") {
+                    static_sizes.timer = val.parse().unwrap_or(static_sizes.timer);
+                }
             }
             
-            (tick_size, ubase_size, base_size, base_signed, stack_type)
+            (tick_size, ubase_size, base_size, base_signed, stack_type, static_sizes)
         } else {
             // Default values for 32-bit ARM Cortex-M (typical for Raspberry Pi Pico)
-            (4, 4, 4, true, 4)
+            (4, 4, 4, true, 4, StaticSizes::default())
         }
     }
 
@@ -277,17 +376,7 @@ int main() {
     }
 
     /// Write the generated types to a file
-    fn write_generated_types(
-        &self,
-        tick_size: u16,
-        tick_type: &str,
-        ubase_size: u16,
-        ubase_type: &str,
-        base_size: u16,
-        base_type: &str,
-        stack_size: u16,
-        stack_type: &str,
-    ) {
+    fn write_generated_types(&self, types: &TypeMappings, static_sizes: &StaticSizes) {
         let generated_code = format!(r#"
 // Auto-generated by build.rs - DO NOT EDIT MANUALLY
 // This file contains FreeRTOS type mappings based on the actual type sizes
@@ -303,15 +392,25 @@ pub type UBaseType = {};
 pub type BaseType = {};
 pub type StackType = {};
 
+// Sizes in bytes of StaticTask_t, StaticQueue_t, StaticEventGroup_t and StaticTimer_t
+pub const STATIC_TASK_SIZE: usize = {};
+pub const STATIC_QUEUE_SIZE: usize = {};
+pub const STATIC_EVENT_GROUP_SIZE: usize = {};
+pub const STATIC_TIMER_SIZE: usize = {};
+
 "#,
-            tick_size, tick_type,
-            ubase_size, ubase_type,
-            base_size, base_type,
-            stack_size, stack_type,
-            tick_type,
-            ubase_type,
-            base_type,
-            stack_type
+            types.tick.size, types.tick.rust_type,
+            types.ubase.size, types.ubase.rust_type,
+            types.base.size, types.base.rust_type,
+            types.stack.size, types.stack.rust_type,
+            types.tick.rust_type,
+            types.ubase.rust_type,
+            types.base.rust_type,
+            types.stack.rust_type,
+            static_sizes.task,
+            static_sizes.queue,
+            static_sizes.event_group,
+            static_sizes.timer
         );
         
         let types_rs = self.out_dir.join("types_generated.rs");
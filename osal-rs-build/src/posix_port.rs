@@ -0,0 +1,134 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Environment variable pointing to a FreeRTOS-Kernel checkout
+pub const FREERTOS_KERNEL_PATH: &str = "FREERTOS_KERNEL_PATH";
+
+/// Kernel sources compiled for the simulator, relative to the kernel root
+const KERNEL_SOURCES: &[&str] = &[
+    "tasks.c",
+    "queue.c",
+    "list.c",
+    "timers.c",
+    "event_groups.c",
+    "stream_buffer.c",
+    "portable/MemMang/heap_4.c",
+    "portable/ThirdParty/GCC/Posix/port.c",
+    "portable/ThirdParty/GCC/Posix/utils/wait_for_event.c",
+];
+
+/// Builds the FreeRTOS-Kernel `Posix_GCC` simulator port, so that the
+/// FreeRTOS backend can run as a native Linux process.
+///
+/// The kernel is taken from `FREERTOS_KERNEL_PATH` or, if unset, from
+/// `osal-rs-porting/FreeRTOS-Kernel`. It is compiled with the sample
+/// `FreeRTOSConfig.h` of `osal-rs-porting/freeretos/posix` together with
+/// the osal-rs porting layer.
+pub struct FreeRtosPosixPort {
+    kernel_path: PathBuf,
+    porting_path: PathBuf,
+}
+
+impl FreeRtosPosixPort {
+    /// Looks for the kernel sources, `workspace_root` is the osal-rs
+    /// repository. Returns `None` if no kernel checkout is found.
+    pub fn locate(workspace_root: &Path) -> Option<Self> {
+        println!("cargo:rerun-if-env-changed={}", FREERTOS_KERNEL_PATH);
+
+        let kernel_path = env::var_os(FREERTOS_KERNEL_PATH)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| workspace_root.join("osal-rs-porting/FreeRTOS-Kernel"));
+        let porting_path = workspace_root.join("osal-rs-porting/freeretos");
+
+        if !kernel_path.join("tasks.c").exists() || !porting_path.exists() {
+            return None;
+        }
+        Some(Self { kernel_path, porting_path })
+    }
+
+    /// Header directories of the kernel, the port, its configuration and
+    /// the porting layer
+    pub fn include_dirs(&self) -> Vec<PathBuf> {
+        vec![
+            self.kernel_path.join("include"),
+            self.kernel_path.join("portable/ThirdParty/GCC/Posix"),
+            self.kernel_path.join("portable/ThirdParty/GCC/Posix/utils"),
+            self.porting_path.join("posix/inc"),
+            self.porting_path.join("inc"),
+        ]
+    }
+
+    /// Compiles the kernel and the porting layer into a static library in
+    /// `OUT_DIR` and tells cargo to link it, with pthreads
+    pub fn compile(&self) {
+        let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set"));
+        let compiler = env::var("CC").unwrap_or_else(|_| "gcc".to_string());
+
+        let sources: Vec<PathBuf> = KERNEL_SOURCES
+            .iter()
+            .map(|source| self.kernel_path.join(source))
+            .chain([
+                self.porting_path.join("src/osal_rs_freertos.c"),
+                self.porting_path.join("posix/src/osal_rs_posix_port.c"),
+            ])
+            .collect();
+
+        let mut objects = Vec::with_capacity(sources.len());
+        for source in &sources {
+            println!("cargo:rerun-if-changed={}", source.display());
+
+            let stem = source.file_stem().expect("Invalid source file name");
+            let object = out_dir.join(stem).with_extension("o");
+            let mut command = Command::new(&compiler);
+            command.args(["-c", "-g", "-O1", "-pthread", "-fPIC"]);
+            for dir in self.include_dirs() {
+                command.arg("-I").arg(dir);
+            }
+            command.arg(source).arg("-o").arg(&object);
+
+            let status = command.status().expect("Failed to run the C compiler");
+            if !status.success() {
+                panic!("Failed to compile {}", source.display());
+            }
+            objects.push(object);
+        }
+        println!("cargo:rerun-if-changed={}", self.porting_path.join("posix/inc/FreeRTOSConfig.h").display());
+
+        let library = out_dir.join("libfreertos_posix.a");
+        let _ = fs::remove_file(&library);
+        let status = Command::new("ar")
+            .arg("crs")
+            .arg(&library)
+            .args(&objects)
+            .status()
+            .expect("Failed to run ar");
+        if !status.success() {
+            panic!("Failed to archive {}", library.display());
+        }
+
+        println!("cargo:rustc-link-search=native={}", out_dir.display());
+        // The kernel is referenced by osal-rs, linked after this archive
+        println!("cargo:rustc-link-lib=static:+whole-archive=freertos_posix");
+        println!("cargo:rustc-link-lib=pthread");
+    }
+}
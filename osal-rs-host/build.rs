@@ -0,0 +1,46 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use osal_rs_build::{FREERTOS_KERNEL_PATH, FreeRtosPosixPort};
+use std::env;
+use std::path::PathBuf;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo::rustc-check-cfg=cfg(freertos_kernel)");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+    let workspace_root = PathBuf::from(manifest_dir)
+        .parent()
+        .expect("Failed to find workspace root")
+        .to_path_buf();
+
+    match FreeRtosPosixPort::locate(&workspace_root) {
+        Some(port) => {
+            port.compile();
+            println!("cargo:rustc-cfg=freertos_kernel");
+        }
+        None => println!("cargo:warning=FreeRTOS-Kernel not found, set {} to run the test suite", FREERTOS_KERNEL_PATH),
+    }
+
+    // no_std binary: link the C runtime, and the unwinder referenced by the
+    // precompiled core library
+    println!("cargo:rustc-link-lib=c");
+    println!("cargo:rustc-link-lib=gcc_s");
+}
@@ -0,0 +1,100 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+//! Native runner of `osal_rs_tests::freertos::run_all_tests` on the
+//! FreeRTOS-Kernel Posix_GCC simulator port: the exit status is 0 when the
+//! whole suite passes. The first argument, when given, only runs the tests
+//! whose name contains it.
+
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use core::ffi::{c_char, c_int};
+
+unsafe extern "C" {
+    #[cfg(not(freertos_kernel))]
+    fn printf(format: *const c_char, ...) -> c_int;
+
+    #[cfg(freertos_kernel)]
+    fn exit(status: c_int) -> !;
+}
+
+#[cfg(freertos_kernel)]
+const TAG: &str = "Host";
+
+/// The suite runs in a task, below the threads spawned by the tests
+#[cfg(freertos_kernel)]
+const RUNNER_PRIORITY: u32 = 1;
+
+#[cfg(freertos_kernel)]
+const RUNNER_STACK_DEPTH: osal_rs::os::types::StackType = 16 * 1024;
+
+#[cfg(freertos_kernel)]
+#[unsafe(no_mangle)]
+pub extern "C" fn main(argc: c_int, argv: *const *const c_char) -> c_int {
+    use core::ffi::CStr;
+    use osal_rs::log_error;
+    use osal_rs::os::{SystemFn, System, Thread, ThreadFn};
+
+    // argv outlives the scheduler
+    let filter: Option<&'static str> = if argc > 1 {
+        unsafe { CStr::from_ptr(*argv.add(1)) }.to_str().ok()
+    } else {
+        None
+    };
+
+    let mut runner = Thread::new("tests", RUNNER_STACK_DEPTH, RUNNER_PRIORITY);
+    let spawned = runner.spawn_simple(move || {
+        let status = match osal_rs_tests::freertos::run_tests(filter) {
+            Ok(()) => 0,
+            Err(error) => {
+                log_error!(TAG, "Test suite failed: {:?}", error);
+                1
+            }
+        };
+        unsafe { exit(status) }
+    });
+
+    if spawned.is_err() {
+        log_error!(TAG, "Failed to create the test task");
+        return 1;
+    }
+
+    System::start();
+    // The scheduler only returns if it could not start
+    1
+}
+
+#[cfg(not(freertos_kernel))]
+#[unsafe(no_mangle)]
+pub extern "C" fn main() -> c_int {
+    unsafe {
+        printf(c"osal-rs-host was built without FreeRTOS-Kernel: set FREERTOS_KERNEL_PATH and rebuild\n".as_ptr());
+    }
+    2
+}
+
+// Panics abort, the personality routine is never called
+#[unsafe(no_mangle)]
+pub extern "C" fn rust_eh_personality() {}
+
+// Keeps the panic handler and the allocator of osal-rs in the build
+use osal_rs as _;
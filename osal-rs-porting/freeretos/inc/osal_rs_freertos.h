@@ -26,6 +26,15 @@
 #include "timers.h"
 #include "task.h"
 
+/* Storage that osal-rs reserves for the static control blocks when its build
+ * cannot compile FreeRTOS.h, as for every cross build: keep these in sync with
+ * StaticSizes in osal-rs-build. The buffers are aligned on 8 bytes. */
+#define OSAL_RS_STATIC_TASK_SIZE        512
+#define OSAL_RS_STATIC_QUEUE_SIZE       256
+#define OSAL_RS_STATIC_EVENT_GROUP_SIZE 128
+#define OSAL_RS_STATIC_TIMER_SIZE       128
+#define OSAL_RS_STATIC_ALIGN            8
+
 void osal_rs_critical_section_enter(void);
 
 void osal_rs_critical_section_exit(void);
@@ -48,5 +57,6 @@ TickType_t osal_rs_config_tick_rate_hz(void);
 uint32_t osal_rs_config_max_priorities(void);
 StackType_t osal_rs_config_minimal_stack_size(void);
 uint32_t osal_rs_config_max_task_name_len(void);
+uint32_t osal_rs_config_task_notification_array_entries(void);
 
 #endif /* OSAL_RS_FREERTOS_H */
\ No newline at end of file
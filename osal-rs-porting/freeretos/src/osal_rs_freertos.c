@@ -22,6 +22,19 @@
 #include <stdarg.h>
 #include <stdio.h>
 
+/* The Static* types of osal-rs hand these buffers to the *CreateStatic APIs:
+ * a control block that does not fit would be written past their end */
+#if configSUPPORT_STATIC_ALLOCATION == 1
+_Static_assert(sizeof(StaticTask_t) <= OSAL_RS_STATIC_TASK_SIZE, "StaticTask_t does not fit in StaticTaskBuffer");
+_Static_assert(sizeof(StaticQueue_t) <= OSAL_RS_STATIC_QUEUE_SIZE, "StaticQueue_t does not fit in StaticQueueBuffer");
+_Static_assert(sizeof(StaticEventGroup_t) <= OSAL_RS_STATIC_EVENT_GROUP_SIZE, "StaticEventGroup_t does not fit in StaticEventGroupBuffer");
+_Static_assert(sizeof(StaticTimer_t) <= OSAL_RS_STATIC_TIMER_SIZE, "StaticTimer_t does not fit in StaticTimerBuffer");
+_Static_assert(_Alignof(StaticTask_t) <= OSAL_RS_STATIC_ALIGN, "StaticTask_t is aligned beyond StaticTaskBuffer");
+_Static_assert(_Alignof(StaticQueue_t) <= OSAL_RS_STATIC_ALIGN, "StaticQueue_t is aligned beyond StaticQueueBuffer");
+_Static_assert(_Alignof(StaticEventGroup_t) <= OSAL_RS_STATIC_ALIGN, "StaticEventGroup_t is aligned beyond StaticEventGroupBuffer");
+_Static_assert(_Alignof(StaticTimer_t) <= OSAL_RS_STATIC_ALIGN, "StaticTimer_t is aligned beyond StaticTimerBuffer");
+#endif
+
 void osal_rs_critical_section_enter(void)
 {
     taskENTER_CRITICAL();
@@ -103,4 +116,9 @@ uint32_t osal_rs_config_max_task_name_len(void)
     return configMAX_TASK_NAME_LEN;
 }
 
+uint32_t osal_rs_config_task_notification_array_entries(void)
+{
+    return configTASK_NOTIFICATION_ARRAY_ENTRIES;
+}
+
 StackType_t MINIMAL_STACK_SIZE = configMINIMAL_STACK_SIZE;
\ No newline at end of file
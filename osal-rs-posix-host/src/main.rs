@@ -0,0 +1,58 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+//! Native runner of `osal_rs_tests::posix::run_all_tests`: the exit status
+//! is 0 when the whole suite passes. The first argument, when given, only
+//! runs the tests whose name contains it. Built with `virtual_time`, the
+//! suite runs on the virtual clock.
+
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use core::ffi::{CStr, c_char, c_int};
+
+use osal_rs::log_error;
+
+const TAG: &str = "PosixHost";
+
+#[unsafe(no_mangle)]
+extern "C" fn main(argc: c_int, argv: *const *const c_char) -> c_int {
+    let filter: Option<&str> = if argc > 1 {
+        unsafe { CStr::from_ptr(*argv.add(1)) }.to_str().ok()
+    } else {
+        None
+    };
+
+    match osal_rs_tests::posix::run_tests(filter) {
+        Ok(()) => 0,
+        Err(error) => {
+            log_error!(TAG, "Test suite failed: {:?}", error);
+            1
+        }
+    }
+}
+
+// Panics abort, the personality routine is never called
+#[unsafe(no_mangle)]
+pub extern "C" fn rust_eh_personality() {}
+
+// Keeps the panic handler and the allocator of osal-rs in the build
+use osal_rs as _;
@@ -0,0 +1,359 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use osal_rs::os::*;
+use osal_rs::os::types::EventBits;
+use osal_rs::utils::{Error, OsalRsBool, Result};
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::time::Duration;
+use osal_rs::{log_debug, log_info};
+
+use super::Backend;
+
+const TAG: &str = "EventGroupTests";
+
+const BIT_0: EventBits = 1 << 0;
+const BIT_1: EventBits = 1 << 1;
+const BIT_2: EventBits = 1 << 2;
+const BIT_3: EventBits = 1 << 3;
+
+pub fn test_event_group_creation<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_creation");
+    let event_group = B::EventGroup::new();
+    assert!(event_group.is_ok());
+    log_info!(TAG, "test_event_group_creation PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_set_get<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_set_get");
+    let event_group = B::EventGroup::new()?;
+    
+    let result = event_group.set(BIT_0);
+    log_debug!(TAG, "Set BIT_0, result: 0x{:X}", result);
+    assert_ne!(result, 0);
+    
+    let bits = event_group.get();
+    log_debug!(TAG, "Current bits: 0x{:X}", bits);
+    assert_eq!(bits & BIT_0, BIT_0);
+    log_info!(TAG, "test_event_group_set_get PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_multiple_bits<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_multiple_bits");
+    let event_group = B::EventGroup::new()?;
+    
+    event_group.set(BIT_0 | BIT_1 | BIT_2);
+    
+    let bits = event_group.get();
+    log_debug!(TAG, "Set bits: 0x{:X}", bits);
+    assert_eq!(bits & BIT_0, BIT_0);
+    assert_eq!(bits & BIT_1, BIT_1);
+    assert_eq!(bits & BIT_2, BIT_2);
+    log_info!(TAG, "test_event_group_multiple_bits PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_clear<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_clear");
+    let event_group = B::EventGroup::new()?;
+    
+    event_group.set(BIT_0 | BIT_1 | BIT_2);
+    
+    log_debug!(TAG, "Clearing BIT_1");
+    event_group.clear(BIT_1);
+    
+    let bits = event_group.get();
+    log_debug!(TAG, "Remaining bits: 0x{:X}", bits);
+    assert_eq!(bits & BIT_0, BIT_0);
+    assert_eq!(bits & BIT_1, 0);
+    assert_eq!(bits & BIT_2, BIT_2);
+    log_info!(TAG, "test_event_group_clear PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_clear_all<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_clear_all");
+    let event_group = B::EventGroup::new()?;
+    
+    event_group.set(BIT_0 | BIT_1 | BIT_2 | BIT_3);
+    
+    log_debug!(TAG, "Clearing all bits");
+    event_group.clear(BIT_0 | BIT_1 | BIT_2 | BIT_3);
+    
+    let bits = event_group.get();
+    log_debug!(TAG, "All bits cleared: 0x{:X}", bits);
+    assert_eq!(bits, 0);
+    log_info!(TAG, "test_event_group_clear_all PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_wait<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_wait");
+    let event_group = B::EventGroup::new()?;
+    
+    event_group.set(BIT_0 | BIT_1);
+    
+    log_debug!(TAG, "Waiting for BIT_0 and BIT_1");
+    let result = event_group.wait(BIT_0 | BIT_1, Duration::from_millis(100).to_ticks());
+    log_debug!(TAG, "Wait result: 0x{:X}", result);
+    assert_eq!(result & BIT_0, BIT_0);
+    assert_eq!(result & BIT_1, BIT_1);
+    log_info!(TAG, "test_event_group_wait PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_wait_timeout<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_wait_timeout");
+    let event_group = B::EventGroup::new()?;
+    
+    let result = event_group.wait(BIT_0, Duration::from_millis(10).to_ticks());
+    log_debug!(TAG, "Wait timeout result: 0x{:X}", result);
+    assert_eq!(result, 0);
+    log_info!(TAG, "test_event_group_wait_timeout PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_wait_partial<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_wait_partial");
+    let event_group = B::EventGroup::new()?;
+    
+    event_group.set(BIT_0);
+    
+    log_debug!(TAG, "Waiting for BIT_0 | BIT_1 (only BIT_0 set)");
+    let result = event_group.wait(BIT_0 | BIT_1, Duration::from_millis(10).to_ticks());
+    log_debug!(TAG, "Partial wait result: 0x{:X}", result);
+    assert_eq!(result & BIT_0, BIT_0);
+    log_info!(TAG, "test_event_group_wait_partial PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_sequential_operations<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_sequential_operations");
+    let event_group = B::EventGroup::new()?;
+    
+    event_group.set(BIT_0);
+    assert_eq!(event_group.get() & BIT_0, BIT_0);
+    
+    event_group.set(BIT_1);
+    assert_eq!(event_group.get() & (BIT_0 | BIT_1), BIT_0 | BIT_1);
+    
+    log_debug!(TAG, "Clearing BIT_0");
+    event_group.clear(BIT_0);
+    assert_eq!(event_group.get() & BIT_0, 0);
+    assert_eq!(event_group.get() & BIT_1, BIT_1);
+    
+    event_group.set(BIT_2);
+    assert_eq!(event_group.get() & (BIT_1 | BIT_2), BIT_1 | BIT_2);
+    log_info!(TAG, "test_event_group_sequential_operations PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_all_bits<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_all_bits");
+    let event_group = B::EventGroup::new()?;
+    
+    let all_bits = 0x00FFFFFF;
+    event_group.set(all_bits);
+    
+    let bits = event_group.get();
+    log_debug!(TAG, "All bits set: 0x{:X}", bits);
+    assert_eq!(bits & all_bits, all_bits);
+    log_info!(TAG, "test_event_group_all_bits PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_clear_returns_previous<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_clear_returns_previous");
+    let event_group = B::EventGroup::new()?;
+
+    event_group.set(BIT_0 | BIT_1);
+    let previous = event_group.clear(BIT_0);
+    log_debug!(TAG, "Bits before clear: 0x{:X}", previous);
+    assert_eq!(previous, BIT_0 | BIT_1);
+    assert_eq!(event_group.get(), BIT_1);
+    log_info!(TAG, "test_event_group_clear_returns_previous PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_cross_thread<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_cross_thread");
+    let event_group = Arc::new(B::EventGroup::new()?);
+    let event_group_clone = Arc::clone(&event_group);
+
+    let mut thread = B::Thread::new("event_setter", 1024, 5);
+    thread.spawn_simple(move || {
+        B::System::delay(Duration::from_millis(20).to_ticks());
+        event_group_clone.set(BIT_2);
+    })?;
+
+    let result = event_group.wait(BIT_1 | BIT_2, Duration::from_millis(1000).to_ticks());
+    log_debug!(TAG, "Woken with bits: 0x{:X}", result);
+    assert_eq!(result & BIT_2, BIT_2);
+    log_info!(TAG, "test_event_group_cross_thread PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_wait_all<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_wait_all");
+    let event_group = B::EventGroup::new()?;
+
+    event_group.set(BIT_0);
+    let result = event_group.wait_bits(BIT_0 | BIT_1, EventWaitOptions::ALL, Duration::from_millis(10));
+    assert_eq!(result, Err(Error::Timeout));
+    assert_eq!(event_group.wait_bits(BIT_0 | BIT_1, EventWaitOptions::ANY, Duration::ZERO)?, BIT_0);
+
+    event_group.set(BIT_1 | BIT_3);
+    let bits = event_group.wait_bits(BIT_0 | BIT_1, EventWaitOptions::ALL, Duration::ZERO)?;
+    log_debug!(TAG, "All bits set: 0x{:X}", bits);
+    assert_eq!(bits, BIT_0 | BIT_1 | BIT_3);
+    assert_eq!(event_group.get(), BIT_0 | BIT_1 | BIT_3);
+    log_info!(TAG, "test_event_group_wait_all PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_wait_clear_on_exit<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_wait_clear_on_exit");
+    let event_group = B::EventGroup::new()?;
+
+    event_group.set(BIT_0 | BIT_2);
+    let bits = event_group.wait_bits(BIT_0 | BIT_1, EventWaitOptions::ANY.clear_on_exit(), Duration::ZERO)?;
+    assert_eq!(bits, BIT_0 | BIT_2);
+    assert_eq!(event_group.get(), BIT_2);
+
+    // Nothing is cleared on timeout
+    let result = event_group.wait_bits(BIT_1 | BIT_2, EventWaitOptions::ALL.clear_on_exit(), Duration::from_millis(10));
+    assert_eq!(result, Err(Error::Timeout));
+    assert_eq!(event_group.get(), BIT_2);
+    log_info!(TAG, "test_event_group_wait_clear_on_exit PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_clear_on_exit_waiters<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_clear_on_exit_waiters");
+    let event_group = Arc::new(B::EventGroup::new()?);
+    let done = Arc::new(B::Semaphore::new(2, 0)?);
+    let released = Arc::new(AtomicU32::new(0));
+
+    for _ in 0..2 {
+        let event_group_clone = Arc::clone(&event_group);
+        let done_clone = Arc::clone(&done);
+        let released_clone = Arc::clone(&released);
+        let mut thread = B::Thread::new("event_consumer", 1024, 5);
+        thread.spawn_simple(move || {
+            let options = EventWaitOptions::ALL.clear_on_exit();
+            if event_group_clone.wait_bits(BIT_0 | BIT_1, options, Duration::from_millis(1000)).is_ok() {
+                released_clone.fetch_add(1, Ordering::SeqCst);
+            }
+            done_clone.signal();
+        })?;
+    }
+
+    // Both consumers are blocked: one set releases them before clearing
+    B::System::delay(Duration::from_millis(20).to_ticks());
+    event_group.set(BIT_0 | BIT_1);
+    for _ in 0..2 {
+        assert_eq!(done.wait(Duration::from_millis(2000)), OsalRsBool::True);
+    }
+    assert_eq!(released.load(Ordering::SeqCst), 2);
+    assert_eq!(event_group.get(), 0);
+    log_info!(TAG, "test_event_group_clear_on_exit_waiters PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_sync<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_sync");
+    const PARTIES: [EventBits; 3] = [BIT_1, BIT_2, BIT_3];
+    const ALL: EventBits = BIT_0 | BIT_1 | BIT_2 | BIT_3;
+    const ROUNDS: u32 = 5;
+
+    let event_group = Arc::new(B::EventGroup::new()?);
+    let done = Arc::new(B::Semaphore::new(PARTIES.len() as _, 0)?);
+    let arrivals = Arc::new(AtomicU32::new(0));
+
+    for bit in PARTIES {
+        let event_group_clone = Arc::clone(&event_group);
+        let done_clone = Arc::clone(&done);
+        let arrivals_clone = Arc::clone(&arrivals);
+        let mut thread = B::Thread::new("event_party", 1024, 5);
+        thread.spawn_simple(move || {
+            for _ in 0..ROUNDS {
+                arrivals_clone.fetch_add(1, Ordering::SeqCst);
+                if event_group_clone.sync(bit, ALL, Duration::from_millis(1000)).is_err() {
+                    return;
+                }
+            }
+            done_clone.signal();
+        })?;
+    }
+
+    for round in 0..ROUNDS {
+        B::System::delay(Duration::from_millis(5).to_ticks());
+        let bits = event_group.sync(BIT_0, ALL, Duration::from_millis(1000))?;
+        assert_eq!(bits & ALL, ALL);
+        // Nobody passes the barrier before every party reached it
+        assert!(arrivals.load(Ordering::SeqCst) >= (round + 1) * PARTIES.len() as u32);
+    }
+    for _ in PARTIES {
+        assert_eq!(done.wait(Duration::from_millis(2000)), OsalRsBool::True);
+    }
+    log_debug!(TAG, "{} rounds of {} parties", ROUNDS, PARTIES.len() + 1);
+    assert_eq!(event_group.get() & ALL, 0);
+    log_info!(TAG, "test_event_group_sync PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_sync_timeout<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_sync_timeout");
+    let event_group = B::EventGroup::new()?;
+
+    let result = event_group.sync(BIT_0, BIT_0 | BIT_1, Duration::from_millis(10));
+    assert_eq!(result, Err(Error::Timeout));
+    // The bit of the party that gave up stays set
+    assert_eq!(event_group.get(), BIT_0);
+
+    assert_eq!(event_group.sync(BIT_1, BIT_0 | BIT_1, Duration::ZERO)?, BIT_0 | BIT_1);
+    assert_eq!(event_group.get(), 0);
+    log_info!(TAG, "test_event_group_sync_timeout PASSED");
+    Ok(())
+}
+
+register_conformance! {
+    test_event_group_creation,
+    test_event_group_set_get,
+    test_event_group_multiple_bits,
+    test_event_group_clear,
+    test_event_group_clear_all,
+    test_event_group_wait,
+    test_event_group_wait_timeout,
+    test_event_group_wait_partial,
+    test_event_group_sequential_operations,
+    test_event_group_all_bits,
+    test_event_group_clear_returns_previous,
+    test_event_group_cross_thread,
+    test_event_group_wait_all,
+    test_event_group_wait_clear_on_exit,
+    test_event_group_clear_on_exit_waiters,
+    test_event_group_sync,
+    test_event_group_sync_timeout,
+}
@@ -0,0 +1,162 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::time::Duration;
+use osal_rs::os::*;
+use osal_rs::utils::{Error, Result};
+use osal_rs::{log_debug, log_info};
+
+use super::Backend;
+
+const TAG: &str = "MessageBufferTests";
+
+/// Bytes stored before each message
+const LENGTH_SIZE: usize = size_of::<usize>();
+
+/// Variable-length frame, as sent by a CAN or log transport
+#[derive(Debug, PartialEq)]
+pub(crate) struct Frame(pub(crate) Vec<u8>);
+
+impl ToBytes for Frame {
+    fn to_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl FromBytes for Frame {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(Self(bytes.to_vec()))
+    }
+}
+
+pub fn test_message_buffer_send_receive<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_message_buffer_send_receive");
+    assert!(matches!(B::MessageBuffer::new(LENGTH_SIZE), Err(Error::InvalidQueueSize)));
+
+    let buffer = B::MessageBuffer::new(64)?;
+    assert_eq!(buffer.next_length(), 0);
+    buffer.send(b"a", Duration::ZERO)?;
+    buffer.send(b"hello world", Duration::ZERO)?;
+    assert_eq!(buffer.next_length(), 1);
+    assert_eq!(buffer.spaces_available(), 64 - (1 + LENGTH_SIZE) - (11 + LENGTH_SIZE));
+
+    let mut message = [0u8; 32];
+    assert_eq!(buffer.receive(&mut message, Duration::ZERO)?, 1);
+    assert_eq!(&message[..1], b"a");
+    assert_eq!(buffer.receive(&mut message, Duration::ZERO)?, 11);
+    assert_eq!(&message[..11], b"hello world");
+    assert!(matches!(buffer.receive(&mut message, Duration::from_millis(10)), Err(Error::Timeout)));
+
+    buffer.send(b"stale", Duration::ZERO)?;
+    buffer.reset()?;
+    assert_eq!(buffer.next_length(), 0);
+    log_info!(TAG, "test_message_buffer_send_receive PASSED");
+    Ok(())
+}
+
+pub fn test_message_buffer_whole_messages<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_message_buffer_whole_messages");
+    let buffer = B::MessageBuffer::new(32)?;
+
+    // A message never fits partially, one larger than the buffer never fits
+    assert!(matches!(buffer.send(&[0u8; 32], Duration::from_millis(10)), Err(Error::Timeout)));
+    buffer.send(&[1u8; 10], Duration::ZERO)?;
+    assert!(matches!(buffer.send(&[2u8; 10], Duration::ZERO), Err(Error::Timeout)));
+
+    // A buffer too small leaves the message in place
+    let mut small = [0u8; 4];
+    let result = buffer.receive(&mut small, Duration::ZERO);
+    assert!(result.is_err() && !matches!(result, Err(Error::Timeout)));
+    assert_eq!(buffer.next_length(), 10);
+
+    let mut message = [0u8; 16];
+    assert_eq!(buffer.receive(&mut message, Duration::ZERO)?, 10);
+    assert_eq!(message[..10], [1u8; 10]);
+    log_info!(TAG, "test_message_buffer_whole_messages PASSED");
+    Ok(())
+}
+
+pub fn test_message_buffer_blocking_send<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_message_buffer_blocking_send");
+    let buffer = Arc::new(B::MessageBuffer::new(32)?);
+    buffer.send(&[1u8; 16], Duration::ZERO)?;
+
+    let buffer_clone = Arc::clone(&buffer);
+    let mut thread = B::Thread::new("message_reader", 1024, 5);
+    thread.spawn_simple(move || {
+        B::System::delay(Duration::from_millis(10).to_ticks());
+        let mut message = [0u8; 16];
+        buffer_clone.receive(&mut message, Duration::ZERO).unwrap();
+    })?;
+
+    buffer.send(&[2u8; 12], Duration::from_millis(1000))?;
+    let mut message = [0u8; 16];
+    assert_eq!(buffer.receive(&mut message, Duration::ZERO)?, 12);
+    assert_eq!(message[..12], [2u8; 12]);
+    log_info!(TAG, "test_message_buffer_blocking_send PASSED");
+    Ok(())
+}
+
+pub fn test_message_buffer_from_isr<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_message_buffer_from_isr");
+    let buffer = B::MessageBuffer::new(24)?;
+    let mut message = [0u8; 8];
+    assert!(matches!(buffer.receive_from_isr(&mut message), Err(Error::Timeout)));
+    buffer.send_from_isr(b"isr")?;
+    assert!(matches!(buffer.send_from_isr(&[0u8; 16]), Err(Error::Timeout)));
+    assert_eq!(buffer.receive_from_isr(&mut message)?, 3);
+    assert_eq!(&message[..3], b"isr");
+    log_info!(TAG, "test_message_buffer_from_isr PASSED");
+    Ok(())
+}
+
+pub fn test_message_buffer_streamed<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_message_buffer_streamed");
+    let buffer = Arc::new(B::MessageBufferStreamed::<Frame>::new(128)?);
+
+    let buffer_clone = Arc::clone(&buffer);
+    let mut thread = B::Thread::new("frame_writer", 1024, 5);
+    thread.spawn_simple(move || {
+        for len in 1..=20u8 {
+            let frame = Frame((0..len).collect());
+            buffer_clone.send(&frame, Duration::from_millis(1000)).unwrap();
+        }
+    })?;
+
+    for len in 1..=20u8 {
+        let frame = buffer.receive(Duration::from_millis(1000))?;
+        assert_eq!(frame, Frame((0..len).collect()));
+    }
+    assert!(matches!(buffer.receive_from_isr(), Err(Error::Timeout)));
+    log_debug!(TAG, "Received 20 frames of 1 to 20 bytes");
+    log_info!(TAG, "test_message_buffer_streamed PASSED");
+    Ok(())
+}
+
+register_conformance! {
+    test_message_buffer_send_receive,
+    test_message_buffer_whole_messages,
+    test_message_buffer_blocking_send,
+    test_message_buffer_from_isr,
+    test_message_buffer_streamed,
+}
@@ -0,0 +1,93 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+//! Conformance suite shared by every backend.
+//!
+//! The cases only use the `*Fn` traits of osal-rs through the types of a
+//! [`Backend`], so the same expectations hold on FreeRTOS, POSIX and any
+//! later backend. Behaviour that a single backend provides is tested in its
+//! own module.
+
+use core::marker::PhantomData;
+
+use osal_rs::os::*;
+use osal_rs::utils::Error;
+
+use crate::harness::Test;
+
+/// Registers the generic cases of a module: `Suite::<B>::TESTS` runs them on
+/// the types of backend `B`
+macro_rules! register_conformance {
+    ($($test:ident),* $(,)?) => {
+        /// Cases of this module instantiated for a backend
+        pub struct Suite<B>(core::marker::PhantomData<B>);
+
+        impl<B: $crate::conformance::Backend> Suite<B> {
+            pub const TESTS: &'static [$crate::harness::Test] = &[
+                $($crate::harness::Test::new(concat!(module_path!(), "::", stringify!($test)), $test::<B>)),*
+            ];
+        }
+    };
+}
+
+pub mod event_group;
+pub mod message_buffer;
+pub mod mutex;
+pub mod queue;
+pub mod queue_set;
+pub mod semaphore;
+pub mod stream_buffer;
+pub mod system;
+pub mod thread;
+pub mod timer;
+
+/// Types of a backend run through the conformance suite
+pub trait Backend: 'static {
+    type Thread: ThreadFn + Send + Sync + 'static;
+    type Queue: QueueFn + QueueSetMember + Send + Sync + 'static;
+    type QueueSet: QueueSetFn + Send + Sync + 'static;
+    type MessageBuffer: MessageBufferFn + Send + Sync + 'static;
+    type MessageBufferStreamed<T: ToBytes + FromBytes + 'static>: MessageBufferStreamedFn<T> + Send + Sync + 'static;
+    type Mutex<T: Send + 'static>: MutexFn<T> + Send + Sync + 'static;
+    type RecursiveMutex<T: Send + 'static>: RecursiveMutexFn<T> + Send + Sync + 'static;
+    type Semaphore: SemaphoreFn + QueueSetMember + Send + Sync + 'static;
+    type BinarySemaphore: BinarySemaphoreFn + QueueSetMember + Send + Sync + 'static;
+    type StreamBuffer: StreamBufferFn + embedded_io::Read<Error = Error> + embedded_io::ReadReady + embedded_io::Write<Error = Error> + Send + Sync + 'static;
+    type EventGroup: EventGroupFn + Send + Sync + 'static;
+    type Timer: TimerFn + Clone + 'static;
+    type System: SystemFn;
+}
+
+/// The conformance suite of backend `B`
+pub struct Conformance<B>(PhantomData<B>);
+
+impl<B: Backend> Conformance<B> {
+    pub const SUITES: &'static [&'static [Test]] = &[
+        event_group::Suite::<B>::TESTS,
+        message_buffer::Suite::<B>::TESTS,
+        mutex::Suite::<B>::TESTS,
+        queue::Suite::<B>::TESTS,
+        queue_set::Suite::<B>::TESTS,
+        semaphore::Suite::<B>::TESTS,
+        stream_buffer::Suite::<B>::TESTS,
+        system::Suite::<B>::TESTS,
+        thread::Suite::<B>::TESTS,
+        timer::Suite::<B>::TESTS,
+    ];
+}
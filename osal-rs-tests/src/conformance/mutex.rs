@@ -0,0 +1,307 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::cell::Cell;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::time::Duration;
+use osal_rs::os::*;
+use osal_rs::utils::{OsalRsBool, Result};
+use osal_rs::{log_debug, log_info};
+
+use super::Backend;
+
+const TAG: &str = "MutexTests";
+
+pub fn test_mutex_creation<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_mutex_creation");
+    let _mutex = B::Mutex::new(0u32);
+    log_info!(TAG, "test_mutex_creation PASSED");
+    Ok(())
+}
+
+pub fn test_mutex_lock_unlock<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_mutex_lock_unlock");
+    let mutex = B::Mutex::new(42u32);
+    
+    {
+        let guard = mutex.lock();
+        assert!(guard.is_ok());
+        
+        if let Ok(g) = guard {
+            log_debug!(TAG, "Mutex locked, value: {}", *g);
+            assert_eq!(*g, 42);
+        }
+    }
+    
+    {
+        let guard = mutex.lock();
+        assert!(guard.is_ok());
+        log_debug!(TAG, "Mutex re-locked successfully");
+    }
+    log_info!(TAG, "test_mutex_lock_unlock PASSED");
+    Ok(())
+}
+
+pub fn test_mutex_modify_data<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_mutex_modify_data");
+    let mutex = B::Mutex::new(0u32);
+    
+    {
+        let mut guard = mutex.lock()?;
+        *guard = 100;
+        log_debug!(TAG, "Modified value to: {}", *guard);
+    }
+    
+    {
+        let guard = mutex.lock()?;
+        log_debug!(TAG, "Read value: {}", *guard);
+        assert_eq!(*guard, 100);
+    }
+    log_info!(TAG, "test_mutex_modify_data PASSED");
+    Ok(())
+}
+
+pub fn test_mutex_multiple_locks<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_mutex_multiple_locks");
+    let mutex = B::Mutex::new(0u32);
+    
+    for i in 0..10 {
+        let mut guard = mutex.lock()?;
+        *guard += 1;
+        assert_eq!(*guard, i + 1);
+    }
+    
+    let guard = mutex.lock()?;
+    log_debug!(TAG, "Final counter value: {}", *guard);
+    assert_eq!(*guard, 10);
+    log_info!(TAG, "test_mutex_multiple_locks PASSED");
+    Ok(())
+}
+
+pub fn test_mutex_guard_drop<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_mutex_guard_drop");
+    let mutex = B::Mutex::new(42u32);
+    
+    {
+        let _guard = mutex.lock()?;
+        log_debug!(TAG, "Guard acquired, will drop on scope exit");
+    }
+    
+    let guard = mutex.lock();
+    assert!(guard.is_ok());
+    log_info!(TAG, "test_mutex_guard_drop PASSED");
+    Ok(())
+}
+
+pub fn test_mutex_with_struct<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_mutex_with_struct");
+    #[derive(Debug, PartialEq)]
+    struct TestData {
+        value: u32,
+        flag: bool,
+    }
+    
+    let mutex = B::Mutex::new(TestData { value: 0, flag: false });
+    
+    {
+        let mut guard = mutex.lock()?;
+        guard.value = 123;
+        guard.flag = true;
+        log_debug!(TAG, "Modified struct - value: {}, flag: {}", guard.value, guard.flag);
+    }
+    
+    {
+        let guard = mutex.lock()?;
+        assert_eq!(guard.value, 123);
+        assert!(guard.flag);
+    }
+    log_info!(TAG, "test_mutex_with_struct PASSED");
+    Ok(())
+}
+
+pub fn test_mutex_not_recursive<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_mutex_not_recursive");
+    let mutex = B::Mutex::new(0u32);
+    
+    let guard = mutex.lock()?;
+    log_debug!(TAG, "Lock acquired");
+    assert!(mutex.try_lock().is_err());
+    drop(guard);
+
+    assert!(mutex.try_lock().is_ok());
+    log_info!(TAG, "test_mutex_not_recursive PASSED");
+    Ok(())
+}
+
+pub fn test_mutex_try_lock_other_thread<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_mutex_try_lock_other_thread");
+    let mutex = Arc::new(B::Mutex::new(0u32));
+    let done = Arc::new(B::BinarySemaphore::new()?);
+    let locked = Arc::new(AtomicBool::new(true));
+
+    let guard = mutex.lock()?;
+    let mutex_clone = Arc::clone(&mutex);
+    let done_clone = Arc::clone(&done);
+    let locked_clone = Arc::clone(&locked);
+    let mut thread = B::Thread::new("mutex_try", 1024, 5);
+    thread.spawn_simple(move || {
+        locked_clone.store(mutex_clone.try_lock().is_ok(), Ordering::SeqCst);
+        done_clone.signal();
+    })?;
+    assert_eq!(done.wait(Duration::from_millis(1000)), OsalRsBool::True);
+    assert!(!locked.load(Ordering::SeqCst));
+    drop(guard);
+
+    let mutex_clone = Arc::clone(&mutex);
+    let done_clone = Arc::clone(&done);
+    let mut thread = B::Thread::new("mutex_try", 1024, 5);
+    thread.spawn_simple(move || {
+        if let Ok(mut guard) = mutex_clone.try_lock() {
+            *guard += 1;
+        }
+        done_clone.signal();
+    })?;
+    assert_eq!(done.wait(Duration::from_millis(1000)), OsalRsBool::True);
+    assert_eq!(*mutex.lock()?, 1);
+    log_info!(TAG, "test_mutex_try_lock_other_thread PASSED");
+    Ok(())
+}
+
+pub fn test_mutex_contention<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_mutex_contention");
+    let counter = Arc::new(B::Mutex::new(0u32));
+    let done = Arc::new(B::Semaphore::new(4, 0)?);
+
+    for _ in 0..4 {
+        let counter_clone = Arc::clone(&counter);
+        let done_clone = Arc::clone(&done);
+        let mut thread = B::Thread::new("mutex_worker", 1024, 5);
+        thread.spawn_simple(move || {
+            for _ in 0..1000 {
+                let mut guard = counter_clone.lock().unwrap();
+                let value = *guard;
+                B::System::delay(Duration::from_millis(0).to_ticks());
+                *guard = value + 1;
+            }
+            done_clone.signal();
+        })?;
+    }
+
+    for _ in 0..4 {
+        assert_eq!(done.wait(Duration::from_millis(5000)), OsalRsBool::True);
+    }
+
+    let final_count = *counter.lock()?;
+    log_debug!(TAG, "Final counter value: {}", final_count);
+    assert_eq!(final_count, 4000);
+    log_info!(TAG, "test_mutex_contention PASSED");
+    Ok(())
+}
+
+pub fn test_mutex_drop<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_mutex_drop");
+    let mutex = B::Mutex::new(42u32);
+    drop(mutex);
+    log_info!(TAG, "test_mutex_drop PASSED");
+    Ok(())
+}
+
+pub fn test_recursive_mutex_nested<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_recursive_mutex_nested");
+    let mutex = B::RecursiveMutex::new(Cell::new(0u32));
+    
+    let guard1 = mutex.lock()?;
+    log_debug!(TAG, "Lock 1 acquired");
+    let guard2 = mutex.lock()?;
+    log_debug!(TAG, "Lock 2 acquired");
+    let guard3 = mutex.try_lock()?;
+    log_debug!(TAG, "Lock 3 acquired");
+    guard3.set(guard1.get() + 1);
+    assert_eq!(guard2.get(), 1);
+    log_info!(TAG, "test_recursive_mutex_nested PASSED");
+    Ok(())
+}
+
+pub fn test_recursive_mutex_release<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_recursive_mutex_release");
+    let mutex = Arc::new(B::RecursiveMutex::new(Cell::new(0u32)));
+    let done = Arc::new(B::BinarySemaphore::new()?);
+    let locked = Arc::new(AtomicBool::new(true));
+
+    let guard1 = mutex.lock()?;
+    let guard2 = mutex.lock()?;
+    drop(guard2);
+
+    // Still held once by this thread: the other one must not get it
+    let mutex_clone = Arc::clone(&mutex);
+    let done_clone = Arc::clone(&done);
+    let locked_clone = Arc::clone(&locked);
+    let mut thread = B::Thread::new("rmutex_try", 1024, 5);
+    thread.spawn_simple(move || {
+        locked_clone.store(mutex_clone.try_lock().is_ok(), Ordering::SeqCst);
+        done_clone.signal();
+    })?;
+    assert_eq!(done.wait(Duration::from_millis(1000)), OsalRsBool::True);
+    assert!(!locked.load(Ordering::SeqCst));
+
+    drop(guard1);
+    log_debug!(TAG, "Both recursive locks released");
+
+    let mutex_clone = Arc::clone(&mutex);
+    let done_clone = Arc::clone(&done);
+    let mut thread = B::Thread::new("rmutex_try", 1024, 5);
+    thread.spawn_simple(move || {
+        if let Ok(guard) = mutex_clone.try_lock() {
+            guard.set(guard.get() + 1);
+        }
+        done_clone.signal();
+    })?;
+    assert_eq!(done.wait(Duration::from_millis(1000)), OsalRsBool::True);
+    assert_eq!(mutex.lock()?.get(), 1);
+    log_info!(TAG, "test_recursive_mutex_release PASSED");
+    Ok(())
+}
+
+pub fn test_recursive_mutex_into_inner<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_recursive_mutex_into_inner");
+    let mut mutex = B::RecursiveMutex::new(Cell::new(7u32));
+    mutex.get_mut().set(8);
+    assert_eq!(mutex.into_inner()?.get(), 8);
+    log_info!(TAG, "test_recursive_mutex_into_inner PASSED");
+    Ok(())
+}
+
+register_conformance! {
+    test_mutex_creation,
+    test_mutex_lock_unlock,
+    test_mutex_modify_data,
+    test_mutex_multiple_locks,
+    test_mutex_guard_drop,
+    test_mutex_with_struct,
+    test_mutex_not_recursive,
+    test_mutex_try_lock_other_thread,
+    test_mutex_contention,
+    test_mutex_drop,
+    test_recursive_mutex_nested,
+    test_recursive_mutex_release,
+    test_recursive_mutex_into_inner,
+}
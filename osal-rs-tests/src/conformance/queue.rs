@@ -0,0 +1,261 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::time::Duration;
+use osal_rs::os::*;
+use osal_rs::os::types::TickType;
+use osal_rs::utils::{Error, OsalRsBool, Result};
+use osal_rs::{log_debug, log_info};
+
+use super::Backend;
+
+const TAG: &str = "QueueTests";
+
+const WAIT_FOREVER: TickType = TickType::MAX;
+
+pub fn test_queue_creation<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_creation");
+    let queue = B::Queue::new(10, 4);
+    assert!(queue.is_ok());
+
+    if let Ok(mut q) = queue {
+        log_debug!(TAG, "Queue created successfully, deleting...");
+        q.delete();
+    }
+    log_info!(TAG, "test_queue_creation PASSED");
+    Ok(())
+}
+
+pub fn test_queue_post_fetch<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_post_fetch");
+    let queue = B::Queue::new(10, 4)?;
+
+    let data: u32 = 0x12345678;
+    let bytes = data.to_le_bytes();
+
+    log_debug!(TAG, "Posting data: 0x{:X}", data);
+    let post_result = queue.post(&bytes, Duration::from_millis(100).to_ticks());
+    assert!(post_result.is_ok());
+
+    let mut received = [0u8; 4];
+    let fetch_result = queue.fetch(&mut received, Duration::from_millis(100).to_ticks());
+    assert!(fetch_result.is_ok());
+
+    let received_data = u32::from_le_bytes(received);
+    log_debug!(TAG, "Received data: 0x{:X}", received_data);
+    assert_eq!(received_data, data);
+    log_info!(TAG, "test_queue_post_fetch PASSED");
+    Ok(())
+}
+
+pub fn test_queue_timeout<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_timeout");
+    let queue = B::Queue::new(10, 4)?;
+
+    let mut buffer = [0u8; 4];
+    let result = queue.fetch(&mut buffer, Duration::from_millis(10).to_ticks());
+    log_debug!(TAG, "Fetch timeout result: {:?}", result.is_err());
+    assert!(result.is_err());
+    log_info!(TAG, "test_queue_timeout PASSED");
+    Ok(())
+}
+
+pub fn test_queue_multiple_items<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_multiple_items");
+    let queue = B::Queue::new(5, 4)?;
+
+    log_debug!(TAG, "Posting 5 items...");
+    for i in 0..5u32 {
+        let bytes = i.to_le_bytes();
+        let result = queue.post(&bytes, Duration::from_millis(100).to_ticks());
+        assert!(result.is_ok());
+    }
+
+    log_debug!(TAG, "Fetching 5 items...");
+    for i in 0..5u32 {
+        let mut received = [0u8; 4];
+        let result = queue.fetch(&mut received, Duration::from_millis(100).to_ticks());
+        assert!(result.is_ok());
+
+        let received_data = u32::from_le_bytes(received);
+        assert_eq!(received_data, i);
+    }
+    log_info!(TAG, "test_queue_multiple_items PASSED");
+    Ok(())
+}
+
+pub fn test_queue_full_timeout<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_full_timeout");
+    let queue = B::Queue::new(2, 4)?;
+
+    queue.post(&1u32.to_le_bytes(), 0)?;
+    queue.post(&2u32.to_le_bytes(), 0)?;
+    let result = queue.post(&3u32.to_le_bytes(), Duration::from_millis(10).to_ticks());
+    log_debug!(TAG, "Post on full queue: {:?}", result);
+    assert!(matches!(result, Err(Error::Timeout)));
+    log_info!(TAG, "test_queue_full_timeout PASSED");
+    Ok(())
+}
+
+pub fn test_queue_producer_consumer<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_producer_consumer");
+    // Smaller than the number of items so that the producer has to block
+    let queue = Arc::new(B::Queue::new(4, 4)?);
+    let queue_clone = Arc::clone(&queue);
+    let done = Arc::new(B::Semaphore::new(1, 0)?);
+    let done_clone = Arc::clone(&done);
+
+    let mut thread = B::Thread::new("queue_producer", 1024, 5);
+    thread.spawn_simple(move || {
+        for i in 0..100u32 {
+            queue_clone.post(&i.to_le_bytes(), WAIT_FOREVER).unwrap();
+        }
+        done_clone.signal();
+    })?;
+
+    for i in 0..100u32 {
+        let mut received = [0u8; 4];
+        queue.fetch(&mut received, Duration::from_millis(1000).to_ticks())?;
+        assert_eq!(u32::from_le_bytes(received), i);
+    }
+    log_debug!(TAG, "Received 100 items in order");
+    assert_eq!(done.wait(Duration::from_millis(1000)), OsalRsBool::True);
+    log_info!(TAG, "test_queue_producer_consumer PASSED");
+    Ok(())
+}
+
+pub fn test_queue_post_to_front<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_post_to_front");
+    let queue = B::Queue::new(4, 4)?;
+
+    queue.post(&1u32.to_le_bytes(), 0)?;
+    queue.post(&2u32.to_le_bytes(), 0)?;
+    queue.post_to_front(&0u32.to_le_bytes(), 0)?;
+    queue.post_to_front_from_isr(&0xFFu32.to_le_bytes())?;
+
+    // Full: the front of the queue blocks as the back does
+    let result = queue.post_to_front(&3u32.to_le_bytes(), Duration::from_millis(10).to_ticks());
+    assert!(matches!(result, Err(Error::Timeout)));
+
+    let mut received = [0u8; 4];
+    for expected in [0xFFu32, 0, 1, 2] {
+        queue.fetch(&mut received, 0)?;
+        assert_eq!(u32::from_le_bytes(received), expected);
+    }
+    log_info!(TAG, "test_queue_post_to_front PASSED");
+    Ok(())
+}
+
+pub fn test_queue_overwrite<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_overwrite");
+    let mailbox = B::Queue::new(1, 4)?;
+
+    mailbox.overwrite(&1u32.to_le_bytes())?;
+    mailbox.overwrite(&2u32.to_le_bytes())?;
+    mailbox.overwrite_from_isr(&3u32.to_le_bytes())?;
+
+    let mut received = [0u8; 4];
+    mailbox.fetch(&mut received, 0)?;
+    assert_eq!(u32::from_le_bytes(received), 3);
+    assert!(matches!(mailbox.fetch(&mut received, 0), Err(Error::Timeout)));
+    log_info!(TAG, "test_queue_overwrite PASSED");
+    Ok(())
+}
+
+pub fn test_queue_peek<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_peek");
+    let queue = B::Queue::new(4, 4)?;
+
+    let mut received = [0u8; 4];
+    assert!(matches!(queue.peek(&mut received, Duration::from_millis(10).to_ticks()), Err(Error::Timeout)));
+    assert!(matches!(queue.peek_from_isr(&mut received), Err(Error::Timeout)));
+
+    queue.post(&7u32.to_le_bytes(), 0)?;
+    queue.post(&8u32.to_le_bytes(), 0)?;
+    queue.peek(&mut received, 0)?;
+    assert_eq!(u32::from_le_bytes(received), 7);
+    received = [0; 4];
+    queue.peek_from_isr(&mut received)?;
+    assert_eq!(u32::from_le_bytes(received), 7);
+
+    // Peeking does not consume
+    queue.fetch(&mut received, 0)?;
+    assert_eq!(u32::from_le_bytes(received), 7);
+    queue.fetch(&mut received, 0)?;
+    assert_eq!(u32::from_le_bytes(received), 8);
+    log_info!(TAG, "test_queue_peek PASSED");
+    Ok(())
+}
+
+pub fn test_queue_peek_wakes_on_post<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_peek_wakes_on_post");
+    let queue = Arc::new(B::Queue::new(2, 4)?);
+    let queue_clone = Arc::clone(&queue);
+
+    let mut thread = B::Thread::new("queue_peek_poster", 1024, 5);
+    thread.spawn_simple(move || {
+        B::System::delay(Duration::from_millis(20).to_ticks());
+        queue_clone.post(&9u32.to_le_bytes(), 0).unwrap();
+    })?;
+
+    let mut received = [0u8; 4];
+    queue.peek(&mut received, Duration::from_millis(1000).to_ticks())?;
+    assert_eq!(u32::from_le_bytes(received), 9);
+    queue.fetch(&mut received, 0)?;
+    assert_eq!(u32::from_le_bytes(received), 9);
+    log_info!(TAG, "test_queue_peek_wakes_on_post PASSED");
+    Ok(())
+}
+
+pub fn test_queue_reset<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_reset");
+    let queue = B::Queue::new(2, 4)?;
+
+    queue.post(&1u32.to_le_bytes(), 0)?;
+    queue.post(&2u32.to_le_bytes(), 0)?;
+    queue.reset()?;
+
+    let mut received = [0u8; 4];
+    assert!(matches!(queue.fetch(&mut received, 0), Err(Error::Timeout)));
+
+    // Room for two items again
+    queue.post(&3u32.to_le_bytes(), 0)?;
+    queue.post(&4u32.to_le_bytes(), 0)?;
+    queue.fetch(&mut received, 0)?;
+    assert_eq!(u32::from_le_bytes(received), 3);
+    log_info!(TAG, "test_queue_reset PASSED");
+    Ok(())
+}
+
+register_conformance! {
+    test_queue_creation,
+    test_queue_post_fetch,
+    test_queue_timeout,
+    test_queue_multiple_items,
+    test_queue_full_timeout,
+    test_queue_producer_consumer,
+    test_queue_post_to_front,
+    test_queue_overwrite,
+    test_queue_peek,
+    test_queue_peek_wakes_on_post,
+    test_queue_reset,
+}
@@ -0,0 +1,178 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::time::Duration;
+use osal_rs::os::*;
+use osal_rs::utils::{Error, OsalRsBool, Result};
+use osal_rs::{log_debug, log_info, select};
+
+use super::Backend;
+
+const TAG: &str = "QueueSetTests";
+
+pub fn test_queue_set_select<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_set_select");
+    let set = B::QueueSet::new(5)?;
+    let queue = B::Queue::new(4, 4)?;
+    let semaphore = B::Semaphore::new(1, 0)?;
+    set.add(&queue)?;
+    set.add(&semaphore)?;
+
+    assert!(matches!(set.select(Duration::from_millis(10).to_ticks()), Err(Error::Timeout)));
+
+    queue.post(&5u32.to_le_bytes(), 0)?;
+    assert_eq!(set.select(0)?, queue.member_handle());
+    let mut received = [0u8; 4];
+    queue.fetch(&mut received, 0)?;
+    assert_eq!(u32::from_le_bytes(received), 5);
+
+    assert_eq!(semaphore.signal(), OsalRsBool::True);
+    assert_eq!(set.select(0)?, semaphore.member_handle());
+    assert_eq!(semaphore.wait(Duration::ZERO), OsalRsBool::True);
+
+    // One entry per item, in the order they arrived
+    queue.post(&6u32.to_le_bytes(), 0)?;
+    semaphore.signal();
+    queue.post(&7u32.to_le_bytes(), 0)?;
+    assert_eq!(set.select(0)?, queue.member_handle());
+    assert_eq!(set.select(0)?, semaphore.member_handle());
+    assert_eq!(set.select(0)?, queue.member_handle());
+    assert!(matches!(set.select(0), Err(Error::Timeout)));
+    log_info!(TAG, "test_queue_set_select PASSED");
+    Ok(())
+}
+
+pub fn test_queue_set_wakes_on_post<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_set_wakes_on_post");
+    let set = B::QueueSet::new(8)?;
+    let first = Arc::new(B::Queue::new(4, 4)?);
+    let second = Arc::new(B::Queue::new(4, 4)?);
+    set.add(&*first)?;
+    set.add(&*second)?;
+
+    let first_clone = Arc::clone(&first);
+    let second_clone = Arc::clone(&second);
+    let mut thread = B::Thread::new("queue_set_poster", 1024, 5);
+    thread.spawn_simple(move || {
+        for i in 0..4u32 {
+            B::System::delay(Duration::from_millis(5).to_ticks());
+            let queue = if i % 2 == 0 { &first_clone } else { &second_clone };
+            queue.post(&i.to_le_bytes(), 0).unwrap();
+        }
+    })?;
+
+    let mut received = [0u8; 4];
+    for i in 0..4u32 {
+        let ready = set.select(Duration::from_millis(1000).to_ticks())?;
+        let queue = if ready == first.member_handle() { &first } else { &second };
+        assert_eq!(ready, queue.member_handle());
+        queue.fetch(&mut received, 0)?;
+        assert_eq!(u32::from_le_bytes(received), i);
+        assert_eq!(ready == first.member_handle(), i % 2 == 0);
+    }
+    log_debug!(TAG, "Selected 4 items from 2 queues");
+    log_info!(TAG, "test_queue_set_wakes_on_post PASSED");
+    Ok(())
+}
+
+pub fn test_queue_set_select_macro<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_set_select_macro");
+    let set = B::QueueSet::new(3)?;
+    let commands = B::Queue::new(2, 4)?;
+    let stop = B::Semaphore::new(1, 0)?;
+    set.add(&commands)?;
+    set.add(&stop)?;
+
+    commands.post(&1u32.to_le_bytes(), 0)?;
+    commands.post(&2u32.to_le_bytes(), 0)?;
+    stop.signal();
+
+    let mut sum = 0u32;
+    let mut timeouts = 0;
+    loop {
+        let stopped = select!(set, Duration::from_millis(10).to_ticks(), {
+            commands => {
+                let mut command = [0u8; 4];
+                commands.fetch(&mut command, 0)?;
+                sum += u32::from_le_bytes(command);
+                false
+            },
+            stop => stop.wait(Duration::ZERO) == OsalRsBool::True,
+            else => {
+                timeouts += 1;
+                true
+            },
+        });
+        if stopped {
+            break;
+        }
+    }
+    assert_eq!(sum, 3);
+    assert_eq!(timeouts, 0);
+    log_info!(TAG, "test_queue_set_select_macro PASSED");
+    Ok(())
+}
+
+pub fn test_queue_set_add_remove<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_set_add_remove");
+    let set = B::QueueSet::new(4)?;
+    let other = B::QueueSet::new(4)?;
+    let queue = B::Queue::new(4, 4)?;
+
+    set.add(&queue)?;
+    assert!(other.add(&queue).is_err());
+    assert!(other.remove(&queue).is_err());
+
+    set.remove(&queue)?;
+    queue.post(&1u32.to_le_bytes(), 0)?;
+    assert!(matches!(set.select(0), Err(Error::Timeout)));
+
+    let mut received = [0u8; 4];
+    queue.fetch(&mut received, 0)?;
+    other.add(&queue)?;
+    queue.post(&2u32.to_le_bytes(), 0)?;
+    assert_eq!(other.select(0)?, queue.member_handle());
+    log_info!(TAG, "test_queue_set_add_remove PASSED");
+    Ok(())
+}
+
+pub fn test_queue_set_from_isr<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_set_from_isr");
+    let set = B::QueueSet::new(2)?;
+    let semaphore = B::Semaphore::new(2, 0)?;
+    set.add(&semaphore)?;
+
+    assert!(matches!(set.select_from_isr(), Err(Error::Timeout)));
+    assert_eq!(semaphore.signal_from_isr(), OsalRsBool::True);
+    assert_eq!(set.select_from_isr()?, semaphore.member_handle());
+    assert_eq!(semaphore.wait_from_isr(), OsalRsBool::True);
+    log_info!(TAG, "test_queue_set_from_isr PASSED");
+    Ok(())
+}
+
+register_conformance! {
+    test_queue_set_select,
+    test_queue_set_wakes_on_post,
+    test_queue_set_select_macro,
+    test_queue_set_add_remove,
+    test_queue_set_from_isr,
+}
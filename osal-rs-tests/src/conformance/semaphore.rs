@@ -0,0 +1,266 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use osal_rs::os::*;
+use osal_rs::utils::{Result, OsalRsBool};
+use core::time::Duration;
+use osal_rs::{log_debug, log_info};
+
+use super::Backend;
+
+const TAG: &str = "SemaphoreTests";
+
+pub fn test_semaphore_creation<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_semaphore_creation");
+    let semaphore = B::Semaphore::new(5, 0);
+    assert!(semaphore.is_ok());
+    log_info!(TAG, "test_semaphore_creation PASSED");
+    Ok(())
+}
+
+pub fn test_semaphore_creation_with_count<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_semaphore_creation_with_count");
+    let semaphore = B::Semaphore::new_with_count(3);
+    assert!(semaphore.is_ok());
+    log_info!(TAG, "test_semaphore_creation_with_count PASSED");
+    Ok(())
+}
+
+pub fn test_semaphore_signal_wait<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_semaphore_signal_wait");
+    let semaphore = B::Semaphore::new(5, 0)?;
+    
+    let signal_result = semaphore.signal();
+    log_debug!(TAG, "Semaphore signal result: {:?}", signal_result);
+    assert_eq!(signal_result, OsalRsBool::True);
+    
+    let wait_result = semaphore.wait(Duration::from_millis(100));
+    log_debug!(TAG, "Semaphore wait result: {:?}", wait_result);
+    assert_eq!(wait_result, OsalRsBool::True);
+    log_info!(TAG, "test_semaphore_signal_wait PASSED");
+    Ok(())
+}
+
+pub fn test_semaphore_wait_timeout<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_semaphore_wait_timeout");
+    let semaphore = B::Semaphore::new(5, 0)?;
+    
+    let wait_result = semaphore.wait(Duration::from_millis(10));
+    log_debug!(TAG, "Wait timeout result: {:?}", wait_result);
+    assert_eq!(wait_result, OsalRsBool::False);
+    log_info!(TAG, "test_semaphore_wait_timeout PASSED");
+    Ok(())
+}
+
+pub fn test_semaphore_multiple_signals<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_semaphore_multiple_signals");
+    let semaphore = B::Semaphore::new(10, 0)?;
+    
+    log_debug!(TAG, "Signaling 5 times...");
+    for _ in 0..5 {
+        let result = semaphore.signal();
+        assert_eq!(result, OsalRsBool::True);
+    }
+    
+    log_debug!(TAG, "Waiting 5 times...");
+    for _ in 0..5 {
+        let result = semaphore.wait(Duration::from_millis(100));
+        assert_eq!(result, OsalRsBool::True);
+    }
+    
+    let result = semaphore.wait(Duration::from_millis(10));
+    assert_eq!(result, OsalRsBool::False);
+    log_info!(TAG, "test_semaphore_multiple_signals PASSED");
+    Ok(())
+}
+
+pub fn test_semaphore_max_count<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_semaphore_max_count");
+    let semaphore = B::Semaphore::new(3, 0)?;
+    
+    for _ in 0..3 {
+        semaphore.signal();
+    }
+    log_debug!(TAG, "Signaled 3 times (max count)");
+    assert_eq!(semaphore.signal(), OsalRsBool::False);
+    
+    for _ in 0..3 {
+        let result = semaphore.wait(Duration::from_millis(100));
+        assert_eq!(result, OsalRsBool::True);
+    }
+    log_info!(TAG, "test_semaphore_max_count PASSED");
+    Ok(())
+}
+
+pub fn test_semaphore_initial_count<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_semaphore_initial_count");
+    let semaphore = B::Semaphore::new(5, 3)?;
+    
+    log_debug!(TAG, "Testing initial count of 3...");
+    for _ in 0..3 {
+        let result = semaphore.wait(Duration::from_millis(100));
+        assert_eq!(result, OsalRsBool::True);
+    }
+    
+    let result = semaphore.wait(Duration::from_millis(10));
+    assert_eq!(result, OsalRsBool::False);
+    log_info!(TAG, "test_semaphore_initial_count PASSED");
+    Ok(())
+}
+
+pub fn test_semaphore_binary<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_semaphore_binary");
+    let semaphore = B::Semaphore::new(1, 1)?;
+    
+    let result = semaphore.wait(Duration::from_millis(100));
+    assert_eq!(result, OsalRsBool::True);
+    
+    let result = semaphore.wait(Duration::from_millis(10));
+    assert_eq!(result, OsalRsBool::False);
+    
+    log_debug!(TAG, "Signaling binary semaphore...");
+    semaphore.signal();
+    
+    let result = semaphore.wait(Duration::from_millis(100));
+    assert_eq!(result, OsalRsBool::True);
+    log_info!(TAG, "test_semaphore_binary PASSED");
+    Ok(())
+}
+
+pub fn test_semaphore_timeout_elapsed<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_semaphore_timeout_elapsed");
+    let semaphore = B::Semaphore::new(1, 0)?;
+
+    let start = B::System::get_tick_count();
+    let wait_result = semaphore.wait(Duration::from_millis(50));
+    let elapsed = B::System::get_tick_count().wrapping_sub(start);
+    log_debug!(TAG, "Wait timed out after {} ticks", elapsed);
+    assert_eq!(wait_result, OsalRsBool::False);
+    assert!(elapsed >= Duration::from_millis(50).to_ticks());
+    log_info!(TAG, "test_semaphore_timeout_elapsed PASSED");
+    Ok(())
+}
+
+pub fn test_semaphore_cross_thread<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_semaphore_cross_thread");
+    let semaphore = Arc::new(B::Semaphore::new(1, 0)?);
+    let semaphore_clone = Arc::clone(&semaphore);
+
+    let mut thread = B::Thread::new("sem_signal", 1024, 5);
+    thread.spawn_simple(move || {
+        B::System::delay(Duration::from_millis(20).to_ticks());
+        semaphore_clone.signal_from_isr();
+    })?;
+
+    let wait_result = semaphore.wait(Duration::from_millis(1000));
+    log_debug!(TAG, "Woken by other thread: {:?}", wait_result);
+    assert_eq!(wait_result, OsalRsBool::True);
+    log_info!(TAG, "test_semaphore_cross_thread PASSED");
+    Ok(())
+}
+
+pub fn test_semaphore_from_isr<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_semaphore_from_isr");
+    let semaphore = B::Semaphore::new(2, 0)?;
+
+    assert_eq!(semaphore.wait_from_isr(), OsalRsBool::False);
+    assert_eq!(semaphore.signal_from_isr(), OsalRsBool::True);
+    assert_eq!(semaphore.signal_from_isr(), OsalRsBool::True);
+    assert_eq!(semaphore.signal_from_isr(), OsalRsBool::False);
+    assert_eq!(semaphore.wait_from_isr(), OsalRsBool::True);
+    assert_eq!(semaphore.wait_from_isr(), OsalRsBool::True);
+    assert_eq!(semaphore.wait_from_isr(), OsalRsBool::False);
+    log_info!(TAG, "test_semaphore_from_isr PASSED");
+    Ok(())
+}
+
+pub fn test_semaphore_drop<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_semaphore_drop");
+    let semaphore = B::Semaphore::new(5, 2)?;
+    drop(semaphore);
+    log_info!(TAG, "test_semaphore_drop PASSED");
+    Ok(())
+}
+
+pub fn test_binary_semaphore_signal_wait<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_binary_semaphore_signal_wait");
+    let semaphore = B::BinarySemaphore::new()?;
+
+    assert_eq!(semaphore.wait(Duration::ZERO), OsalRsBool::False);
+    assert_eq!(semaphore.signal(), OsalRsBool::True);
+    log_debug!(TAG, "Signaling an available binary semaphore...");
+    assert_eq!(semaphore.signal(), OsalRsBool::False);
+
+    assert_eq!(semaphore.wait(Duration::from_millis(100)), OsalRsBool::True);
+    assert_eq!(semaphore.wait(Duration::from_millis(10)), OsalRsBool::False);
+    log_info!(TAG, "test_binary_semaphore_signal_wait PASSED");
+    Ok(())
+}
+
+pub fn test_binary_semaphore_from_isr<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_binary_semaphore_from_isr");
+    let semaphore = B::BinarySemaphore::new()?;
+
+    assert_eq!(semaphore.wait_from_isr(), OsalRsBool::False);
+    assert_eq!(semaphore.signal_from_isr(), OsalRsBool::True);
+    assert_eq!(semaphore.signal_from_isr(), OsalRsBool::False);
+    assert_eq!(semaphore.wait_from_isr(), OsalRsBool::True);
+    assert_eq!(semaphore.wait_from_isr(), OsalRsBool::False);
+    log_info!(TAG, "test_binary_semaphore_from_isr PASSED");
+    Ok(())
+}
+
+pub fn test_binary_semaphore_cross_thread<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_binary_semaphore_cross_thread");
+    let semaphore = Arc::new(B::BinarySemaphore::new()?);
+    let semaphore_clone = Arc::clone(&semaphore);
+
+    let mut thread = B::Thread::new("bsem_signal", 1024, 5);
+    thread.spawn_simple(move || {
+        B::System::delay(Duration::from_millis(20).to_ticks());
+        semaphore_clone.signal_from_isr();
+    })?;
+
+    let wait_result = semaphore.wait(Duration::from_millis(1000));
+    log_debug!(TAG, "Woken by other thread: {:?}", wait_result);
+    assert_eq!(wait_result, OsalRsBool::True);
+    log_info!(TAG, "test_binary_semaphore_cross_thread PASSED");
+    Ok(())
+}
+
+register_conformance! {
+    test_semaphore_creation,
+    test_semaphore_creation_with_count,
+    test_semaphore_signal_wait,
+    test_semaphore_wait_timeout,
+    test_semaphore_multiple_signals,
+    test_semaphore_max_count,
+    test_semaphore_initial_count,
+    test_semaphore_binary,
+    test_semaphore_timeout_elapsed,
+    test_semaphore_cross_thread,
+    test_semaphore_from_isr,
+    test_semaphore_drop,
+    test_binary_semaphore_signal_wait,
+    test_binary_semaphore_from_isr,
+    test_binary_semaphore_cross_thread,
+}
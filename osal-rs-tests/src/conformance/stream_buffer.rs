@@ -0,0 +1,163 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::time::Duration;
+use embedded_io::{Read, ReadReady, Write};
+use osal_rs::os::*;
+use osal_rs::utils::{Error, Result};
+use osal_rs::{log_debug, log_info};
+
+use super::Backend;
+
+const TAG: &str = "StreamBufferTests";
+
+pub fn test_stream_buffer_send_receive<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_stream_buffer_send_receive");
+    assert!(matches!(B::StreamBuffer::new(0, 0), Err(Error::InvalidQueueSize)));
+    assert!(matches!(B::StreamBuffer::new(8, 9), Err(Error::InvalidQueueSize)));
+
+    let stream = B::StreamBuffer::new(16, 1)?;
+    assert_eq!(stream.send(b"hello", Duration::ZERO), 5);
+    assert_eq!(stream.bytes_available(), 5);
+    assert_eq!(stream.spaces_available(), 11);
+
+    let mut buffer = [0u8; 3];
+    assert_eq!(stream.receive(&mut buffer, Duration::ZERO), 3);
+    assert_eq!(&buffer, b"hel");
+    assert_eq!(stream.receive(&mut buffer, Duration::ZERO), 2);
+    assert_eq!(&buffer[..2], b"lo");
+    assert_eq!(stream.receive(&mut buffer, Duration::from_millis(10)), 0);
+
+    // Only the bytes that fit are written, across the end of the ring
+    assert_eq!(stream.send(b"0123456789abcdefXYZ", Duration::ZERO), 16);
+    assert_eq!(stream.spaces_available(), 0);
+    assert_eq!(stream.send(b"!", Duration::from_millis(10)), 0);
+    let mut buffer = [0u8; 16];
+    assert_eq!(stream.receive(&mut buffer, Duration::ZERO), 16);
+    assert_eq!(&buffer, b"0123456789abcdef");
+
+    stream.send(b"stale", Duration::ZERO);
+    stream.reset()?;
+    assert_eq!(stream.bytes_available(), 0);
+    log_info!(TAG, "test_stream_buffer_send_receive PASSED");
+    Ok(())
+}
+
+pub fn test_stream_buffer_trigger_level<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_stream_buffer_trigger_level");
+    let stream = Arc::new(B::StreamBuffer::new(16, 4)?);
+    assert!(matches!(stream.set_trigger_level(17), Err(Error::InvalidQueueSize)));
+
+    let stream_clone = Arc::clone(&stream);
+    let mut thread = B::Thread::new("stream_writer", 1024, 5);
+    thread.spawn_simple(move || {
+        for byte in b"abcd" {
+            B::System::delay(Duration::from_millis(5).to_ticks());
+            stream_clone.send(&[*byte], Duration::ZERO);
+        }
+    })?;
+
+    // The reader is woken once the trigger level is reached
+    let mut buffer = [0u8; 16];
+    let received = stream.receive(&mut buffer, Duration::from_millis(1000));
+    assert_eq!(received, 4);
+    assert_eq!(&buffer[..4], b"abcd");
+
+    // On timeout the bytes below the trigger level are returned
+    stream.set_trigger_level(8)?;
+    let stream_clone = Arc::clone(&stream);
+    let mut thread = B::Thread::new("stream_writer_short", 1024, 5);
+    thread.spawn_simple(move || {
+        B::System::delay(Duration::from_millis(5).to_ticks());
+        stream_clone.send(b"xy", Duration::ZERO);
+    })?;
+    assert_eq!(stream.receive(&mut buffer, Duration::from_millis(50)), 2);
+    assert_eq!(&buffer[..2], b"xy");
+    log_info!(TAG, "test_stream_buffer_trigger_level PASSED");
+    Ok(())
+}
+
+pub fn test_stream_buffer_blocking_send<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_stream_buffer_blocking_send");
+    let stream = Arc::new(B::StreamBuffer::new(4, 1)?);
+    assert_eq!(stream.send(b"full", Duration::ZERO), 4);
+
+    let stream_clone = Arc::clone(&stream);
+    let mut thread = B::Thread::new("stream_reader", 1024, 5);
+    thread.spawn_simple(move || {
+        B::System::delay(Duration::from_millis(10).to_ticks());
+        let mut buffer = [0u8; 2];
+        stream_clone.receive(&mut buffer, Duration::ZERO);
+    })?;
+
+    assert_eq!(stream.send(b"ok", Duration::from_millis(1000)), 2);
+    let mut buffer = [0u8; 4];
+    assert_eq!(stream.receive(&mut buffer, Duration::ZERO), 4);
+    assert_eq!(&buffer, b"llok");
+    log_info!(TAG, "test_stream_buffer_blocking_send PASSED");
+    Ok(())
+}
+
+pub fn test_stream_buffer_from_isr<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_stream_buffer_from_isr");
+    let stream = B::StreamBuffer::new(4, 1)?;
+    let mut buffer = [0u8; 4];
+    assert_eq!(stream.receive_from_isr(&mut buffer), 0);
+    assert_eq!(stream.send_from_isr(b"isr!!"), 4);
+    assert_eq!(stream.send_from_isr(b"?"), 0);
+    assert_eq!(stream.receive_from_isr(&mut buffer), 4);
+    assert_eq!(&buffer, b"isr!");
+    log_info!(TAG, "test_stream_buffer_from_isr PASSED");
+    Ok(())
+}
+
+pub fn test_stream_buffer_embedded_io<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_stream_buffer_embedded_io");
+    let mut stream = B::StreamBuffer::new(32, 1)?;
+    assert!(!stream.read_ready()?);
+
+    stream.write_all(b"AT+OK\r\n")?;
+    let len = 42;
+    write!(stream, "len:{}", len).map_err(|_| Error::Unhandled("Formatted write failed"))?;
+    stream.flush()?;
+    assert!(stream.read_ready()?);
+
+    let mut line = [0u8; 7];
+    stream.read_exact(&mut line).map_err(|_| Error::Unhandled("Short read"))?;
+    assert_eq!(&line, b"AT+OK\r\n");
+
+    let mut rest = [0u8; 16];
+    let read = stream.read(&mut rest)?;
+    assert_eq!(&rest[..read], b"len:42");
+    assert_eq!(stream.read(&mut [])?, 0);
+    log_debug!(TAG, "Read {} bytes through embedded_io", line.len() + read);
+    log_info!(TAG, "test_stream_buffer_embedded_io PASSED");
+    Ok(())
+}
+
+register_conformance! {
+    test_stream_buffer_send_receive,
+    test_stream_buffer_trigger_level,
+    test_stream_buffer_blocking_send,
+    test_stream_buffer_from_isr,
+    test_stream_buffer_embedded_io,
+}
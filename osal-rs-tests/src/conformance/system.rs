@@ -0,0 +1,295 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::time::Duration;
+use osal_rs::os::*;
+use osal_rs::utils::{Result, OsalRsBool};
+use osal_rs::{log_debug, log_info, tick_rate_hz};
+
+use super::Backend;
+
+const TAG: &str = "SystemTests";
+
+pub fn test_system_get_tick_count<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_get_tick_count");
+    let start = B::System::get_tick_count();
+    B::System::delay(2);
+    let tick_count = B::System::get_tick_count();
+    log_debug!(TAG, "Current tick count: {}", tick_count);
+    assert!(tick_count >= start + 2);
+    log_info!(TAG, "test_system_get_tick_count PASSED");
+    Ok(())
+}
+
+pub fn test_system_get_current_time<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_get_current_time");
+    let time = B::System::get_current_time_us();
+    log_debug!(TAG, "Current time: {} us", time.as_micros());
+    assert!(time.as_micros() > 0);
+    log_info!(TAG, "test_system_get_current_time PASSED");
+    Ok(())
+}
+
+pub fn test_system_count_threads<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_count_threads");
+    let before = B::System::count_threads();
+    let release = Arc::new(B::Semaphore::new(1, 0)?);
+    let release_clone = Arc::clone(&release);
+
+    let mut thread = B::Thread::new("count_thread", 1024, 5);
+    thread.spawn_simple(move || {
+        release_clone.wait(Duration::from_millis(1000));
+    })?;
+
+    let during = B::System::count_threads();
+    release.signal();
+
+    log_debug!(TAG, "Number of threads: {} -> {}", before, during);
+    assert!(before > 0);
+    assert_eq!(during, before + 1);
+    log_info!(TAG, "test_system_count_threads PASSED");
+    Ok(())
+}
+
+pub fn test_system_get_all_threads<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_get_all_threads");
+    let release = Arc::new(B::Semaphore::new(1, 0)?);
+    let release_clone = Arc::clone(&release);
+    let mut thread = B::Thread::new("listed_thread", 1024, 5);
+    let spawned = thread.spawn_simple(move || {
+        release_clone.wait(Duration::from_millis(1000));
+    })?;
+
+    let state = B::System::get_all_thread();
+    log_debug!(TAG, "Total threads: {}, Total runtime: {}", state.tasks.len(), state.total_run_time);
+    let listed = state.iter().find(|meta| meta.name == "listed_thread");
+    assert!(!state.tasks.is_empty());
+    assert!(listed.is_some());
+    if let Some(meta) = listed {
+        assert_eq!(meta.thread, spawned.get_metadata().thread);
+        assert_eq!(meta.priority, 5);
+    }
+
+    release.signal();
+    log_info!(TAG, "test_system_get_all_threads PASSED");
+    Ok(())
+}
+
+pub fn test_system_delay<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_delay");
+    let start = B::System::get_tick_count();
+    log_debug!(TAG, "Delaying 10ms...");
+    B::System::delay(Duration::from_millis(10).to_ticks());
+    let end = B::System::get_tick_count();
+    
+    log_debug!(TAG, "Delay completed. Start: {}, End: {}", start, end);
+    assert!(end.wrapping_sub(start) >= Duration::from_millis(10).to_ticks());
+    log_info!(TAG, "test_system_delay PASSED");
+    Ok(())
+}
+
+pub fn test_system_delay_until<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_delay_until");
+    let mut wake_time = B::System::get_tick_count();
+    let increment = Duration::from_millis(10).to_ticks();
+    
+    log_debug!(TAG, "Wake time: {}, Increment: {}", wake_time, increment);
+    B::System::delay_until(&mut wake_time, increment);
+    
+    assert!(wake_time > 0);
+    log_info!(TAG, "test_system_delay_until PASSED");
+    Ok(())
+}
+
+pub fn test_system_critical_section<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_critical_section");
+    log_debug!(TAG, "Entering critical section");
+    B::System::critical_section_enter();
+    // Critical section code
+    B::System::critical_section_exit();
+    log_debug!(TAG, "Exited critical section");
+    log_info!(TAG, "test_system_critical_section PASSED");
+    Ok(())
+}
+
+pub fn test_system_delay_until_no_drift<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_delay_until_no_drift");
+    let increment = Duration::from_millis(10).to_ticks();
+    let start = B::System::get_tick_count();
+    let mut wake_time = start;
+
+    for _ in 0..10 {
+        // Work done between two wake-ups must not push the next one back
+        B::System::delay(Duration::from_millis(3).to_ticks());
+        B::System::delay_until(&mut wake_time, increment);
+    }
+
+    let elapsed = B::System::get_tick_count().wrapping_sub(start);
+    log_debug!(TAG, "10 periods of {} ticks took {} ticks", increment, elapsed);
+    assert_eq!(wake_time, start.wrapping_add(10 * increment));
+    assert!(elapsed >= 10 * increment);
+    assert!(elapsed < 10 * increment + Duration::from_millis(20).to_ticks());
+    log_info!(TAG, "test_system_delay_until_no_drift PASSED");
+    Ok(())
+}
+
+pub fn test_system_delay_until_late<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_delay_until_late");
+    let increment = Duration::from_millis(10).to_ticks();
+    let mut wake_time = B::System::get_tick_count();
+    B::System::delay(Duration::from_millis(30).to_ticks());
+
+    // The wake time has already passed: no block, the timeline still advances
+    let start = B::System::get_tick_count();
+    B::System::delay_until(&mut wake_time, increment);
+    let elapsed = B::System::get_tick_count().wrapping_sub(start);
+    log_debug!(TAG, "Late delay_until blocked for {} ticks", elapsed);
+    assert!(elapsed < increment);
+    log_info!(TAG, "test_system_delay_until_late PASSED");
+    Ok(())
+}
+
+pub fn test_system_suspend_resume_all<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_suspend_resume_all");
+    log_debug!(TAG, "Suspending all threads");
+    B::System::suspend_all();
+    let result = B::System::resume_all();
+    log_debug!(TAG, "Resumed all threads, result: {}", result);
+    assert!(result >= 0);
+    log_info!(TAG, "test_system_suspend_resume_all PASSED");
+    Ok(())
+}
+
+pub fn test_system_check_timer<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_check_timer");
+    let timestamp = B::System::get_current_time_us();
+    let wait_time = Duration::from_millis(10);
+    
+    // Should be false immediately
+    let result = B::System::check_timer(&timestamp, &wait_time);
+    log_debug!(TAG, "Check timer immediately: {:?}", result);
+    assert_eq!(result, OsalRsBool::False);
+    
+    // Wait for the duration
+    B::System::delay(wait_time.to_ticks());
+    
+    // Should be true after waiting
+    let result = B::System::check_timer(&timestamp, &wait_time);
+    log_debug!(TAG, "Check timer after delay: {:?}", result);
+    assert_eq!(result, OsalRsBool::True);
+    log_info!(TAG, "test_system_check_timer PASSED");
+    Ok(())
+}
+
+pub fn test_system_get_free_heap_size<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_get_free_heap_size");
+    let heap_size = B::System::get_free_heap_size();
+    log_debug!(TAG, "Free heap size: {} bytes", heap_size);
+    assert!(heap_size > 0);
+    log_info!(TAG, "test_system_get_free_heap_size PASSED");
+    Ok(())
+}
+
+pub fn test_system_get_state<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_get_state");
+    let state = B::System::get_state();
+    log_debug!(TAG, "Current thread state: {:?}", state);
+    // Current thread should be in Running state
+    assert!(matches!(state, ThreadState::Running | ThreadState::Ready));
+    log_info!(TAG, "test_system_get_state PASSED");
+    Ok(())
+}
+
+pub fn test_system_thread_metadata<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_thread_metadata");
+    let state = B::System::get_all_thread();
+    
+    for thread_meta in state.tasks.iter() {
+        assert!(!thread_meta.thread.is_null());
+        assert!(!thread_meta.name.is_empty());
+        assert!(thread_meta.priority > 0);
+    }
+    log_debug!(TAG, "Verified metadata for {} threads", state.tasks.len());
+    log_info!(TAG, "test_system_thread_metadata PASSED");
+    Ok(())
+}
+
+pub fn test_system_multiple_delays<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_multiple_delays");
+    let start = B::System::get_tick_count();
+    
+    log_debug!(TAG, "Performing 3 delays of 5ms each");
+    for _ in 0..3 {
+        B::System::delay(Duration::from_millis(5).to_ticks());
+    }
+    
+    let end = B::System::get_tick_count();
+    log_debug!(TAG, "Total delay completed. Start: {}, End: {}", start, end);
+    assert!(end > start);
+    log_info!(TAG, "test_system_multiple_delays PASSED");
+    Ok(())
+}
+
+pub fn test_system_time_monotonic<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_time_monotonic");
+    let time1 = B::System::get_current_time_us();
+    B::System::delay(Duration::from_millis(10).to_ticks());
+    let time2 = B::System::get_current_time_us();
+    
+    log_debug!(TAG, "Time1: {} us, Time2: {} us", time1.as_micros(), time2.as_micros());
+    assert!(time2 >= time1);
+    log_info!(TAG, "test_system_time_monotonic PASSED");
+    Ok(())
+}
+
+pub fn test_system_time_conversion<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_system_time_conversion");
+    let duration = Duration::from_millis(100);
+    let ticks = B::System::get_ticks_from_duration(&duration);
+    log_debug!(TAG, "100ms = {} ticks", ticks);
+    assert!(ticks > 0);
+    assert_eq!(ticks, duration.to_ticks());
+    // One second is as many ticks as the tick rate
+    assert_eq!(B::System::get_ticks_from_duration(&Duration::from_secs(1)), tick_rate_hz!());
+    log_info!(TAG, "test_system_time_conversion PASSED");
+    Ok(())
+}
+
+register_conformance! {
+    test_system_get_tick_count,
+    test_system_get_current_time,
+    test_system_count_threads,
+    test_system_get_all_threads,
+    test_system_delay,
+    test_system_delay_until,
+    test_system_critical_section,
+    test_system_delay_until_no_drift,
+    test_system_delay_until_late,
+    test_system_suspend_resume_all,
+    test_system_check_timer,
+    test_system_get_free_heap_size,
+    test_system_get_state,
+    test_system_thread_metadata,
+    test_system_multiple_delays,
+    test_system_time_monotonic,
+    test_system_time_conversion,
+}
@@ -0,0 +1,599 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::any::Any;
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use core::time::Duration;
+use osal_rs::os::*;
+use osal_rs::os::types::{BaseType, UBaseType};
+use osal_rs::utils::{Error, OsalRsBool, Result};
+use osal_rs::{log_debug, log_info, task_notification_array_entries};
+
+use super::Backend;
+
+const TAG: &str = "ThreadTests";
+
+pub fn test_thread_creation<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_creation");
+    let thread = B::Thread::new(
+        "test_thread",
+        1024,
+        5
+    );
+
+    let metadata = thread.get_metadata();
+    log_debug!(TAG, "Thread metadata: name={}, stack={}, priority={}", metadata.name, metadata.stack_depth, metadata.priority);
+    assert_eq!(metadata.stack_depth, 1024);
+    assert_eq!(metadata.priority, 5);
+    log_info!(TAG, "test_thread_creation PASSED");
+    Ok(())
+}
+
+pub fn test_thread_spawn<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_spawn");
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+    let done = Arc::new(B::Semaphore::new(1, 0)?);
+    let done_clone = Arc::clone(&done);
+
+    let mut thread = B::Thread::new(
+        "spawn_test",
+        1024,
+        5
+    );
+
+    let spawned = thread.spawn(None, move |_thread, param| {
+        COUNTER.fetch_add(1, Ordering::SeqCst);
+        done_clone.signal();
+        Ok(param.unwrap_or_else(|| Arc::new(())))
+    })?;
+
+    let metadata = spawned.get_metadata();
+    log_debug!(TAG, "Spawned thread handle: {:?}", metadata.thread);
+    assert!(!metadata.thread.is_null());
+    assert_eq!(done.wait(Duration::from_millis(1000)), OsalRsBool::True);
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 1);
+    log_info!(TAG, "test_thread_spawn PASSED");
+    Ok(())
+}
+
+pub fn test_thread_with_param<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_with_param");
+    static RECEIVED_VALUE: AtomicU32 = AtomicU32::new(0);
+    let done = Arc::new(B::Semaphore::new(1, 0)?);
+    let done_clone = Arc::clone(&done);
+
+    let param: Arc<dyn Any + Send + Sync> = Arc::new(42u32);
+
+    let mut thread = B::Thread::new(
+        "param_test",
+        1024,
+        5
+    );
+
+    thread.spawn(Some(param), move |_thread, param| {
+        if let Some(val) = param.as_ref().and_then(|p| p.downcast_ref::<u32>()) {
+            RECEIVED_VALUE.store(*val, Ordering::SeqCst);
+        }
+        done_clone.signal();
+        Ok(param.unwrap_or_else(|| Arc::new(())))
+    })?;
+
+    assert_eq!(done.wait(Duration::from_millis(1000)), OsalRsBool::True);
+    assert_eq!(RECEIVED_VALUE.load(Ordering::SeqCst), 42);
+    log_info!(TAG, "test_thread_with_param PASSED");
+    Ok(())
+}
+
+pub fn test_thread_spawn_with_param<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_spawn_with_param");
+    struct Job {
+        samples: [u32; 4],
+        scale: u32,
+    }
+
+    let mut thread = B::Thread::new("typed_param", 1024, 5);
+    let mut worker = thread.spawn_with_param(Job { samples: [1, 2, 3, 4], scale: 3 }, |job| {
+        job.samples.iter().map(|sample| sample * job.scale).sum::<u32>()
+    })?;
+
+    assert_eq!(worker.join(Duration::from_millis(2000).to_ticks())?, 30);
+    log_info!(TAG, "test_thread_spawn_with_param PASSED");
+    Ok(())
+}
+
+pub fn test_thread_suspend_resume<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_suspend_resume");
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+    let done = Arc::new(B::Semaphore::new(1, 0)?);
+    let done_clone = Arc::clone(&done);
+
+    let mut thread = B::Thread::new(
+        "suspend_test",
+        1024,
+        5
+    );
+
+    let spawned = thread.spawn(None, move |_thread, param| {
+        for _ in 0..10 {
+            COUNTER.fetch_add(1, Ordering::SeqCst);
+            B::System::delay(Duration::from_millis(5).to_ticks());
+        }
+        done_clone.signal();
+        Ok(param.unwrap_or_else(|| Arc::new(())))
+    })?;
+
+    B::System::delay(Duration::from_millis(12).to_ticks());
+    log_debug!(TAG, "Suspending thread...");
+    spawned.suspend();
+
+    let count = COUNTER.load(Ordering::SeqCst);
+    B::System::delay(Duration::from_millis(50).to_ticks());
+    assert_eq!(COUNTER.load(Ordering::SeqCst), count);
+
+    log_debug!(TAG, "Resuming thread...");
+    spawned.resume();
+    assert_eq!(done.wait(Duration::from_millis(1000)), OsalRsBool::True);
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 10);
+    log_info!(TAG, "test_thread_suspend_resume PASSED");
+    Ok(())
+}
+
+pub fn test_thread_get_metadata<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_get_metadata");
+    let release = Arc::new(B::Semaphore::new(1, 0)?);
+    let release_clone = Arc::clone(&release);
+
+    let mut thread = B::Thread::new(
+        "metadata_test",
+        1024,
+        5
+    );
+
+    let spawned = thread.spawn(None, move |_thread, param| {
+        release_clone.wait(Duration::from_millis(1000));
+        Ok(param.unwrap_or_else(|| Arc::new(())))
+    })?;
+
+    let metadata = spawned.get_metadata();
+
+    log_debug!(TAG, "Metadata - name: {}, priority: {}", metadata.name, metadata.priority);
+    assert_eq!(metadata.name, "metadata_test");
+    assert_eq!(metadata.priority, 5);
+
+    release.signal();
+    log_info!(TAG, "test_thread_get_metadata PASSED");
+    Ok(())
+}
+
+pub fn test_thread_get_current<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_get_current");
+    let current = B::Thread::get_current();
+    let metadata = current.get_metadata();
+    log_debug!(TAG, "Current thread: {}", metadata.name);
+    assert!(!metadata.thread.is_null());
+    log_info!(TAG, "test_thread_get_current PASSED");
+    Ok(())
+}
+
+pub fn test_thread_spawn_simple<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_spawn_simple");
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+    let done = Arc::new(B::Semaphore::new(1, 0)?);
+    let done_clone = Arc::clone(&done);
+
+    let mut thread = B::Thread::new(
+        "simple_test",
+        1024,
+        5
+    );
+
+    thread.spawn_simple(move || {
+        log_debug!(TAG, "Simple thread executing");
+        COUNTER.fetch_add(1, Ordering::SeqCst);
+        done_clone.signal();
+    })?;
+
+    assert_eq!(done.wait(Duration::from_millis(1000)), OsalRsBool::True);
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 1);
+    log_info!(TAG, "test_thread_spawn_simple PASSED");
+    Ok(())
+}
+
+pub fn test_thread_spawn_simple_with_shared_data<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_spawn_simple_with_shared_data");
+
+    let counter = Arc::new(B::Mutex::new(0u32));
+    let counter_clone = Arc::clone(&counter);
+    let done = Arc::new(B::Semaphore::new(1, 0)?);
+    let done_clone = Arc::clone(&done);
+
+    let mut thread = B::Thread::new(
+        "shared_data_test",
+        1024,
+        5
+    );
+
+    thread.spawn_simple(move || {
+        for _ in 0..5 {
+            let mut num = counter_clone.lock().unwrap();
+            *num += 1;
+            log_debug!(TAG, "Counter: {}", *num);
+        }
+        done_clone.signal();
+    })?;
+
+    assert_eq!(done.wait(Duration::from_millis(1000)), OsalRsBool::True);
+
+    let final_count = *counter.lock()?;
+    log_debug!(TAG, "Final counter value: {}", final_count);
+    assert_eq!(final_count, 5);
+
+    log_info!(TAG, "test_thread_spawn_simple_with_shared_data PASSED");
+    Ok(())
+}
+
+pub fn test_thread_notification<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_notification");
+    static RECEIVED: AtomicU32 = AtomicU32::new(0);
+    let done = Arc::new(B::Semaphore::new(1, 0)?);
+    let done_clone = Arc::clone(&done);
+
+    let mut thread = B::Thread::new(
+        "notify_test",
+        1024,
+        5
+    );
+
+    let spawned = thread.spawn(None, move |thread, _param| {
+        let notification = thread.wait_notification(0, 0xFFFFFFFF, Duration::from_millis(1000).to_ticks());
+        if let Ok(notification) = notification {
+            log_debug!(TAG, "Received notification: 0x{:X}", notification);
+            RECEIVED.store(notification, Ordering::SeqCst);
+        }
+        done_clone.signal();
+        Ok(Arc::new(()))
+    })?;
+
+    B::System::delay(Duration::from_millis(10).to_ticks());
+    log_debug!(TAG, "Sending notification: 0x12345678");
+    let notify_result = spawned.notify(ThreadNotification::SetValueWithOverwrite(0x12345678));
+    assert!(notify_result.is_ok());
+
+    assert_eq!(done.wait(Duration::from_millis(1000)), OsalRsBool::True);
+    assert_eq!(RECEIVED.load(Ordering::SeqCst), 0x12345678);
+    log_info!(TAG, "test_thread_notification PASSED");
+    Ok(())
+}
+
+/// Drops a pending notification and zeroes the value left by a previous test
+fn clear_notification(thread: &impl ThreadFn) {
+    let _ = thread.wait_notification(0xFFFFFFFF, 0xFFFFFFFF, 0);
+}
+
+pub fn test_thread_notification_actions<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_notification_actions");
+    let current = B::Thread::get_current();
+
+    clear_notification(&current);
+
+    current.notify(ThreadNotification::SetBits(0b0001))?;
+    current.notify(ThreadNotification::SetBits(0b0100))?;
+    assert_eq!(current.wait_notification(0, 0, 0)?, 0b0101);
+
+    current.notify(ThreadNotification::Increment)?;
+    current.notify(ThreadNotification::Increment)?;
+    assert_eq!(current.wait_notification(0, 0xFFFFFFFF, 0)?, 0b0111);
+
+    current.notify(ThreadNotification::SetValueWithoutOverwrite(10))?;
+    let result = current.notify(ThreadNotification::SetValueWithoutOverwrite(20));
+    log_debug!(TAG, "Overwrite of a pending value: {:?}", result);
+    assert!(result.is_err());
+    current.notify(ThreadNotification::SetValueWithOverwrite(30))?;
+    assert_eq!(current.wait_notification(0, 0, 0)?, 30);
+
+    current.notify(ThreadNotification::NoAction)?;
+    assert_eq!(current.wait_notification(0, 0, 0)?, 30);
+    log_info!(TAG, "test_thread_notification_actions PASSED");
+    Ok(())
+}
+
+pub fn test_thread_notification_clear_bits<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_notification_clear_bits");
+    let current = B::Thread::get_current();
+    clear_notification(&current);
+
+    // Exit bits are cleared after the value is returned
+    current.notify(ThreadNotification::SetBits(0xF0))?;
+    assert_eq!(current.wait_notification(0, 0x30, 0)?, 0xF0);
+    current.notify(ThreadNotification::NoAction)?;
+    assert_eq!(current.wait_notification(0, 0, 0)?, 0xC0);
+
+    // Entry bits are not cleared when a notification is already pending
+    current.notify(ThreadNotification::SetBits(0x01))?;
+    assert_eq!(current.wait_notification(0xFF, 0, 0)?, 0xC1);
+
+    // ...but are when nothing is pending, even if the wait then times out
+    assert!(current.wait_notification(0x80, 0, 0).is_err());
+    current.notify(ThreadNotification::NoAction)?;
+    assert_eq!(current.wait_notification(0, 0, 0)?, 0x41);
+    log_info!(TAG, "test_thread_notification_clear_bits PASSED");
+    Ok(())
+}
+
+pub fn test_thread_notification_timeout<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_notification_timeout");
+    let current = B::Thread::get_current();
+    clear_notification(&current);
+
+    let start = B::System::get_tick_count();
+    let result = current.wait_notification(0, 0, Duration::from_millis(20).to_ticks());
+    let elapsed = B::System::get_tick_count().wrapping_sub(start);
+    log_debug!(TAG, "Wait timed out after {} ticks", elapsed);
+    assert!(matches!(result, Err(Error::Timeout)));
+    assert!(elapsed >= Duration::from_millis(20).to_ticks());
+    log_info!(TAG, "test_thread_notification_timeout PASSED");
+    Ok(())
+}
+
+/// Indexed cases need entries 1 and 2 of the notification array
+fn has_notification_array() -> bool {
+    let entries = task_notification_array_entries!();
+    if entries < 3 {
+        log_info!(TAG, "Only {} notification entries, skipped", entries);
+    }
+    entries >= 3
+}
+
+/// `clear_notification` on entry `index`
+fn clear_notification_indexed(thread: &impl ThreadFn, index: UBaseType) {
+    let _ = thread.wait_notification_indexed(index, 0xFFFFFFFF, 0xFFFFFFFF, 0);
+}
+
+pub fn test_thread_notification_indexed<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_notification_indexed");
+    if !has_notification_array() {
+        return Ok(());
+    }
+    let current = B::Thread::get_current();
+    clear_notification(&current);
+    clear_notification_indexed(&current, 1);
+
+    // Each entry is a channel of its own
+    current.notify_indexed(1, ThreadNotification::SetBits(0x10))?;
+    assert!(matches!(current.wait_notification(0, 0, 0), Err(Error::Timeout)));
+    assert_eq!(current.wait_notification_indexed(1, 0, 0, 0)?, 0x10);
+
+    assert_eq!(current.notify_and_query(1, ThreadNotification::SetBits(0x01))?, 0x10);
+    assert_eq!(current.notify_and_query(1, ThreadNotification::SetValueWithOverwrite(7))?, 0x11);
+    assert_eq!(current.wait_notification_indexed(1, 0, 0xFFFFFFFF, 0)?, 7);
+
+    let entries = task_notification_array_entries!() as UBaseType;
+    assert!(matches!(current.notify_indexed(entries, ThreadNotification::Increment), Err(Error::OutOfIndex)));
+    assert!(matches!(current.wait_notification_indexed(entries, 0, 0, 0), Err(Error::OutOfIndex)));
+    log_info!(TAG, "test_thread_notification_indexed PASSED");
+    Ok(())
+}
+
+pub fn test_thread_notify_give_take<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_notify_give_take");
+    if !has_notification_array() {
+        return Ok(());
+    }
+    let current = B::Thread::get_current();
+    clear_notification_indexed(&current, 2);
+
+    for _ in 0..3 {
+        current.notify_give(2)?;
+    }
+    assert_eq!(current.notify_take(2, false, 0)?, 3);
+    assert_eq!(current.notify_take(2, false, 0)?, 2);
+    // As a binary semaphore the count is cleared
+    assert_eq!(current.notify_take(2, true, 0)?, 1);
+    assert!(matches!(current.notify_take(2, false, 0), Err(Error::Timeout)));
+
+    let mut woken: BaseType = 0;
+    current.notify_give_from_isr(2, &mut woken)?;
+    assert_eq!(current.notify_take(2, true, 0)?, 1);
+    log_info!(TAG, "test_thread_notify_give_take PASSED");
+    Ok(())
+}
+
+pub fn test_thread_notify_take_cross_thread<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_notify_take_cross_thread");
+    if !has_notification_array() {
+        return Ok(());
+    }
+    static TAKEN: AtomicU32 = AtomicU32::new(0);
+    TAKEN.store(0, Ordering::SeqCst);
+    let done = Arc::new(B::Semaphore::new(1, 0)?);
+    let done_clone = Arc::clone(&done);
+
+    let mut thread = B::Thread::new("notify_take", 1024, 5);
+    let spawned = thread.spawn(None, move |thread, _param| {
+        while thread.notify_take(1, false, Duration::from_millis(1000).to_ticks()).is_ok() {
+            if TAKEN.fetch_add(1, Ordering::SeqCst) == 2 {
+                break;
+            }
+        }
+        done_clone.signal();
+        Ok(Arc::new(()))
+    })?;
+
+    for _ in 0..3 {
+        B::System::delay(Duration::from_millis(5).to_ticks());
+        spawned.notify_give(1)?;
+    }
+    assert_eq!(done.wait(Duration::from_millis(2000)), OsalRsBool::True);
+    log_debug!(TAG, "Taken {} times", TAKEN.load(Ordering::SeqCst));
+    assert_eq!(TAKEN.load(Ordering::SeqCst), 3);
+    log_info!(TAG, "test_thread_notify_take_cross_thread PASSED");
+    Ok(())
+}
+
+pub fn test_thread_notification_clear_state<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_notification_clear_state");
+    if !has_notification_array() {
+        return Ok(());
+    }
+    let current = B::Thread::get_current();
+    clear_notification_indexed(&current, 1);
+
+    current.notify_indexed(1, ThreadNotification::SetValueWithOverwrite(5))?;
+    assert!(current.clear_state(1)?);
+    assert!(!current.clear_state(1)?);
+    assert!(matches!(current.wait_notification_indexed(1, 0, 0, 0), Err(Error::Timeout)));
+
+    // The value is kept, only the pending state is gone
+    current.notify_indexed(1, ThreadNotification::NoAction)?;
+    assert_eq!(current.wait_notification_indexed(1, 0, 0, 0)?, 5);
+    log_info!(TAG, "test_thread_notification_clear_state PASSED");
+    Ok(())
+}
+
+pub fn test_thread_join_result<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_join_result");
+    let mut thread = B::Thread::new("join_result", 1024, 5);
+    let mut worker = thread.spawn_joinable(|| (1..=10u32).sum::<u32>())?;
+
+    assert_eq!(worker.join(Duration::from_millis(2000).to_ticks())?, 55);
+    assert!(worker.is_finished());
+    assert!(worker.join(0).is_err());
+    log_info!(TAG, "test_thread_join_result PASSED");
+    Ok(())
+}
+
+pub fn test_thread_join_timeout<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_join_timeout");
+    let mut thread = B::Thread::new("join_timeout", 1024, 5);
+    let mut worker = thread.spawn_joinable(|| {
+        B::Thread::get_current().notify_take(0, true, Duration::from_millis(2000).to_ticks())
+    })?;
+
+    // Still waiting for the notification: the handle stays joinable
+    assert!(matches!(worker.join(Duration::from_millis(20).to_ticks()), Err(Error::Timeout)));
+    assert!(!worker.is_finished());
+
+    worker.thread().notify_give(0)?;
+    assert_eq!(worker.join(Duration::from_millis(2000).to_ticks())?, Ok(1));
+    log_info!(TAG, "test_thread_join_timeout PASSED");
+    Ok(())
+}
+
+pub fn test_thread_join_waits<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_join_waits");
+    static STEPS: AtomicU32 = AtomicU32::new(0);
+    STEPS.store(0, Ordering::SeqCst);
+
+    let mut thread = B::Thread::new("join_waits", 1024, 5);
+    let spawned = thread.spawn_simple(|| {
+        for _ in 0..3 {
+            B::System::delay(Duration::from_millis(5).to_ticks());
+            STEPS.fetch_add(1, Ordering::SeqCst);
+        }
+    })?;
+
+    spawned.join(core::ptr::null_mut())?;
+    assert_eq!(STEPS.load(Ordering::SeqCst), 3);
+    log_info!(TAG, "test_thread_join_waits PASSED");
+    Ok(())
+}
+
+pub fn test_thread_join_frees_heap<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_join_frees_heap");
+    let run = || -> Result<()> {
+        let mut thread = B::Thread::new("join_heap", 1024, 5);
+        thread.spawn_simple(|| {})?.join(core::ptr::null_mut())?;
+        thread.spawn(None, |_, _| Ok(Arc::new(())))?.join(core::ptr::null_mut())?;
+        thread.spawn_joinable(|| 1u32)?.join(Duration::from_millis(2000).to_ticks())?;
+        // Deleted tasks are freed by the idle task
+        B::System::delay(Duration::from_millis(20).to_ticks());
+        Ok(())
+    };
+
+    run()?;
+    let heap_size = B::System::get_free_heap_size();
+    for _ in 0..10 {
+        run()?;
+    }
+    log_debug!(TAG, "Free heap size: {} -> {} bytes", heap_size, B::System::get_free_heap_size());
+    assert_eq!(B::System::get_free_heap_size(), heap_size);
+    log_info!(TAG, "test_thread_join_frees_heap PASSED");
+    Ok(())
+}
+
+pub fn test_thread_join_deleted<B: Backend>() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_join_deleted");
+    let threads = B::System::count_threads();
+    let running = Arc::new(AtomicBool::new(true));
+    let running_clone = Arc::clone(&running);
+
+    let mut thread = B::Thread::new("join_deleted", 1024, 5);
+    let spawned = thread.spawn_simple(move || {
+        while running_clone.load(Ordering::SeqCst) {
+            B::System::delay(Duration::from_millis(1).to_ticks());
+        }
+    })?;
+    B::System::delay(Duration::from_millis(5).to_ticks());
+    spawned.delete();
+
+    // The callback never returns on FreeRTOS: join must not wait for it
+    let start = B::System::get_tick_count();
+    assert!(spawned.join(core::ptr::null_mut()).is_err());
+    assert!(B::System::get_tick_count().wrapping_sub(start) < Duration::from_millis(100).to_ticks());
+
+    // A pthread is only detached by `delete`, it ends when its callback returns
+    running.store(false, Ordering::SeqCst);
+    for _ in 0..100 {
+        if B::System::count_threads() <= threads {
+            break;
+        }
+        B::System::delay(Duration::from_millis(10).to_ticks());
+    }
+    assert!(B::System::count_threads() <= threads);
+    log_info!(TAG, "test_thread_join_deleted PASSED");
+    Ok(())
+}
+
+register_conformance! {
+    test_thread_creation,
+    test_thread_spawn,
+    test_thread_with_param,
+    test_thread_spawn_with_param,
+    test_thread_suspend_resume,
+    test_thread_get_metadata,
+    test_thread_get_current,
+    test_thread_spawn_simple,
+    test_thread_spawn_simple_with_shared_data,
+    test_thread_notification,
+    test_thread_notification_actions,
+    test_thread_notification_clear_bits,
+    test_thread_notification_timeout,
+    test_thread_notification_indexed,
+    test_thread_notify_give_take,
+    test_thread_notify_take_cross_thread,
+    test_thread_notification_clear_state,
+    test_thread_join_result,
+    test_thread_join_timeout,
+    test_thread_join_waits,
+    test_thread_join_frees_heap,
+    test_thread_join_deleted,
+}
@@ -108,15 +108,12 @@ pub fn test_duration_large_value() -> Result<()> {
     Ok(())
 }
 
-pub fn run_all_tests() -> Result<()> {
-    log_info!(TAG, "========== Running Duration Tests ==========");
-    test_duration_to_ticks()?;
-    test_duration_from_ticks()?;
-    test_duration_conversion_roundtrip()?;
-    test_duration_zero()?;
-    test_duration_one_second()?;
-    test_duration_microseconds()?;
-    test_duration_large_value()?;
-    log_info!(TAG, "========== All Duration Tests PASSED ==========");
-    Ok(())
+register_tests! {
+    test_duration_to_ticks,
+    test_duration_from_ticks,
+    test_duration_conversion_roundtrip,
+    test_duration_zero,
+    test_duration_one_second,
+    test_duration_microseconds,
+    test_duration_large_value,
 }
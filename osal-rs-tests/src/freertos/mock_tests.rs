@@ -0,0 +1,653 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use alloc::sync::Arc;
+use alloc::vec;
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::time::Duration;
+use osal_rs::os::*;
+use osal_rs::os::mock::{Arg, Mock};
+use osal_rs::utils::{Error, OsalRsBool, Result};
+use osal_rs::log_info;
+
+use crate::conformance::message_buffer::Frame;
+
+const TAG: &str = "MockTests";
+
+const PD_FAIL: i64 = 0;
+
+pub fn test_mock_queue_calls() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_queue_calls");
+    let _session = Mock::session();
+
+    let queue = Queue::new(10, 4)?;
+    let handle = *queue as usize;
+    let create = Mock::last("xQueueGenericCreate").unwrap();
+    assert_eq!(create.args, [Arg::Int(10), Arg::Int(4), Arg::Int(0)]);
+    assert_eq!(Mock::count("xQueueCreateCountingSemaphore"), 0);
+
+    let item = 0x1234_5678u32.to_le_bytes();
+    queue.post(&item, 100)?;
+    let send = Mock::last("xQueueGenericSend").unwrap();
+    assert_eq!(send.args, [Arg::Ptr(handle), Arg::Ptr(item.as_ptr() as usize), Arg::Int(100), Arg::Int(0)]);
+
+    let mut buffer = [0u8; 4];
+    queue.fetch(&mut buffer, 50)?;
+    let receive = Mock::last("xQueueReceive").unwrap();
+    assert_eq!(receive.args, [Arg::Ptr(handle), Arg::Ptr(buffer.as_ptr() as usize), Arg::Int(50)]);
+
+    queue.post_from_isr(&item)?;
+    assert_eq!(Mock::count("xQueueGenericSendFromISR"), 1);
+    assert_eq!(Mock::last("osal_rs_port_yield_from_isr").unwrap().args, [Arg::Int(0)]);
+
+    drop(queue);
+    assert_eq!(Mock::last("vQueueDelete").unwrap().args, [Arg::Ptr(handle)]);
+    log_info!(TAG, "test_mock_queue_calls PASSED");
+    Ok(())
+}
+
+pub fn test_mock_queue_errors() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_queue_errors");
+    let _session = Mock::session();
+
+    Mock::push_return("xQueueGenericCreate", 0);
+    assert!(matches!(Queue::new(10, 4), Err(Error::OutOfMemory)));
+
+    let queue = Queue::new(10, 4)?;
+    let mut buffer = [0u8; 4];
+    Mock::push_return("xQueueReceive", PD_FAIL);
+    assert!(matches!(queue.fetch(&mut buffer, 10), Err(Error::Timeout)));
+    assert!(queue.fetch(&mut buffer, 10).is_ok());
+
+    Mock::set_return("xQueueGenericSend", PD_FAIL);
+    assert!(matches!(queue.post(&buffer, 10), Err(Error::Timeout)));
+    assert!(matches!(queue.post(&buffer, 10), Err(Error::Timeout)));
+    assert_eq!(Mock::count("xQueueGenericSend"), 2);
+    log_info!(TAG, "test_mock_queue_errors PASSED");
+    Ok(())
+}
+
+pub fn test_mock_queue_front_overwrite_peek() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_queue_front_overwrite_peek");
+    let _session = Mock::session();
+
+    let queue = Queue::new(1, 4)?;
+    let handle = *queue as usize;
+    let item = 7u32.to_le_bytes();
+
+    queue.post_to_front(&item, 20)?;
+    assert_eq!(Mock::last("xQueueGenericSend").unwrap().args, [Arg::Ptr(handle), Arg::Ptr(item.as_ptr() as usize), Arg::Int(20), Arg::Int(1)]);
+    queue.post_to_front_from_isr(&item)?;
+    assert_eq!(Mock::last("xQueueGenericSendFromISR").unwrap().args[3], Arg::Int(1));
+
+    queue.overwrite(&item)?;
+    assert_eq!(Mock::last("xQueueGenericSend").unwrap().args, [Arg::Ptr(handle), Arg::Ptr(item.as_ptr() as usize), Arg::Int(0), Arg::Int(2)]);
+    queue.overwrite_from_isr(&item)?;
+    assert_eq!(Mock::last("xQueueGenericSendFromISR").unwrap().args[3], Arg::Int(2));
+
+    let mut buffer = [0u8; 4];
+    queue.peek(&mut buffer, 30)?;
+    assert_eq!(Mock::last("xQueuePeek").unwrap().args, [Arg::Ptr(handle), Arg::Ptr(buffer.as_ptr() as usize), Arg::Int(30)]);
+    Mock::push_return("xQueuePeekFromISR", PD_FAIL);
+    assert!(matches!(queue.peek_from_isr(&mut buffer), Err(Error::Timeout)));
+    assert_eq!(Mock::count("xQueueReceive"), 0);
+
+    queue.reset()?;
+    assert_eq!(Mock::last("xQueueGenericReset").unwrap().args, [Arg::Ptr(handle), Arg::Int(0)]);
+    log_info!(TAG, "test_mock_queue_front_overwrite_peek PASSED");
+    Ok(())
+}
+
+pub fn test_mock_queue_set() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_queue_set");
+    let _session = Mock::session();
+
+    let set = QueueSet::new(6)?;
+    let queue = Queue::new(4, 4)?;
+    let other = Queue::new(2, 8)?;
+    let semaphore = Semaphore::new(1, 0)?;
+    assert_eq!(Mock::last("xQueueCreateSet").unwrap().args, [Arg::Int(6)]);
+
+    set.add(&queue)?;
+    assert_eq!(Mock::last("xQueueAddToSet").unwrap().args, [Arg::Ptr(*queue as usize), Arg::Ptr(*set as usize)]);
+    set.add(&other)?;
+    set.add(&semaphore)?;
+    assert_eq!(Mock::count("xQueueAddToSet"), 3);
+    Mock::push_return("xQueueAddToSet", PD_FAIL);
+    assert!(set.add(&queue).is_err());
+
+    assert!(matches!(set.select(50), Err(Error::Timeout)));
+    assert_eq!(Mock::last("xQueueSelectFromSet").unwrap().args, [Arg::Ptr(*set as usize), Arg::Int(50)]);
+    Mock::push_return("xQueueSelectFromSet", *semaphore as usize as i64);
+    assert_eq!(set.select(50)?, semaphore.member_handle());
+    Mock::push_return("xQueueSelectFromSetFromISR", *other as usize as i64);
+    assert_eq!(set.select_from_isr()?, other.member_handle());
+
+    set.remove(&queue)?;
+    assert_eq!(Mock::last("xQueueRemoveFromSet").unwrap().args, [Arg::Ptr(*queue as usize), Arg::Ptr(*set as usize)]);
+    Mock::push_return("xQueueRemoveFromSet", PD_FAIL);
+    assert!(set.remove(&queue).is_err());
+    log_info!(TAG, "test_mock_queue_set PASSED");
+    Ok(())
+}
+
+pub fn test_mock_stream_buffer() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_stream_buffer");
+    let _session = Mock::session();
+
+    let mut stream = StreamBuffer::new(64, 4)?;
+    let handle = *stream as usize;
+    assert_eq!(Mock::last("xStreamBufferGenericCreate").unwrap().args, [Arg::Int(64), Arg::Int(4), Arg::Int(0), Arg::Ptr(0), Arg::Ptr(0)]);
+    assert!(matches!(StreamBuffer::new(8, 9), Err(Error::InvalidQueueSize)));
+
+    let data = *b"hello";
+    assert_eq!(stream.send(&data, Duration::from_millis(20)), 5);
+    assert_eq!(Mock::last("xStreamBufferSend").unwrap().args, [Arg::Ptr(handle), Arg::Ptr(data.as_ptr() as usize), Arg::Int(5), Arg::Int(20)]);
+    Mock::push_return("xStreamBufferSendFromISR", 2);
+    assert_eq!(stream.send_from_isr(&data), 2);
+
+    let mut buffer = [0u8; 8];
+    assert_eq!(stream.receive(&mut buffer, Duration::from_millis(30)), 0);
+    assert_eq!(Mock::last("xStreamBufferReceive").unwrap().args, [Arg::Ptr(handle), Arg::Ptr(buffer.as_ptr() as usize), Arg::Int(8), Arg::Int(30)]);
+    Mock::push_return("xStreamBufferReceiveFromISR", 3);
+    assert_eq!(stream.receive_from_isr(&mut buffer), 3);
+
+    Mock::push_return("xStreamBufferBytesAvailable", 7);
+    assert_eq!(stream.bytes_available(), 7);
+    Mock::push_return("xStreamBufferSetTriggerLevel", PD_FAIL);
+    assert!(matches!(stream.set_trigger_level(128), Err(Error::InvalidQueueSize)));
+    Mock::push_return("xStreamBufferReset", PD_FAIL);
+    assert!(stream.reset().is_err());
+
+    // embedded_io calls block until at least one byte moved
+    Mock::push_return("xStreamBufferSend", 0);
+    assert_eq!(embedded_io::Write::write(&mut stream, &data)?, 5);
+    assert_eq!(Mock::count("xStreamBufferSend"), 3);
+    Mock::push_return("xStreamBufferReceive", 0);
+    Mock::push_return("xStreamBufferReceive", 4);
+    assert_eq!(embedded_io::Read::read(&mut stream, &mut buffer)?, 4);
+
+    drop(stream);
+    assert_eq!(Mock::last("vStreamBufferDelete").unwrap().args, [Arg::Ptr(handle)]);
+    log_info!(TAG, "test_mock_stream_buffer PASSED");
+    Ok(())
+}
+
+pub fn test_mock_message_buffer() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_message_buffer");
+    let _session = Mock::session();
+
+    let buffer = MessageBuffer::new(128)?;
+    let handle = *buffer as usize;
+    assert_eq!(Mock::last("xStreamBufferGenericCreate").unwrap().args, [Arg::Int(128), Arg::Int(0), Arg::Int(1), Arg::Ptr(0), Arg::Ptr(0)]);
+
+    let frame = [0x55u8; 12];
+    buffer.send(&frame, Duration::from_millis(10))?;
+    assert_eq!(Mock::last("xStreamBufferSend").unwrap().args, [Arg::Ptr(handle), Arg::Ptr(frame.as_ptr() as usize), Arg::Int(12), Arg::Int(10)]);
+    Mock::push_return("xStreamBufferSendFromISR", 0);
+    assert!(matches!(buffer.send_from_isr(&frame), Err(Error::Timeout)));
+
+    // A receive of 0 bytes is a timeout, or a message too long for the buffer
+    let mut message = [0u8; 8];
+    assert!(matches!(buffer.receive(&mut message, Duration::from_millis(10)), Err(Error::Timeout)));
+    Mock::push_return("xStreamBufferNextMessageLengthBytes", 12);
+    assert!(matches!(buffer.receive(&mut message, Duration::ZERO), Err(Error::Unhandled(_))));
+    Mock::push_return("xStreamBufferReceiveFromISR", 6);
+    assert_eq!(buffer.receive_from_isr(&mut message)?, 6);
+
+    let typed = MessageBufferStreamed::<Frame>::new(64)?;
+    typed.send(&Frame(vec![1, 2, 3, 4]), Duration::ZERO)?;
+    assert_eq!(Mock::last("xStreamBufferSend").unwrap().args[2], Arg::Int(4));
+    Mock::push_return("xStreamBufferReceive", 4);
+    assert_eq!(typed.receive(Duration::ZERO)?.0.len(), 4);
+    assert_eq!(Mock::last("xStreamBufferReceive").unwrap().args[2], Arg::Int(64));
+    log_info!(TAG, "test_mock_message_buffer PASSED");
+    Ok(())
+}
+
+pub fn test_mock_mutex_kinds() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_mutex_kinds");
+    let _session = Mock::session();
+
+    let mutex = Mutex::new(0u32);
+    assert_eq!(Mock::last("xQueueCreateMutex").unwrap().args, [Arg::Int(1)]);
+    Mock::push_return("xQueueSemaphoreTake", PD_FAIL);
+    assert!(mutex.try_lock().is_err());
+    assert_eq!(Mock::last("xQueueSemaphoreTake").unwrap().args[1], Arg::Int(0));
+    drop(mutex.lock()?);
+    assert_eq!(Mock::count("xQueueGenericSend"), 1);
+    assert_eq!(Mock::count("xQueueTakeMutexRecursive"), 0);
+
+    let recursive = RecursiveMutex::new(0u32);
+    assert_eq!(Mock::last("xQueueCreateMutex").unwrap().args, [Arg::Int(4)]);
+    drop(recursive.try_lock()?);
+    assert_eq!(Mock::last("xQueueTakeMutexRecursive").unwrap().args[1], Arg::Int(0));
+    assert_eq!(Mock::count("xQueueGiveMutexRecursive"), 1);
+
+    let semaphore = BinarySemaphore::new()?;
+    assert_eq!(Mock::last("xQueueGenericCreate").unwrap().args, [Arg::Int(1), Arg::Int(0), Arg::Int(3)]);
+    assert_eq!(semaphore.signal_from_isr(), OsalRsBool::True);
+    assert_eq!(Mock::last("xQueueGiveFromISR").unwrap().args[0], Arg::Ptr(*semaphore as usize));
+    Mock::push_return("xQueueReceiveFromISR", PD_FAIL);
+    assert_eq!(semaphore.wait_from_isr(), OsalRsBool::False);
+    log_info!(TAG, "test_mock_mutex_kinds PASSED");
+    Ok(())
+}
+
+pub fn test_mock_event_group_wait() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_event_group_wait");
+    let _session = Mock::session();
+
+    let events = EventGroup::new()?;
+    let handle = *events as usize;
+
+    // The kernel returns the bits on timeout too: only the condition tells
+    Mock::push_return("xEventGroupWaitBits", 0x1);
+    let result = events.wait_bits(0x3, EventWaitOptions::ALL.clear_on_exit(), Duration::from_millis(40));
+    assert!(matches!(result, Err(Error::Timeout)));
+    assert_eq!(Mock::last("xEventGroupWaitBits").unwrap().args, [Arg::Ptr(handle), Arg::Int(0x3), Arg::Int(1), Arg::Int(1), Arg::Int(40)]);
+    Mock::push_return("xEventGroupWaitBits", 0x5);
+    assert_eq!(events.wait_bits(0x3, EventWaitOptions::ANY, Duration::ZERO)?, 0x5);
+    assert_eq!(Mock::last("xEventGroupWaitBits").unwrap().args[2..4], [Arg::Int(0), Arg::Int(0)]);
+
+    Mock::push_return("xEventGroupSync", 0x7);
+    assert_eq!(events.sync(0x1, 0x7, Duration::from_millis(10))?, 0x7);
+    assert_eq!(Mock::last("xEventGroupSync").unwrap().args, [Arg::Ptr(handle), Arg::Int(0x1), Arg::Int(0x7), Arg::Int(10)]);
+    Mock::push_return("xEventGroupSync", 0x3);
+    assert!(matches!(events.sync(0x1, 0x7, Duration::ZERO), Err(Error::Timeout)));
+    log_info!(TAG, "test_mock_event_group_wait PASSED");
+    Ok(())
+}
+
+pub fn test_mock_thread_spawn() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_thread_spawn");
+    let _session = Mock::session();
+    static RUNS: AtomicU32 = AtomicU32::new(0);
+
+    let mut thread = Thread::new("mock_worker", 512, 3);
+    let spawned = thread.spawn_simple(|| {
+        RUNS.fetch_add(1, Ordering::SeqCst);
+    })?;
+
+    let create = Mock::last("xTaskCreate").unwrap();
+    assert_eq!(create.args[1], Arg::Str("mock_worker".to_string()));
+    assert_eq!(create.args[2], Arg::Int(512));
+    assert_eq!(create.args[4], Arg::Int(3));
+    assert_eq!(Mock::task_state(*spawned), ThreadState::Ready);
+
+    let metadata = spawned.get_metadata();
+    assert_eq!(metadata.name, "mock_worker");
+    assert_eq!(metadata.priority, 3);
+
+    // Nothing runs until the test plays the scheduler
+    assert_eq!(RUNS.load(Ordering::SeqCst), 0);
+    assert!(Mock::run_task(*spawned));
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    // The wrapper deletes the task when the closure returns
+    assert_eq!(Mock::task_state(*spawned), ThreadState::Deleted);
+    assert_eq!(Mock::last("vTaskDelete").unwrap().args, [Arg::Ptr(*spawned as usize)]);
+    log_info!(TAG, "test_mock_thread_spawn PASSED");
+    Ok(())
+}
+
+pub fn test_mock_thread_join() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_thread_join");
+    let _session = Mock::session();
+
+    let mut thread = Thread::new("mock_join", 512, 3);
+    let mut worker = thread.spawn_joinable(|| 42u32)?;
+
+    // The exit is a semaphore given by the task when the closure returns
+    Mock::push_return("xQueueSemaphoreTake", PD_FAIL);
+    assert!(matches!(worker.join(10), Err(Error::Timeout)));
+    assert_eq!(Mock::last("xQueueSemaphoreTake").unwrap().args[1], Arg::Int(10));
+    assert!(!worker.is_finished());
+
+    assert!(Mock::run_task(**worker.thread()));
+    assert!(worker.is_finished());
+    assert_eq!(Mock::task_state(**worker.thread()), ThreadState::Deleted);
+    assert_eq!(worker.join(0)?, 42);
+    assert!(worker.join(0).is_err());
+
+    // The kernel cannot tell when a task it did not start through osal-rs ends
+    assert!(matches!(Thread::get_current().join(core::ptr::null_mut()), Err(Error::NullPtr)));
+    log_info!(TAG, "test_mock_thread_join PASSED");
+    Ok(())
+}
+
+pub fn test_mock_thread_join_deleted() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_thread_join_deleted");
+    let _session = Mock::session();
+
+    // Deleted before its callback runs: the joiners are released at once
+    let mut thread = Thread::new("mock_deleted", 512, 3);
+    let mut worker = thread.spawn_joinable(|| 42u32)?;
+    worker.thread().delete();
+    assert_eq!(Mock::last("vTaskDelete").unwrap().args, [Arg::Ptr(**worker.thread() as usize)]);
+    assert!(worker.is_finished());
+    assert!(worker.thread().join(core::ptr::null_mut()).is_err());
+    assert!(worker.join(10).is_err());
+    assert_eq!(Mock::count("vTaskDelete"), 1);
+
+    // A task that returned from its callback has already deleted itself
+    let spawned = thread.spawn_simple(|| {})?;
+    assert!(Mock::run_task(*spawned));
+    assert_eq!(Mock::count("vTaskDelete"), 2);
+    spawned.delete();
+    assert_eq!(Mock::count("vTaskDelete"), 2);
+    assert_eq!(spawned.join(core::ptr::null_mut())?, 0);
+    log_info!(TAG, "test_mock_thread_join_deleted PASSED");
+    Ok(())
+}
+
+pub fn test_mock_thread_create_fails() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_thread_create_fails");
+    let _session = Mock::session();
+
+    Mock::set_return("xTaskCreate", PD_FAIL);
+    let mut thread = Thread::new("no_memory", 512, 3);
+    assert!(matches!(thread.spawn_simple(|| {}), Err(Error::OutOfMemory)));
+    assert!(matches!(thread.spawn(None, |_thread, param| Ok(param.unwrap_or_else(|| Arc::new(())))), Err(Error::OutOfMemory)));
+    assert_eq!(Mock::count("xTaskCreate"), 2);
+    assert_eq!(System::count_threads(), 0);
+    log_info!(TAG, "test_mock_thread_create_fails PASSED");
+    Ok(())
+}
+
+pub fn test_mock_thread_control() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_thread_control");
+    let _session = Mock::session();
+
+    let mut thread = Thread::new("controlled", 512, 2);
+    let spawned = thread.spawn_simple(|| {})?;
+    let handle = Arg::Ptr(*spawned as usize);
+
+    spawned.suspend();
+    assert_eq!(Mock::task_state(*spawned), ThreadState::Suspended);
+    spawned.resume();
+    assert_eq!(Mock::task_state(*spawned), ThreadState::Ready);
+    spawned.notify(ThreadNotification::SetBits(0x05))?;
+
+    Mock::push_return("xTaskGenericNotify", PD_FAIL);
+    assert!(matches!(spawned.notify(ThreadNotification::SetValueWithoutOverwrite(1)), Err(Error::QueueFull)));
+
+    let names: alloc::vec::Vec<&str> = Mock::calls()
+        .iter()
+        .filter(|call| call.args.first() == Some(&handle))
+        .map(|call| call.name)
+        .collect();
+    assert_eq!(names, ["vTaskSuspend", "vTaskResume", "xTaskGenericNotify", "xTaskGenericNotify"]);
+
+    Mock::set_notification_value(0x42);
+    assert_eq!(spawned.wait_notification(0, u32::MAX, 10)?, 0x42);
+    Mock::push_return("xTaskGenericNotifyWait", PD_FAIL);
+    assert!(spawned.wait_notification(0, u32::MAX, 10).is_err());
+    log_info!(TAG, "test_mock_thread_control PASSED");
+    Ok(())
+}
+
+pub fn test_mock_thread_notification_indexed() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_thread_notification_indexed");
+    let _session = Mock::session();
+
+    let mut thread = Thread::new("notified", 512, 2);
+    let spawned = thread.spawn_simple(|| {})?;
+    let handle = Arg::Ptr(*spawned as usize);
+
+    spawned.notify_indexed(2, ThreadNotification::SetBits(0x05))?;
+    assert_eq!(Mock::last("xTaskGenericNotify").unwrap().args[..4], [handle.clone(), Arg::Int(2), Arg::Int(0x05), Arg::Int(1)]);
+    Mock::set_notification_value(0x30);
+    assert_eq!(spawned.notify_and_query(1, ThreadNotification::Increment)?, 0x30);
+    spawned.notify_give(1)?;
+    assert_eq!(Mock::last("xTaskGenericNotify").unwrap().args[1..4], [Arg::Int(1), Arg::Int(0), Arg::Int(2)]);
+    assert!(matches!(spawned.notify_indexed(3, ThreadNotification::NoAction), Err(Error::OutOfIndex)));
+    assert_eq!(Mock::count("xTaskGenericNotify"), 3);
+
+    let mut woken = 0;
+    spawned.notify_give_from_isr(2, &mut woken)?;
+    assert_eq!(Mock::last("vTaskGenericNotifyGiveFromISR").unwrap().args[..2], [handle.clone(), Arg::Int(2)]);
+
+    assert_eq!(spawned.wait_notification_indexed(1, 0, u32::MAX, 10)?, 0x30);
+    assert_eq!(Mock::last("xTaskGenericNotifyWait").unwrap().args[0], Arg::Int(1));
+
+    // `ulTaskNotifyTake` returns 0 on timeout
+    assert!(matches!(spawned.notify_take(2, true, 25), Err(Error::Timeout)));
+    assert_eq!(Mock::last("ulTaskGenericNotifyTake").unwrap().args, [Arg::Int(2), Arg::Int(1), Arg::Int(25)]);
+    Mock::push_return("ulTaskGenericNotifyTake", 4);
+    assert_eq!(spawned.notify_take(2, false, 25)?, 4);
+
+    Mock::push_return("xTaskGenericNotifyStateClear", 1);
+    assert!(spawned.clear_state(1)?);
+    assert!(!spawned.clear_state(1)?);
+    assert_eq!(Mock::last("xTaskGenericNotifyStateClear").unwrap().args, [handle, Arg::Int(1)]);
+    log_info!(TAG, "test_mock_thread_notification_indexed PASSED");
+    Ok(())
+}
+
+pub fn test_mock_timer() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_timer");
+    let _session = Mock::session();
+    static FIRED: AtomicU32 = AtomicU32::new(0);
+
+    Mock::push_return("xTimerCreate", 0);
+    assert!(matches!(
+        Timer::new("no_timer", 100, true, None, |_timer, param| Ok(param.unwrap_or_else(|| Arc::new(())))),
+        Err(Error::NullPtr)
+    ));
+
+    let timer = Timer::new("mock_timer", 100, true, None, |_timer, param| {
+        FIRED.fetch_add(1, Ordering::SeqCst);
+        Ok(param.unwrap_or_else(|| Arc::new(())))
+    })?;
+    let create = Mock::last("xTimerCreate").unwrap();
+    assert_eq!(create.args[..3], [Arg::Str("mock_timer".to_string()), Arg::Int(100), Arg::Int(1)]);
+
+    assert_eq!(timer.start(10), OsalRsBool::True);
+    assert_eq!(Mock::last("osal_rs_timer_start").unwrap().args, [Arg::Ptr(*timer as usize), Arg::Int(10)]);
+    Mock::push_return("osal_rs_timer_start", PD_FAIL);
+    assert_eq!(timer.start(10), OsalRsBool::False);
+    assert_eq!(timer.change_period(200, 10), OsalRsBool::True);
+    assert_eq!(Mock::last("osal_rs_timer_change_period").unwrap().args[1], Arg::Int(200));
+
+    assert!(Mock::fire_timer(*timer));
+    assert_eq!(FIRED.load(Ordering::SeqCst), 1);
+    log_info!(TAG, "test_mock_timer PASSED");
+    Ok(())
+}
+
+pub fn test_mock_timer_param() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_timer_param");
+    let _session = Mock::session();
+    static TOTAL: AtomicU32 = AtomicU32::new(0);
+    TOTAL.store(0, Ordering::SeqCst);
+
+    let timer = Timer::new_with_param("typed_timer", 100, true, 7u32, |step| {
+        TOTAL.fetch_add(*step, Ordering::SeqCst);
+    })?;
+
+    // The timer ID is read again on every expiry
+    assert!(Mock::fire_timer(*timer));
+    assert!(Mock::fire_timer(*timer));
+    assert_eq!(TOTAL.load(Ordering::SeqCst), 14);
+    assert_eq!(Mock::count("osal_rs_timer_delete"), 0);
+
+    drop(timer);
+    assert_eq!(Mock::count("osal_rs_timer_delete"), 1);
+    log_info!(TAG, "test_mock_timer_param PASSED");
+    Ok(())
+}
+
+pub fn test_mock_timer_delete() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_timer_delete");
+    let _session = Mock::session();
+    static FIRED: AtomicU32 = AtomicU32::new(0);
+    FIRED.store(0, Ordering::SeqCst);
+
+    let mut timer = Timer::new_with_param("delete_timer", 100, true, (), |_| {
+        FIRED.fetch_add(1, Ordering::SeqCst);
+    })?;
+    let handle = *timer;
+
+    // Clones never delete the timer
+    drop(timer.clone());
+    assert_eq!(Mock::count("osal_rs_timer_delete"), 0);
+
+    // A delete that cannot be queued leaves the timer and its ID alive
+    Mock::push_return("osal_rs_timer_delete", PD_FAIL);
+    assert_eq!(timer.delete(0), OsalRsBool::False);
+    assert_eq!(*timer, handle);
+    assert!(Mock::fire_timer(handle));
+    assert_eq!(FIRED.load(Ordering::SeqCst), 1);
+    assert_eq!(Mock::count("xTimerPendFunctionCall"), 0);
+
+    // The ID is freed by the timer task, behind the delete command
+    assert_eq!(timer.delete(10), OsalRsBool::True);
+    assert!(timer.is_null());
+    let pended = Mock::last("xTimerPendFunctionCall").unwrap();
+    assert_eq!(pended.args[3], Arg::Int(10));
+    assert_eq!(timer.delete(10), OsalRsBool::False);
+    assert_eq!(Mock::count("osal_rs_timer_delete"), 2);
+    log_info!(TAG, "test_mock_timer_delete PASSED");
+    Ok(())
+}
+
+pub fn test_mock_static_thread() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_static_thread");
+    let _session = Mock::session();
+    static RUNS: AtomicU32 = AtomicU32::new(0);
+
+    // Leaked storage stands for the `static` of an application
+    let storage: &'static mut StaticThread<256> = Box::leak(Box::new(StaticThread::new()));
+    let stack = storage as *const _ as usize;
+    let thread = storage.spawn(c"static_worker", 3, || {
+        RUNS.fetch_add(1, Ordering::SeqCst);
+    })?;
+
+    let create = Mock::last("xTaskCreateStatic").unwrap();
+    assert_eq!(create.args[1], Arg::Str("static_worker".to_string()));
+    assert_eq!(create.args[2], Arg::Int(256));
+    assert_eq!(create.args[4], Arg::Int(3));
+    assert!(matches!(create.args[5], Arg::Ptr(address) if address >= stack));
+    assert_eq!(Mock::count("xTaskCreate"), 0);
+
+    assert!(Mock::run_task(*thread));
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+    assert_eq!(Mock::task_state(*thread), ThreadState::Deleted);
+
+    Mock::push_return("xTaskCreateStatic", 0);
+    let storage: &'static mut StaticThread<256> = Box::leak(Box::new(StaticThread::new()));
+    assert!(matches!(storage.spawn(c"no_thread", 3, || {}), Err(Error::NullPtr)));
+    log_info!(TAG, "test_mock_static_thread PASSED");
+    Ok(())
+}
+
+pub fn test_mock_static_objects() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_static_objects");
+    let _session = Mock::session();
+    static FIRED: AtomicU32 = AtomicU32::new(0);
+
+    let storage: &'static mut StaticQueue<u32, 8> = Box::leak(Box::new(StaticQueue::new()));
+    let queue = storage.create()?;
+    let create = Mock::last("xQueueGenericCreateStatic").unwrap();
+    assert_eq!(create.args[..2], [Arg::Int(8), Arg::Int(4)]);
+    assert_eq!(create.args[4], Arg::Int(0));
+    let item = 7u32;
+    queue.post(&item, 0)?;
+    assert_eq!(Mock::last("xQueueGenericSend").unwrap().args[..2], [Arg::Ptr(*queue as usize), Arg::Ptr(&item as *const u32 as usize)]);
+    Mock::push_return("xQueueReceive", 0);
+    assert!(matches!(queue.fetch(0), Err(Error::Timeout)));
+
+    let storage: &'static mut StaticQueue<u32, 0> = Box::leak(Box::new(StaticQueue::new()));
+    assert!(matches!(storage.create(), Err(Error::InvalidQueueSize)));
+
+    let storage: &'static mut StaticSemaphore = Box::leak(Box::new(StaticSemaphore::new()));
+    let semaphore = storage.create(5, 1)?;
+    assert_eq!(Mock::last("xQueueCreateCountingSemaphoreStatic").unwrap().args[..2], [Arg::Int(5), Arg::Int(1)]);
+    assert_eq!(semaphore.signal(), OsalRsBool::True);
+
+    let storage: &'static mut StaticEventGroup = Box::leak(Box::new(StaticEventGroup::new()));
+    Mock::push_return("xEventGroupCreateStatic", 0);
+    assert!(matches!(Box::leak(Box::new(StaticEventGroup::new())).create(), Err(Error::NullPtr)));
+    let events = storage.create()?;
+    events.set(0x3);
+    assert_eq!(Mock::last("xEventGroupSetBits").unwrap().args, [Arg::Ptr(*events as usize), Arg::Int(0x3)]);
+
+    let storage: &'static mut StaticTimer = Box::leak(Box::new(StaticTimer::new()));
+    let timer = storage.create(c"static_timer", 100, true, || {
+        FIRED.fetch_add(1, Ordering::SeqCst);
+    })?;
+    let create = Mock::last("xTimerCreateStatic").unwrap();
+    assert_eq!(create.args[..3], [Arg::Str("static_timer".to_string()), Arg::Int(100), Arg::Int(1)]);
+    assert!(Mock::fire_timer(*timer));
+    assert!(Mock::fire_timer(*timer));
+    assert_eq!(FIRED.load(Ordering::SeqCst), 2);
+
+    // None of the objects came from the FreeRTOS heap
+    assert_eq!(Mock::count("xQueueGenericCreate") + Mock::count("xEventGroupCreate") + Mock::count("xTimerCreate"), 0);
+    log_info!(TAG, "test_mock_static_objects PASSED");
+    Ok(())
+}
+
+pub fn test_mock_delay() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_delay");
+    let _session = Mock::session();
+
+    Mock::set_tick_count(1_000);
+    System::delay(25);
+    assert_eq!(System::get_tick_count(), 1_025);
+
+    let mut wake_time = 1_000;
+    System::delay_until(&mut wake_time, 50);
+    assert_eq!(wake_time, 1_050);
+    assert_eq!(System::get_tick_count(), 1_050);
+    assert_eq!(Mock::last("xTaskDelayUntil").unwrap().args, [Arg::Int(1_000), Arg::Int(50)]);
+    log_info!(TAG, "test_mock_delay PASSED");
+    Ok(())
+}
+
+pub fn test_mock_ticks_from_duration() -> Result<()> {
+    log_info!(TAG, "Starting test_mock_ticks_from_duration");
+    // The mock kernel ticks at 1 kHz
+    assert_eq!(System::get_ticks_from_duration(&Duration::from_millis(100)), 100);
+    assert_eq!(System::get_ticks_from_duration(&Duration::from_secs(2)), 2_000);
+    assert_eq!(System::get_ticks_from_duration(&Duration::from_micros(500)), 0);
+    log_info!(TAG, "test_mock_ticks_from_duration PASSED");
+    Ok(())
+}
+
+pub fn run_all_tests() -> Result<()> {
+    log_info!(TAG, "========== Running Mock Tests ==========");
+    test_mock_queue_calls()?;
+    test_mock_queue_errors()?;
+    test_mock_queue_front_overwrite_peek()?;
+    test_mock_thread_spawn()?;
+    test_mock_thread_create_fails()?;
+    test_mock_thread_control()?;
+    test_mock_timer()?;
+    test_mock_static_thread()?;
+    test_mock_static_objects()?;
+    test_mock_delay()?;
+    test_mock_ticks_from_duration()?;
+    log_info!(TAG, "========== All Mock Tests PASSED ==========");
+    Ok(())
+}
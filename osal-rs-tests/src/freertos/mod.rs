@@ -17,37 +17,57 @@
  *
  ***************************************************************************/
 
-pub mod thread_tests;
-pub mod queue_tests;
-pub mod mutex_tests;
-pub mod semaphore_tests;
-pub mod timer_tests;
-pub mod event_group_tests;
 pub mod duration_tests;
 pub mod system_tests;
+#[cfg(feature = "mock")]
+pub mod mock_tests;
 
+use alloc::vec::Vec;
+use osal_rs::os::*;
 use osal_rs::utils::Result;
+use crate::conformance::{Backend, Conformance};
+use crate::harness::{Harness, Test};
 use osal_rs::log_info;
 
 const TAG: &str = "FreeRTOSTests";
 
+/// The FreeRTOS types under the conformance suite
+pub struct FreeRtos;
+
+impl Backend for FreeRtos {
+    type Thread = Thread;
+    type Queue = Queue;
+    type QueueSet = QueueSet;
+    type MessageBuffer = MessageBuffer;
+    type MessageBufferStreamed<T: ToBytes + FromBytes + 'static> = MessageBufferStreamed<T>;
+    type Mutex<T: Send + 'static> = Mutex<T>;
+    type RecursiveMutex<T: Send + 'static> = RecursiveMutex<T>;
+    type Semaphore = Semaphore;
+    type BinarySemaphore = BinarySemaphore;
+    type StreamBuffer = StreamBuffer;
+    type EventGroup = EventGroup;
+    type Timer = Timer;
+    type System = System;
+}
+
+/// Tests of what only the FreeRTOS backend provides
+pub const SUITES: &[&[Test]] = &[
+    duration_tests::TESTS,
+    system_tests::TESTS,
+];
+
 /// Run all available FreeRTOS tests
 pub fn run_all_tests() -> Result<()> {
+    run_tests(None)
+}
+
+/// Run the conformance suite and the FreeRTOS tests whose name contains
+/// `filter`, each in its own thread: a failing test does not stop the run
+pub fn run_tests(filter: Option<&str>) -> Result<()> {
     log_info!(TAG, "\n\n========================================");
     log_info!(TAG, "   Starting FreeRTOS Test Suite");
     log_info!(TAG, "========================================\n");
-    
-    duration_tests::run_all_tests()?;
-    event_group_tests::run_all_tests()?;
-    mutex_tests::run_all_tests()?;
-    queue_tests::run_all_tests()?;
-    semaphore_tests::run_all_tests()?;
-    system_tests::run_all_tests()?;
-    thread_tests::run_all_tests()?;
-    timer_tests::run_all_tests()?;
-    
-    log_info!(TAG, "\n========================================");
-    log_info!(TAG, "   All FreeRTOS Tests PASSED!");
-    log_info!(TAG, "========================================\n");
-    Ok(())
+
+    let suites: Vec<&[Test]> = [Conformance::<FreeRtos>::SUITES, SUITES].concat();
+    Harness::new().with_filter(filter).run(&suites).result()
 }
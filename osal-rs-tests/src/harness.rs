@@ -0,0 +1,352 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+//! Test harness of the suites.
+//!
+//! Each module registers its tests with `register_tests!`. Every test runs in
+//! its own thread with a timeout, so a test that returns an error, panics or
+//! hangs is reported as failed and the run goes on. The report is printed
+//! one line per test and ends with a summary line for CI:
+//!
+//! `TEST SUMMARY: result=PASS total=98 passed=96 failed=0 skipped=2 filtered=0`
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::panic::PanicInfo;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicPtr, AtomicU32, Ordering};
+use core::time::Duration;
+
+use osal_rs::os::*;
+use osal_rs::os::types::{StackType, TickType, UBaseType};
+use osal_rs::println;
+use osal_rs::utils::{Error, OsalRsBool, Result};
+
+/// Stack depth of the thread running a test, in words
+pub const DEFAULT_STACK_DEPTH: StackType = 4096;
+
+/// Time a test may run before it is reported as failed
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+const REPORT_QUEUE_SIZE: UBaseType = 8;
+
+const REPORT_SIZE: UBaseType = 8;
+
+/// Registers the test functions of a module: defines `TESTS` and a
+/// `run_all_tests` running them through the harness. A test preceded by
+/// `#[ignore]` is reported as skipped.
+#[macro_export]
+macro_rules! register_tests {
+    ($($(#[$attr:ident])? $test:ident),* $(,)?) => {
+        /// Tests registered by this module
+        pub const TESTS: &[$crate::harness::Test] = &[
+            $($crate::harness::Test::new(concat!(module_path!(), "::", stringify!($test)), $test)$(.$attr())?),*
+        ];
+
+        /// Runs the tests of this module through the harness
+        pub fn run_all_tests() -> osal_rs::utils::Result<()> {
+            $crate::harness::Harness::new().run(&[TESTS]).result()
+        }
+    };
+}
+
+/// A registered test
+#[derive(Clone, Copy)]
+pub struct Test {
+    pub name: &'static str,
+    pub run: fn() -> Result<()>,
+    pub ignored: bool,
+}
+
+impl Test {
+    pub const fn new(name: &'static str, run: fn() -> Result<()>) -> Self {
+        Self { name, run, ignored: false }
+    }
+
+    /// Reports the test as skipped instead of running it
+    pub const fn ignore(mut self) -> Self {
+        self.ignored = true;
+        self
+    }
+
+    /// Name without the crate prefix
+    pub fn short_name(&self) -> &'static str {
+        self.name.strip_prefix("osal_rs_tests::").unwrap_or(self.name)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Outcome {
+    Passed = 0,
+    Failed = 1,
+    Panicked = 2,
+    TimedOut = 3,
+}
+
+impl From<u32> for Outcome {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Outcome::Passed,
+            1 => Outcome::Failed,
+            2 => Outcome::Panicked,
+            _ => Outcome::TimedOut,
+        }
+    }
+}
+
+/// Queue where test threads report `[test id, outcome]`. Created on the first
+/// run and never freed: a test that timed out may still report late.
+static REPORTS: AtomicPtr<Queue> = AtomicPtr::new(null_mut());
+
+/// Id of the running test, reports of earlier tests are discarded
+static CURRENT: AtomicU32 = AtomicU32::new(0);
+
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+fn reports() -> Result<&'static Queue> {
+    let mut queue = REPORTS.load(Ordering::SeqCst);
+    if queue.is_null() {
+        let created = Box::into_raw(Box::new(Queue::new(REPORT_QUEUE_SIZE, REPORT_SIZE)?));
+        queue = match REPORTS.compare_exchange(null_mut(), created, Ordering::SeqCst, Ordering::SeqCst) {
+            Ok(_) => created,
+            Err(current) => {
+                drop(unsafe { Box::from_raw(created) });
+                current
+            }
+        };
+    }
+    Ok(unsafe { &*queue })
+}
+
+fn report(id: u32, outcome: Outcome) {
+    let queue = REPORTS.load(Ordering::SeqCst);
+    if queue.is_null() {
+        return;
+    }
+
+    let mut message = [0u8; REPORT_SIZE as usize];
+    message[..4].copy_from_slice(&id.to_le_bytes());
+    message[4..].copy_from_slice(&(outcome as u32).to_le_bytes());
+    let _ = unsafe { &*queue }.post(&message, Duration::from_millis(100).to_ticks());
+}
+
+/// Fails the running test and parks the panicking thread until the runner
+/// deletes it
+fn on_panic(_info: &PanicInfo) {
+    report(CURRENT.load(Ordering::SeqCst), Outcome::Panicked);
+    loop {
+        Thread::get_current().suspend();
+        System::delay(TickType::MAX);
+    }
+}
+
+/// Counters of a run
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub filtered: usize,
+}
+
+impl Summary {
+    /// Number of registered tests
+    pub fn total(&self) -> usize {
+        self.passed + self.failed + self.skipped + self.filtered
+    }
+
+    pub fn result(&self) -> Result<()> {
+        if self.failed == 0 {
+            Ok(())
+        } else {
+            Err(Error::Unhandled("Some tests failed"))
+        }
+    }
+
+    fn print(&self) {
+        println!(
+            "TEST SUMMARY: result={} total={} passed={} failed={} skipped={} filtered={}",
+            if self.failed == 0 { "PASS" } else { "FAIL" },
+            self.total(),
+            self.passed,
+            self.failed,
+            self.skipped,
+            self.filtered
+        );
+    }
+}
+
+/// Runs registered tests, one thread per test
+pub struct Harness<'a> {
+    filter: Option<&'a str>,
+    timeout: TickType,
+    stack_depth: StackType,
+    priority: UBaseType,
+}
+
+impl Default for Harness<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Harness<'a> {
+    /// Tests run at the priority of the calling thread, above the idle one
+    pub fn new() -> Self {
+        Self {
+            filter: None,
+            timeout: DEFAULT_TIMEOUT.to_ticks(),
+            stack_depth: DEFAULT_STACK_DEPTH,
+            priority: Thread::get_current().get_metadata().priority.max(1),
+        }
+    }
+
+    /// Only runs the tests whose name contains `filter`
+    pub fn with_filter(mut self, filter: Option<&'a str>) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: impl ToTick) -> Self {
+        self.timeout = timeout.to_ticks();
+        self
+    }
+
+    pub fn with_stack_depth(mut self, stack_depth: StackType) -> Self {
+        self.stack_depth = stack_depth;
+        self
+    }
+
+    pub fn with_priority(mut self, priority: UBaseType) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Runs every test of `suites` and prints the report
+    pub fn run(&self, suites: &[&[Test]]) -> Summary {
+        let mut summary = Summary::default();
+        let mut failures = Vec::new();
+
+        let reports = match reports() {
+            Ok(reports) => reports,
+            Err(error) => {
+                println!("Failed to create the report queue: {:?}", error);
+                summary.failed = suites.iter().map(|suite| suite.len()).sum();
+                summary.print();
+                return summary;
+            }
+        };
+        osal_rs::set_panic_hook(Some(on_panic));
+
+        for test in suites.iter().flat_map(|suite| suite.iter()) {
+            if let Some(filter) = self.filter && !test.name.contains(filter) {
+                summary.filtered += 1;
+                continue;
+            }
+
+            if test.ignored {
+                println!("test {} ... skipped", test.short_name());
+                summary.skipped += 1;
+                continue;
+            }
+
+            match self.run_test(test, reports) {
+                Outcome::Passed => {
+                    println!("test {} ... ok", test.short_name());
+                    summary.passed += 1;
+                }
+                outcome => {
+                    println!("test {} ... FAILED ({:?})", test.short_name(), outcome);
+                    summary.failed += 1;
+                    failures.push(test.short_name());
+                }
+            }
+        }
+
+        osal_rs::set_panic_hook(None);
+
+        for name in &failures {
+            println!("failed: {}", name);
+        }
+        summary.print();
+        summary
+    }
+
+    fn run_test(&self, test: &Test, reports: &Queue) -> Outcome {
+        let id = NEXT_ID.fetch_add(1, Ordering::SeqCst);
+        CURRENT.store(id, Ordering::SeqCst);
+
+        // The thread waits for the runner before terminating, so a timeout
+        // never races with its exit
+        let release = match Semaphore::new(1, 0) {
+            Ok(release) => Arc::new(release),
+            Err(_) => return Outcome::Failed,
+        };
+        let thread_release = Arc::clone(&release);
+        let (name, run) = (test.short_name(), test.run);
+
+        let mut thread = Thread::new("test", self.stack_depth, self.priority);
+        let spawned = thread.spawn_simple(move || {
+            let outcome = match run() {
+                Ok(()) => Outcome::Passed,
+                Err(error) => {
+                    println!("{} returned {:?}", name, error);
+                    Outcome::Failed
+                }
+            };
+            report(id, outcome);
+            while thread_release.wait(Duration::from_secs(1)) == OsalRsBool::False {}
+        });
+
+        let spawned = match spawned {
+            Ok(spawned) => spawned,
+            Err(error) => {
+                println!("{} could not be spawned: {:?}", name, error);
+                return Outcome::Failed;
+            }
+        };
+
+        let start = System::get_tick_count();
+        let outcome = loop {
+            let elapsed = System::get_tick_count().wrapping_sub(start);
+            let mut message = [0u8; REPORT_SIZE as usize];
+            if reports.fetch(&mut message, self.timeout.saturating_sub(elapsed)).is_err() {
+                break Outcome::TimedOut;
+            }
+
+            let reported_id = u32::from_le_bytes(message[..4].try_into().unwrap());
+            if reported_id == id {
+                break Outcome::from(u32::from_le_bytes(message[4..].try_into().unwrap()));
+            }
+        };
+
+        match outcome {
+            Outcome::Passed | Outcome::Failed => {
+                release.signal();
+                // The next test starts once this thread is gone, e.g. for
+                // those that count the running threads
+                let _ = spawned.join(null_mut());
+            }
+            Outcome::Panicked | Outcome::TimedOut => spawned.delete(),
+        }
+        outcome
+    }
+}
@@ -21,5 +21,13 @@
 
 extern crate alloc;
 
+#[macro_use]
+pub mod harness;
+
+pub mod conformance;
+
 #[cfg(feature = "freertos")]
 pub mod freertos;
+
+#[cfg(feature = "posix")]
+pub mod posix;
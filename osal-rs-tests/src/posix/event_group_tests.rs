@@ -0,0 +1,58 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use osal_rs::os::*;
+use osal_rs::os::types::EventBits;
+use osal_rs::utils::Result;
+use osal_rs::{log_debug, log_info};
+
+const TAG: &str = "EventGroupTests";
+
+const BIT_0: EventBits = 1 << 0;
+const BIT_3: EventBits = 1 << 3;
+
+pub fn test_event_group_control_bits() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_control_bits");
+    let event_group = EventGroup::new()?;
+
+    let result = event_group.set(0xFF00_0000 | BIT_0);
+    log_debug!(TAG, "Set with control bits, result: 0x{:X}", result);
+    assert_eq!(result, BIT_0);
+    log_info!(TAG, "test_event_group_control_bits PASSED");
+    Ok(())
+}
+
+pub fn test_event_group_from_isr() -> Result<()> {
+    log_info!(TAG, "Starting test_event_group_from_isr");
+    let event_group = EventGroup::new()?;
+
+    event_group.set_from_isr(BIT_0 | BIT_3)?;
+    assert_eq!(event_group.get_from_isr(), BIT_0 | BIT_3);
+    event_group.clear_from_isr(BIT_3)?;
+    assert_eq!(event_group.get_from_isr(), BIT_0);
+    log_info!(TAG, "test_event_group_from_isr PASSED");
+    Ok(())
+}
+
+register_tests! {
+    test_event_group_control_bits,
+    test_event_group_from_isr,
+}
@@ -0,0 +1,268 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use core::time::Duration;
+use osal_rs::os::*;
+use osal_rs::os::types::BaseType;
+use osal_rs::utils::{Result, OsalRsBool};
+use osal_rs::{log_debug, log_info};
+
+const TAG: &str = "IsrTests";
+
+pub fn test_isr_context() -> Result<()> {
+    log_info!(TAG, "Starting test_isr_context");
+    static IN_ISR: AtomicBool = AtomicBool::new(false);
+
+    let interrupt = Interrupt::new(1, || {
+        IN_ISR.store(Interrupt::is_in_isr(), Ordering::SeqCst);
+    })?;
+
+    assert!(!Interrupt::is_in_isr());
+    interrupt.trigger();
+    assert_eq!(Interrupt::wait_idle(Duration::from_millis(100)), OsalRsBool::True);
+
+    log_debug!(TAG, "Handler ran {} times", interrupt.run_count());
+    assert_eq!(interrupt.run_count(), 1);
+    assert!(IN_ISR.load(Ordering::SeqCst));
+    log_info!(TAG, "test_isr_context PASSED");
+    Ok(())
+}
+
+pub fn test_isr_duplicate_line() -> Result<()> {
+    log_info!(TAG, "Starting test_isr_duplicate_line");
+    let _interrupt = Interrupt::new(2, || {})?;
+    assert!(Interrupt::new(2, || {}).is_err());
+    log_info!(TAG, "test_isr_duplicate_line PASSED");
+    Ok(())
+}
+
+pub fn test_isr_semaphore_to_thread() -> Result<()> {
+    log_info!(TAG, "Starting test_isr_semaphore_to_thread");
+    let semaphore = Arc::new(Semaphore::new(1, 0)?);
+    let semaphore_isr = Arc::clone(&semaphore);
+
+    let interrupt = Interrupt::new(3, move || {
+        semaphore_isr.signal_from_isr();
+    })?;
+
+    let waiter_semaphore = Arc::clone(&semaphore);
+    let mut thread = Thread::new("isr_waiter", 1024, 5);
+    let waiter = thread.spawn_simple(move || {
+        assert_eq!(waiter_semaphore.wait(Duration::from_millis(1000)), OsalRsBool::True);
+    })?;
+
+    // Let the thread block on the semaphore first
+    System::delay(Duration::from_millis(20).to_ticks());
+    interrupt.trigger();
+    waiter.join(null_mut())?;
+    Interrupt::wait_idle(Duration::from_millis(100));
+
+    log_debug!(TAG, "Context switches requested: {}", interrupt.yield_count());
+    assert_eq!(interrupt.run_count(), 1);
+    assert_eq!(interrupt.yield_count(), 1);
+
+    // Nobody waits this time: no context switch is requested
+    interrupt.trigger();
+    Interrupt::wait_idle(Duration::from_millis(100));
+    assert_eq!(interrupt.run_count(), 2);
+    assert_eq!(interrupt.yield_count(), 1);
+    log_info!(TAG, "test_isr_semaphore_to_thread PASSED");
+    Ok(())
+}
+
+pub fn test_isr_queue_to_thread() -> Result<()> {
+    log_info!(TAG, "Starting test_isr_queue_to_thread");
+    static NEXT: AtomicU32 = AtomicU32::new(0);
+
+    let queue = Arc::new(Queue::new(4, 4)?);
+    let queue_isr = Arc::clone(&queue);
+
+    let interrupt = Interrupt::new(4, move || {
+        let value = NEXT.fetch_add(1, Ordering::SeqCst);
+        let _ = queue_isr.post_from_isr(&value.to_le_bytes());
+    })?;
+
+    for i in 0..10u32 {
+        interrupt.trigger();
+        let mut received = [0u8; 4];
+        queue.fetch(&mut received, Duration::from_millis(100).to_ticks())?;
+        assert_eq!(u32::from_le_bytes(received), i);
+    }
+    log_debug!(TAG, "Received 10 items posted from the ISR");
+    log_info!(TAG, "test_isr_queue_to_thread PASSED");
+    Ok(())
+}
+
+pub fn test_isr_blocking_forbidden() -> Result<()> {
+    log_info!(TAG, "Starting test_isr_blocking_forbidden");
+    static FAILURES: AtomicU32 = AtomicU32::new(0);
+    static ELAPSED: AtomicU32 = AtomicU32::new(u32::MAX);
+
+    let semaphore = Arc::new(Semaphore::new(1, 0)?);
+    let queue = Arc::new(Queue::new(1, 4)?);
+    queue.post(&0u32.to_le_bytes(), 0)?;
+    let mutex = Arc::new(Mutex::new(0u32));
+
+    let (semaphore_isr, queue_isr, mutex_isr) = (semaphore.clone(), queue.clone(), mutex.clone());
+    let interrupt = Interrupt::new(5, move || {
+        let start = System::get_tick_count();
+        if semaphore_isr.wait(Duration::from_millis(100)) == OsalRsBool::False {
+            FAILURES.fetch_add(1, Ordering::SeqCst);
+        }
+        if queue_isr.post(&1u32.to_le_bytes(), Duration::from_millis(100).to_ticks()).is_err() {
+            FAILURES.fetch_add(1, Ordering::SeqCst);
+        }
+        if mutex_isr.lock().is_err() {
+            FAILURES.fetch_add(1, Ordering::SeqCst);
+        }
+        System::delay(Duration::from_millis(100).to_ticks());
+        ELAPSED.store(System::get_tick_count().wrapping_sub(start), Ordering::SeqCst);
+    })?;
+
+    interrupt.trigger();
+    Interrupt::wait_idle(Duration::from_millis(1000));
+
+    log_debug!(TAG, "Blocking calls failed: {}, handler took {} ticks", FAILURES.load(Ordering::SeqCst), ELAPSED.load(Ordering::SeqCst));
+    assert_eq!(FAILURES.load(Ordering::SeqCst), 3);
+    assert!(ELAPSED.load(Ordering::SeqCst) < Duration::from_millis(50).to_ticks());
+    log_info!(TAG, "test_isr_blocking_forbidden PASSED");
+    Ok(())
+}
+
+pub fn test_isr_critical_section_masks() -> Result<()> {
+    log_info!(TAG, "Starting test_isr_critical_section_masks");
+    static ORDER: AtomicU32 = AtomicU32::new(0);
+
+    let low = Interrupt::new(21, || {
+        ORDER.store(ORDER.load(Ordering::SeqCst) * 10 + 2, Ordering::SeqCst);
+    })?;
+    let high = Interrupt::new(20, || {
+        ORDER.store(ORDER.load(Ordering::SeqCst) * 10 + 1, Ordering::SeqCst);
+    })?;
+
+    System::critical_section_enter();
+    low.trigger();
+    low.trigger();
+    high.trigger();
+    System::delay(Duration::from_millis(20).to_ticks());
+    let masked_runs = low.run_count() + high.run_count();
+    System::critical_section_exit();
+
+    Interrupt::wait_idle(Duration::from_millis(100));
+    log_debug!(TAG, "Runs while masked: {}, order: {}", masked_runs, ORDER.load(Ordering::SeqCst));
+    assert_eq!(masked_runs, 0);
+    // Pending lines are coalesced and served by priority
+    assert_eq!(low.run_count(), 1);
+    assert_eq!(high.run_count(), 1);
+    assert_eq!(ORDER.load(Ordering::SeqCst), 12);
+    log_info!(TAG, "test_isr_critical_section_masks PASSED");
+    Ok(())
+}
+
+pub fn test_isr_notify_thread() -> Result<()> {
+    log_info!(TAG, "Starting test_isr_notify_thread");
+    static RECEIVED: AtomicU32 = AtomicU32::new(0);
+    static WOKEN: AtomicU32 = AtomicU32::new(0);
+
+    let mut thread = Thread::new("isr_notified", 1024, 5);
+    let waiter = thread.spawn_simple(|| {
+        if let Ok(value) = Thread::get_current().wait_notification(0, 0xFFFFFFFF, Duration::from_millis(1000).to_ticks()) {
+            RECEIVED.store(value, Ordering::SeqCst);
+        }
+    })?;
+
+    let target = waiter.clone();
+    let interrupt = Interrupt::new(6, move || {
+        let mut higher_priority_task_woken: BaseType = 0;
+        let _ = target.notify_from_isr(ThreadNotification::SetBits(0x10), &mut higher_priority_task_woken);
+        WOKEN.store(higher_priority_task_woken as u32, Ordering::SeqCst);
+        System::yield_from_isr(higher_priority_task_woken);
+    })?;
+
+    System::delay(Duration::from_millis(20).to_ticks());
+    interrupt.trigger();
+    waiter.join(null_mut())?;
+    Interrupt::wait_idle(Duration::from_millis(100));
+
+    assert_eq!(RECEIVED.load(Ordering::SeqCst), 0x10);
+    assert_eq!(WOKEN.load(Ordering::SeqCst), 1);
+    assert_eq!(interrupt.yield_count(), 1);
+    log_info!(TAG, "test_isr_notify_thread PASSED");
+    Ok(())
+}
+
+pub fn test_isr_preempts_threads() -> Result<()> {
+    log_info!(TAG, "Starting test_isr_preempts_threads");
+    static SPINS: AtomicU32 = AtomicU32::new(0);
+    static STOP: AtomicBool = AtomicBool::new(false);
+    static UNCHANGED: AtomicBool = AtomicBool::new(false);
+    SPINS.store(0, Ordering::SeqCst);
+    STOP.store(false, Ordering::SeqCst);
+
+    // Never enters osal-rs: it can only be stopped by the interrupt
+    let mut thread = Thread::new("isr_spinner", 1024, 5);
+    let spinner = thread.spawn_simple(|| {
+        while !STOP.load(Ordering::SeqCst) {
+            SPINS.fetch_add(1, Ordering::SeqCst);
+        }
+    })?;
+
+    // Long enough for the host scheduler to run the spinner meanwhile, were
+    // it not stopped, even on a single CPU
+    let interrupt = Interrupt::new(7, || {
+        let before = SPINS.load(Ordering::SeqCst);
+        for _ in 0..10_000_000 {
+            core::hint::spin_loop();
+        }
+        UNCHANGED.store(SPINS.load(Ordering::SeqCst) == before, Ordering::SeqCst);
+    })?;
+
+    while SPINS.load(Ordering::SeqCst) == 0 {
+        System::delay(0);
+    }
+    interrupt.trigger();
+    let idle = Interrupt::wait_idle(Duration::from_millis(1000));
+    let spins = SPINS.load(Ordering::SeqCst);
+    while SPINS.load(Ordering::SeqCst) == spins {
+        System::delay(0);
+    }
+    STOP.store(true, Ordering::SeqCst);
+    spinner.join(null_mut())?;
+
+    assert_eq!(idle, OsalRsBool::True);
+    assert!(UNCHANGED.load(Ordering::SeqCst));
+    log_info!(TAG, "test_isr_preempts_threads PASSED");
+    Ok(())
+}
+
+register_tests! {
+    test_isr_context,
+    test_isr_duplicate_line,
+    test_isr_semaphore_to_thread,
+    test_isr_queue_to_thread,
+    test_isr_blocking_forbidden,
+    test_isr_critical_section_masks,
+    test_isr_notify_thread,
+    test_isr_preempts_threads,
+}
@@ -0,0 +1,93 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+pub mod thread_tests;
+pub mod event_group_tests;
+pub mod isr_tests;
+pub mod mutex_tests;
+pub mod named_queue_tests;
+pub mod queue_tests;
+pub mod system_tests;
+#[cfg(feature = "virtual_time")]
+pub mod virtual_time_tests;
+
+#[cfg(feature = "virtual_time")]
+use core::time::Duration;
+use alloc::vec::Vec;
+use osal_rs::os::*;
+use osal_rs::utils::Result;
+use crate::conformance::{Backend, Conformance};
+use crate::harness::{Harness, Test};
+use osal_rs::log_info;
+
+const TAG: &str = "PosixTests";
+
+/// The POSIX types under the conformance suite
+pub struct Posix;
+
+impl Backend for Posix {
+    type Thread = Thread;
+    type Queue = Queue;
+    type QueueSet = QueueSet;
+    type MessageBuffer = MessageBuffer;
+    type MessageBufferStreamed<T: ToBytes + FromBytes + 'static> = MessageBufferStreamed<T>;
+    type Mutex<T: Send + 'static> = Mutex<T>;
+    type RecursiveMutex<T: Send + 'static> = RecursiveMutex<T>;
+    type Semaphore = Semaphore;
+    type BinarySemaphore = BinarySemaphore;
+    type StreamBuffer = StreamBuffer;
+    type EventGroup = EventGroup;
+    type Timer = Timer;
+    type System = System;
+}
+
+/// Tests of what only the POSIX backend provides
+pub const SUITES: &[&[Test]] = &[
+    event_group_tests::TESTS,
+    isr_tests::TESTS,
+    mutex_tests::TESTS,
+    named_queue_tests::TESTS,
+    queue_tests::TESTS,
+    system_tests::TESTS,
+    thread_tests::TESTS,
+    #[cfg(feature = "virtual_time")]
+    virtual_time_tests::TESTS,
+];
+
+/// Run all available POSIX tests
+pub fn run_all_tests() -> Result<()> {
+    run_tests(None)
+}
+
+/// Run the conformance suite and the POSIX tests whose name contains
+/// `filter`, each in its own thread: a failing test does not stop the run
+pub fn run_tests(filter: Option<&str>) -> Result<()> {
+    log_info!(TAG, "\n\n========================================");
+    log_info!(TAG, "   Starting POSIX Test Suite");
+    log_info!(TAG, "========================================\n");
+
+    let harness = Harness::new().with_filter(filter);
+
+    // Virtual delays reach an hour: the timeout is counted on the same clock
+    #[cfg(feature = "virtual_time")]
+    let harness = harness.with_timeout(Duration::from_secs(2 * 3600));
+
+    let suites: Vec<&[Test]> = [Conformance::<Posix>::SUITES, SUITES].concat();
+    harness.run(&suites).result()
+}
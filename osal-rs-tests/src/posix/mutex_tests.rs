@@ -0,0 +1,74 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::time::Duration;
+use osal_rs::os::*;
+use osal_rs::utils::{OsalRsBool, Result};
+use osal_rs::{log_debug, log_info};
+
+const TAG: &str = "MutexTests";
+
+pub fn test_mutex_relock_fails() -> Result<()> {
+    log_info!(TAG, "Starting test_mutex_relock_fails");
+    let mutex = Mutex::new(0u32);
+
+    let _guard = mutex.lock()?;
+    // A non-recursive mutex taken again by its owner would deadlock
+    assert!(mutex.lock().is_err());
+    log_debug!(TAG, "Second lock by the owner failed");
+    log_info!(TAG, "test_mutex_relock_fails PASSED");
+    Ok(())
+}
+
+pub fn test_mutex_isr_forbidden() -> Result<()> {
+    log_info!(TAG, "Starting test_mutex_isr_forbidden");
+    static FAILURES: AtomicU32 = AtomicU32::new(0);
+
+    let mutex = Arc::new(Mutex::new(0u32));
+    let recursive = Arc::new(RecursiveMutex::new(0u32));
+
+    let (mutex_isr, recursive_isr) = (mutex.clone(), recursive.clone());
+    let interrupt = Interrupt::new(7, move || {
+        if mutex_isr.try_lock().is_err() {
+            FAILURES.fetch_add(1, Ordering::SeqCst);
+        }
+        if recursive_isr.try_lock().is_err() {
+            FAILURES.fetch_add(1, Ordering::SeqCst);
+        }
+    })?;
+
+    interrupt.trigger();
+    assert_eq!(Interrupt::wait_idle(Duration::from_millis(100)), OsalRsBool::True);
+    assert_eq!(FAILURES.load(Ordering::SeqCst), 2);
+
+    // Both are still free for threads
+    assert!(mutex.try_lock().is_ok());
+    assert!(recursive.try_lock().is_ok());
+    log_info!(TAG, "test_mutex_isr_forbidden PASSED");
+    Ok(())
+}
+
+register_tests! {
+    test_mutex_relock_fails,
+    test_mutex_isr_forbidden,
+}
@@ -0,0 +1,216 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+extern crate alloc;
+
+use core::ptr::null_mut;
+use core::time::Duration;
+use osal_rs::os::*;
+use osal_rs::utils::{Error, Result};
+use osal_rs::{log_debug, log_info};
+
+use super::queue_tests::Packet;
+
+const TAG: &str = "NamedQueueTests";
+
+const WAIT_FOREVER: types::TickType = types::TickType::MAX;
+
+pub fn test_named_queue_create_open() -> Result<()> {
+    log_info!(TAG, "Starting test_named_queue_create_open");
+    let _ = NamedQueue::unlink("osal-rs-test-open");
+
+    let writer = NamedQueue::create("osal-rs-test-open", 4, 8)?;
+    let reader = NamedQueue::open("/osal-rs-test-open")?;
+    assert_eq!(reader.message_size(), 8);
+
+    writer.post(&0x1122_3344_5566_7788u64.to_le_bytes(), 0)?;
+    assert_eq!(reader.count()?, 1);
+    let mut received = [0u8; 8];
+    reader.fetch(&mut received, Duration::from_millis(100).to_ticks())?;
+    assert_eq!(u64::from_le_bytes(received), 0x1122_3344_5566_7788);
+
+    // Opened descriptors outlive the name
+    NamedQueue::unlink("osal-rs-test-open")?;
+    assert!(matches!(NamedQueue::open("osal-rs-test-open"), Err(Error::NotFound)));
+    writer.post(&[1; 8], 0)?;
+    reader.fetch(&mut received, 0)?;
+    assert_eq!(received, [1; 8]);
+    log_info!(TAG, "test_named_queue_create_open PASSED");
+    Ok(())
+}
+
+pub fn test_named_queue_invalid() -> Result<()> {
+    log_info!(TAG, "Starting test_named_queue_invalid");
+    let _ = NamedQueue::unlink("osal-rs-test-invalid");
+
+    let _queue = NamedQueue::create("osal-rs-test-invalid", 4, 8)?;
+    assert!(NamedQueue::create("osal-rs-test-invalid", 4, 8).is_ok());
+    assert!(matches!(NamedQueue::create("osal-rs-test-invalid", 4, 16), Err(Error::InvalidQueueSize)));
+    assert!(matches!(NamedQueue::create("osal-rs-test-zero", 0, 8), Err(Error::InvalidQueueSize)));
+    assert!(NamedQueue::create("osal-rs/test", 4, 8).is_err());
+    assert!(NamedQueue::open("").is_err());
+    NamedQueue::unlink("osal-rs-test-invalid")?;
+    assert!(matches!(NamedQueue::unlink("osal-rs-test-invalid"), Err(Error::NotFound)));
+    log_info!(TAG, "test_named_queue_invalid PASSED");
+    Ok(())
+}
+
+pub fn test_named_queue_timeout() -> Result<()> {
+    log_info!(TAG, "Starting test_named_queue_timeout");
+    let queue = NamedQueue::new(2, 4)?;
+
+    let mut buffer = [0u8; 4];
+    assert!(matches!(queue.fetch(&mut buffer, Duration::from_millis(10).to_ticks()), Err(Error::Timeout)));
+
+    queue.post(&[1; 4], 0)?;
+    queue.post(&[2; 4], 0)?;
+    assert!(matches!(queue.post(&[3; 4], Duration::from_millis(10).to_ticks()), Err(Error::Timeout)));
+    assert!(matches!(queue.post(&[3; 4], 0), Err(Error::Timeout)));
+    log_info!(TAG, "test_named_queue_timeout PASSED");
+    Ok(())
+}
+
+pub fn test_named_queue_message_size() -> Result<()> {
+    log_info!(TAG, "Starting test_named_queue_message_size");
+    let queue = NamedQueue::new(4, 8)?;
+
+    // Short items are zero padded, long ones truncated
+    queue.post(&[0xAA; 4], 0)?;
+    queue.post(&[0xBB; 12], 0)?;
+
+    let mut received = [0xFFu8; 8];
+    queue.fetch(&mut received, 0)?;
+    assert_eq!(received, [0xAA, 0xAA, 0xAA, 0xAA, 0, 0, 0, 0]);
+
+    let mut short = [0u8; 2];
+    queue.fetch(&mut short, 0)?;
+    assert_eq!(short, [0xBB; 2]);
+    log_info!(TAG, "test_named_queue_message_size PASSED");
+    Ok(())
+}
+
+pub fn test_named_queue_between_threads() -> Result<()> {
+    log_info!(TAG, "Starting test_named_queue_between_threads");
+    let _ = NamedQueue::unlink("osal-rs-test-threads");
+    let consumer = NamedQueue::create("osal-rs-test-threads", 2, 4)?;
+
+    let mut thread = Thread::new("mq_producer", 1024, 5);
+    let producer = thread.spawn_simple(|| {
+        // Reaches the queue by name only, as another process would
+        let queue = NamedQueue::open("osal-rs-test-threads").unwrap();
+        for i in 0..10u32 {
+            queue.post(&i.to_le_bytes(), WAIT_FOREVER).unwrap();
+        }
+    })?;
+
+    for i in 0..10u32 {
+        let mut received = [0u8; 4];
+        consumer.fetch(&mut received, Duration::from_millis(1000).to_ticks())?;
+        assert_eq!(u32::from_le_bytes(received), i);
+    }
+    producer.join(null_mut())?;
+    NamedQueue::unlink("osal-rs-test-threads")?;
+    log_debug!(TAG, "Received 10 items through the named queue");
+    log_info!(TAG, "test_named_queue_between_threads PASSED");
+    Ok(())
+}
+
+pub fn test_named_queue_streamed() -> Result<()> {
+    log_info!(TAG, "Starting test_named_queue_streamed");
+    let _ = NamedQueueStreamed::<Packet>::unlink("osal-rs-test-streamed");
+
+    let sender = NamedQueueStreamed::<Packet>::create("osal-rs-test-streamed", 5, 4)?;
+    let receiver = NamedQueueStreamed::<Packet>::open("osal-rs-test-streamed")?;
+    for i in 10..15u32 {
+        sender.post(&Packet(i.to_le_bytes()), 0)?;
+    }
+
+    for i in 10..15u32 {
+        let mut received = Packet([0; 4]);
+        receiver.fetch(&mut received, Duration::from_millis(100).to_ticks())?;
+        assert_eq!(u32::from_le_bytes(received.0), i);
+    }
+    NamedQueueStreamed::<Packet>::unlink("osal-rs-test-streamed")?;
+    log_info!(TAG, "test_named_queue_streamed PASSED");
+    Ok(())
+}
+
+pub fn test_named_queue_from_isr() -> Result<()> {
+    log_info!(TAG, "Starting test_named_queue_from_isr");
+    let queue = alloc::sync::Arc::new(NamedQueue::new(1, 4)?);
+    let queue_isr = queue.clone();
+
+    let interrupt = Interrupt::new(12, move || {
+        // The second post finds the queue full and must not block
+        let _ = queue_isr.post_from_isr(&7u32.to_le_bytes());
+        let _ = queue_isr.post(&8u32.to_le_bytes(), Duration::from_millis(100).to_ticks());
+    })?;
+
+    interrupt.trigger();
+    Interrupt::wait_idle(Duration::from_millis(1000));
+    assert_eq!(queue.count()?, 1);
+
+    let mut received = [0u8; 4];
+    queue.fetch(&mut received, 0)?;
+    assert_eq!(u32::from_le_bytes(received), 7);
+    log_info!(TAG, "test_named_queue_from_isr PASSED");
+    Ok(())
+}
+
+pub fn test_named_queue_front_overwrite_reset() -> Result<()> {
+    log_info!(TAG, "Starting test_named_queue_front_overwrite_reset");
+    let queue = NamedQueue::new(4, 4)?;
+
+    // Messages posted to the front keep their order among themselves
+    queue.post(&1u32.to_le_bytes(), 0)?;
+    queue.post_to_front(&10u32.to_le_bytes(), 0)?;
+    queue.post_to_front(&11u32.to_le_bytes(), 0)?;
+    let mut received = [0u8; 4];
+    for expected in [10u32, 11, 1] {
+        queue.fetch(&mut received, 0)?;
+        assert_eq!(u32::from_le_bytes(received), expected);
+    }
+
+    // No peek on POSIX message queues
+    queue.post(&2u32.to_le_bytes(), 0)?;
+    assert!(matches!(queue.peek(&mut received, 0), Err(Error::Unhandled(_))));
+    assert_eq!(queue.count()?, 1);
+
+    queue.reset()?;
+    assert_eq!(queue.count()?, 0);
+
+    let mailbox = NamedQueue::new(1, 4)?;
+    mailbox.overwrite(&3u32.to_le_bytes())?;
+    mailbox.overwrite(&4u32.to_le_bytes())?;
+    assert_eq!(mailbox.count()?, 1);
+    mailbox.fetch(&mut received, 0)?;
+    assert_eq!(u32::from_le_bytes(received), 4);
+    log_info!(TAG, "test_named_queue_front_overwrite_reset PASSED");
+    Ok(())
+}
+
+register_tests! {
+    test_named_queue_create_open,
+    test_named_queue_invalid,
+    test_named_queue_timeout,
+    test_named_queue_message_size,
+    test_named_queue_between_threads,
+    test_named_queue_streamed,
+    test_named_queue_from_isr,
+    test_named_queue_front_overwrite_reset,
+}
@@ -0,0 +1,83 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use osal_rs::os::*;
+use osal_rs::utils::{Error, Result};
+use core::time::Duration;
+use osal_rs::log_info;
+
+const TAG: &str = "QueueTests";
+
+pub fn test_queue_from_isr() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_from_isr");
+    let queue = Queue::new(1, 4)?;
+
+    let mut received = [0u8; 4];
+    assert!(queue.fetch_from_isr(&mut received).is_err());
+    assert!(queue.post_from_isr(&7u32.to_le_bytes()).is_ok());
+    assert!(queue.post_from_isr(&8u32.to_le_bytes()).is_err());
+    assert!(queue.fetch_from_isr(&mut received).is_ok());
+    assert_eq!(u32::from_le_bytes(received), 7);
+    log_info!(TAG, "test_queue_from_isr PASSED");
+    Ok(())
+}
+
+pub(crate) struct Packet(pub(crate) [u8; 4]);
+
+impl ToBytes for Packet {
+    fn to_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl BytesHasLen for Packet {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl FromBytes for Packet {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(Self(bytes.try_into().map_err(|_| Error::InvalidType)?))
+    }
+}
+
+pub fn test_queue_streamed() -> Result<()> {
+    log_info!(TAG, "Starting test_queue_streamed");
+    let queue = QueueStreamed::<Packet>::new(5, 4)?;
+
+    for i in 10..15u32 {
+        queue.post(&Packet(i.to_le_bytes()), Duration::from_millis(100).to_ticks())?;
+    }
+
+    for i in 10..15u32 {
+        let mut received = Packet([0; 4]);
+        queue.fetch(&mut received, Duration::from_millis(100).to_ticks())?;
+        assert_eq!(u32::from_le_bytes(received.0), i);
+    }
+    log_info!(TAG, "test_queue_streamed PASSED");
+    Ok(())
+}
+
+register_tests! {
+    test_queue_from_isr,
+    test_queue_streamed,
+}
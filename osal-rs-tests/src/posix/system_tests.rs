@@ -0,0 +1,118 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use core::time::Duration;
+use osal_rs::os::*;
+use osal_rs::utils::Result;
+use osal_rs::{log_debug, log_info};
+
+const TAG: &str = "SystemTests";
+
+pub fn test_system_count_threads() -> Result<()> {
+    log_info!(TAG, "Starting test_system_count_threads");
+    let before = System::count_threads();
+
+    let running = Arc::new(AtomicBool::new(true));
+    let running_clone = Arc::clone(&running);
+    let mut thread = Thread::new("count_thread", 1024, 5);
+    let spawned = thread.spawn_simple(move || {
+        while running_clone.load(Ordering::SeqCst) {
+            System::delay(1);
+        }
+    })?;
+
+    let during = System::count_threads();
+    running.store(false, Ordering::SeqCst);
+    spawned.join(null_mut())?;
+    let after = System::count_threads();
+
+    log_debug!(TAG, "Number of threads: {} -> {} -> {}", before, during, after);
+    assert_eq!(during, before + 1);
+    assert_eq!(after, before);
+    log_info!(TAG, "test_system_count_threads PASSED");
+    Ok(())
+}
+
+pub fn test_system_critical_section_recursive() -> Result<()> {
+    log_info!(TAG, "Starting test_system_critical_section_recursive");
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    System::critical_section_enter();
+    System::critical_section_enter();
+
+    let mut thread = Thread::new("critical_thread", 1024, 5);
+    let spawned = thread.spawn_simple(|| {
+        System::critical_section_enter();
+        COUNTER.fetch_add(1, Ordering::SeqCst);
+        System::critical_section_exit();
+    })?;
+
+    System::delay(Duration::from_millis(20).to_ticks());
+    log_debug!(TAG, "Counter while nested section is held: {}", COUNTER.load(Ordering::SeqCst));
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 0);
+
+    System::critical_section_exit();
+    System::delay(Duration::from_millis(20).to_ticks());
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 0);
+
+    System::critical_section_exit();
+    spawned.join(null_mut())?;
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 1);
+    log_info!(TAG, "test_system_critical_section_recursive PASSED");
+    Ok(())
+}
+
+pub fn test_system_tick_rate() -> Result<()> {
+    log_info!(TAG, "Starting test_system_tick_rate");
+    assert!(config::set_tick_rate_hz(0).is_err());
+    assert!(config::set_tick_rate_hz(config::tick_rate_hz()).is_ok());
+    assert_eq!(Duration::from_millis(1000).to_ticks(), config::tick_rate_hz());
+    log_info!(TAG, "test_system_tick_rate PASSED");
+    Ok(())
+}
+
+pub fn test_system_thread_signals() -> Result<()> {
+    log_info!(TAG, "Starting test_system_thread_signals");
+    let (suspend, resume) = (config::suspend_signal(), config::resume_signal());
+    assert_ne!(suspend, resume);
+
+    assert!(config::set_thread_signals(0, resume).is_err());
+    assert!(config::set_thread_signals(suspend, suspend).is_err());
+    assert!(config::set_thread_signals(config::sigrtmax() + 1, resume).is_err());
+    // SIGKILL cannot be caught
+    assert!(config::set_thread_signals(9, resume).is_err());
+
+    // The harness runs each test in a thread: the signals are already in use
+    assert!(config::set_thread_signals(config::sigrtmin(), config::sigrtmin() + 1).is_err());
+    assert_eq!((config::suspend_signal(), config::resume_signal()), (suspend, resume));
+    log_info!(TAG, "test_system_thread_signals PASSED");
+    Ok(())
+}
+
+register_tests! {
+    test_system_count_threads,
+    test_system_critical_section_recursive,
+    test_system_tick_rate,
+    test_system_thread_signals,
+}
@@ -0,0 +1,207 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::time::Duration;
+use osal_rs::os::*;
+use osal_rs::os::types::BaseType;
+use osal_rs::utils::Result;
+use osal_rs::{log_debug, log_info};
+
+const TAG: &str = "ThreadTests";
+
+pub fn test_thread_creation() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_creation");
+    let thread = Thread::new(
+        "test_thread",
+        1024,
+        5
+    );
+
+    let metadata = thread.get_metadata();
+    log_debug!(TAG, "Thread metadata: name={}, stack={}, priority={}", metadata.name, metadata.stack_depth, metadata.priority);
+    assert_eq!(metadata.name, "test_thread");
+    assert_eq!(metadata.stack_depth, 1024);
+    assert_eq!(metadata.priority, 5);
+    assert!(metadata.thread.is_null());
+    log_info!(TAG, "test_thread_creation PASSED");
+    Ok(())
+}
+
+pub fn test_thread_spawn_join() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_spawn_join");
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    let mut thread = Thread::new(
+        "join_test",
+        1024,
+        5
+    );
+
+    let spawned = thread.spawn(None, |_thread, param| {
+        System::delay(Duration::from_millis(20).to_ticks());
+        COUNTER.fetch_add(1, Ordering::SeqCst);
+        Ok(param.unwrap_or_else(|| Arc::new(())))
+    })?;
+
+    assert!(!spawned.get_metadata().thread.is_null());
+    spawned.join(null_mut())?;
+
+    log_debug!(TAG, "Thread joined, counter: {}", COUNTER.load(Ordering::SeqCst));
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 1);
+    assert!(spawned.join(null_mut()).is_err());
+    log_info!(TAG, "test_thread_spawn_join PASSED");
+    Ok(())
+}
+
+pub fn test_thread_suspend_resume() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_suspend_resume");
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    let mut thread = Thread::new(
+        "suspend_test",
+        1024,
+        5
+    );
+
+    let spawned = thread.spawn(None, |_thread, param| {
+        for _ in 0..10 {
+            COUNTER.fetch_add(1, Ordering::SeqCst);
+            System::delay(Duration::from_millis(5).to_ticks());
+        }
+        Ok(param.unwrap_or_else(|| Arc::new(())))
+    })?;
+
+    System::delay(Duration::from_millis(12).to_ticks());
+    log_debug!(TAG, "Suspending thread...");
+    spawned.suspend();
+    assert_eq!(spawned.get_metadata().state, ThreadState::Suspended);
+
+    let count = COUNTER.load(Ordering::SeqCst);
+    System::delay(Duration::from_millis(50).to_ticks());
+    assert_eq!(COUNTER.load(Ordering::SeqCst), count);
+
+    log_debug!(TAG, "Resuming thread...");
+    spawned.resume();
+    spawned.join(null_mut())?;
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 10);
+    log_info!(TAG, "test_thread_suspend_resume PASSED");
+    Ok(())
+}
+
+pub fn test_thread_get_metadata() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_get_metadata");
+    let mut thread = Thread::new(
+        "metadata_test",
+        1024,
+        5
+    );
+
+    let spawned = thread.spawn(None, |_thread, param| {
+        System::delay(Duration::from_millis(20).to_ticks());
+        Ok(param.unwrap_or_else(|| Arc::new(())))
+    })?;
+    
+    let metadata = spawned.get_metadata();
+    
+    log_debug!(TAG, "Metadata - name: {}, priority: {}", metadata.name, metadata.priority);
+    assert_eq!(metadata.name, "metadata_test");
+    assert_eq!(metadata.priority, 5);
+    assert_ne!(metadata.state, ThreadState::Deleted);
+
+    spawned.join(null_mut())?;
+    assert_eq!(spawned.get_metadata().state, ThreadState::Deleted);
+    log_info!(TAG, "test_thread_get_metadata PASSED");
+    Ok(())
+}
+
+pub fn test_thread_get_current() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_get_current");
+    let current = Thread::get_current();
+    let metadata = current.get_metadata();
+    log_debug!(TAG, "Current thread: {}", metadata.name);
+    assert!(!metadata.thread.is_null());
+    assert_eq!(metadata.state, ThreadState::Running);
+    assert_eq!(*Thread::get_current(), metadata.thread);
+    log_info!(TAG, "test_thread_get_current PASSED");
+    Ok(())
+}
+
+pub fn test_thread_notification() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_notification");
+    static RECEIVED: AtomicU32 = AtomicU32::new(0);
+
+    let mut thread = Thread::new(
+        "notify_test",
+        1024,
+        5
+    );
+
+    let spawned = thread.spawn(None, |thread, _param| {
+        let notification = thread.wait_notification(0, 0xFFFFFFFF, Duration::from_millis(1000).to_ticks())?;
+        log_debug!(TAG, "Received notification: 0x{:X}", notification);
+        RECEIVED.store(notification, Ordering::SeqCst);
+        Ok(Arc::new(()))
+    })?;
+
+    System::delay(Duration::from_millis(10).to_ticks());
+    assert_eq!(spawned.get_metadata().state, ThreadState::Blocked);
+    log_debug!(TAG, "Sending notification: 0x12345678");
+    let notify_result = spawned.notify(ThreadNotification::SetValueWithOverwrite(0x12345678));
+    assert!(notify_result.is_ok());
+
+    spawned.join(null_mut())?;
+    assert_eq!(RECEIVED.load(Ordering::SeqCst), 0x12345678);
+    log_info!(TAG, "test_thread_notification PASSED");
+    Ok(())
+}
+
+/// Drops a pending notification and zeroes the value left by a previous test
+fn clear_notification(thread: &Thread) {
+    let _ = thread.wait_notification(0xFFFFFFFF, 0xFFFFFFFF, 0);
+}
+
+pub fn test_thread_notification_from_isr() -> Result<()> {
+    log_info!(TAG, "Starting test_thread_notification_from_isr");
+    let current = Thread::get_current();
+    clear_notification(&current);
+
+    let mut higher_priority_task_woken: BaseType = 0;
+    current.notify_from_isr(ThreadNotification::SetBits(0x2), &mut higher_priority_task_woken)?;
+    assert_eq!(current.wait_notification(0, 0xFFFFFFFF, 0)?, 0x2);
+
+    let unspawned = Thread::new("unspawned", 1024, 5);
+    assert!(unspawned.notify(ThreadNotification::Increment).is_err());
+    log_info!(TAG, "test_thread_notification_from_isr PASSED");
+    Ok(())
+}
+
+register_tests! {
+    test_thread_creation,
+    test_thread_spawn_join,
+    test_thread_suspend_resume,
+    test_thread_get_metadata,
+    test_thread_get_current,
+    test_thread_notification,
+    test_thread_notification_from_isr,
+}
@@ -0,0 +1,155 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicU32, Ordering};
+use osal_rs::os::*;
+use osal_rs::utils::{Error, Result, OsalRsBool};
+use core::time::Duration;
+use osal_rs::{log_debug, log_info};
+
+const TAG: &str = "VirtualTimeTests";
+
+fn elapsed_since(start: types::TickType) -> types::TickType {
+    System::get_tick_count().wrapping_sub(start)
+}
+
+pub fn test_virtual_delay_exact() -> Result<()> {
+    log_info!(TAG, "Starting test_virtual_delay_exact");
+    let start = System::get_tick_count();
+    System::delay(Duration::from_millis(1000).to_ticks());
+    assert_eq!(elapsed_since(start), Duration::from_millis(1000).to_ticks());
+
+    // An hour of virtual time takes no real time
+    let start = System::get_tick_count();
+    System::delay(Duration::from_secs(3600).to_ticks());
+    assert_eq!(elapsed_since(start), Duration::from_secs(3600).to_ticks());
+    log_info!(TAG, "test_virtual_delay_exact PASSED");
+    Ok(())
+}
+
+pub fn test_virtual_timeouts_exact() -> Result<()> {
+    log_info!(TAG, "Starting test_virtual_timeouts_exact");
+    let semaphore = Semaphore::new(1, 0)?;
+    let start = System::get_tick_count();
+    assert_eq!(semaphore.wait(Duration::from_millis(250)), OsalRsBool::False);
+    assert_eq!(elapsed_since(start), Duration::from_millis(250).to_ticks());
+
+    let queue = Queue::new(4, 4)?;
+    let mut buffer = [0u8; 4];
+    let start = System::get_tick_count();
+    let result = queue.fetch(&mut buffer, Duration::from_millis(100).to_ticks());
+    assert!(matches!(result, Err(Error::Timeout)));
+    assert_eq!(elapsed_since(start), Duration::from_millis(100).to_ticks());
+
+    let event_group = EventGroup::new()?;
+    let start = System::get_tick_count();
+    let bits = event_group.wait(0x01, Duration::from_millis(75).to_ticks());
+    assert_eq!(bits & 0x01, 0);
+    assert_eq!(elapsed_since(start), Duration::from_millis(75).to_ticks());
+    log_info!(TAG, "test_virtual_timeouts_exact PASSED");
+    Ok(())
+}
+
+pub fn test_virtual_timer_period_exact() -> Result<()> {
+    log_info!(TAG, "Starting test_virtual_timer_period_exact");
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    let timer = Timer::new(
+        "virtual_timer",
+        Duration::from_millis(50).to_ticks(),
+        true,
+        None,
+        |_timer, param| {
+            COUNTER.fetch_add(1, Ordering::SeqCst);
+            Ok(param.unwrap_or_else(|| Arc::new(())))
+        }
+    )?;
+
+    assert_eq!(timer.start(0), OsalRsBool::True);
+    System::delay(Duration::from_millis(1025).to_ticks());
+    timer.stop(0);
+
+    let count = COUNTER.load(Ordering::SeqCst);
+    log_debug!(TAG, "Auto-reload timer fired {} times", count);
+    assert_eq!(count, 20);
+    log_info!(TAG, "test_virtual_timer_period_exact PASSED");
+    Ok(())
+}
+
+pub fn test_virtual_thread_order() -> Result<()> {
+    log_info!(TAG, "Starting test_virtual_thread_order");
+    static START: AtomicU32 = AtomicU32::new(0);
+
+    let queue = Arc::new(Queue::new(4, 8)?);
+    START.store(System::get_tick_count() as u32, Ordering::SeqCst);
+
+    let mut threads = alloc::vec::Vec::new();
+    for (id, delay_ms) in [(1u32, 30u64), (2u32, 20u64)] {
+        let queue = queue.clone();
+        let mut thread = Thread::new("virtual_order", 1024, 5);
+        threads.push(thread.spawn_simple(move || {
+            System::delay(Duration::from_millis(delay_ms).to_ticks());
+            let woken_at = System::get_tick_count().wrapping_sub(START.load(Ordering::SeqCst) as types::TickType) as u32;
+            let mut item = [0u8; 8];
+            item[..4].copy_from_slice(&id.to_le_bytes());
+            item[4..].copy_from_slice(&woken_at.to_le_bytes());
+            queue.post(&item, 0).unwrap();
+        })?);
+    }
+
+    let mut item = [0u8; 8];
+    for (expected_id, expected_ms) in [(2u32, 20u64), (1u32, 30u64)] {
+        queue.fetch(&mut item, Duration::from_millis(100).to_ticks())?;
+        let id = u32::from_le_bytes(item[..4].try_into().unwrap());
+        let woken_at = u32::from_le_bytes(item[4..].try_into().unwrap());
+        log_debug!(TAG, "Thread {} woke up at tick {}", id, woken_at);
+        assert_eq!(id, expected_id);
+        assert_eq!(woken_at as types::TickType, Duration::from_millis(expected_ms).to_ticks());
+    }
+
+    for thread in threads {
+        thread.join(null_mut())?;
+    }
+    log_info!(TAG, "test_virtual_thread_order PASSED");
+    Ok(())
+}
+
+pub fn test_virtual_advance() -> Result<()> {
+    log_info!(TAG, "Starting test_virtual_advance");
+    let start = System::get_tick_count();
+    let now = VirtualTime::now();
+
+    VirtualTime::advance(Duration::from_millis(500));
+
+    assert_eq!(VirtualTime::now() - now, Duration::from_millis(500).to_ticks() as u64);
+    assert_eq!(elapsed_since(start), Duration::from_millis(500).to_ticks());
+    log_info!(TAG, "test_virtual_advance PASSED");
+    Ok(())
+}
+
+register_tests! {
+    test_virtual_delay_exact,
+    test_virtual_timeouts_exact,
+    test_virtual_timer_period_exact,
+    test_virtual_thread_order,
+    test_virtual_advance,
+}
@@ -0,0 +1,129 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+//! Host unit tests of the FreeRTOS wrappers against the recording kernel:
+//! `cargo test -p osal-rs-tests --features mock`
+
+#![cfg(feature = "mock")]
+
+use osal_rs_tests::freertos::mock_tests;
+
+#[test]
+fn queue_calls() {
+    mock_tests::test_mock_queue_calls().unwrap();
+}
+
+#[test]
+fn queue_errors() {
+    mock_tests::test_mock_queue_errors().unwrap();
+}
+
+#[test]
+fn queue_front_overwrite_peek() {
+    mock_tests::test_mock_queue_front_overwrite_peek().unwrap();
+}
+
+#[test]
+fn queue_set() {
+    mock_tests::test_mock_queue_set().unwrap();
+}
+
+#[test]
+fn stream_buffer() {
+    mock_tests::test_mock_stream_buffer().unwrap();
+}
+
+#[test]
+fn message_buffer() {
+    mock_tests::test_mock_message_buffer().unwrap();
+}
+
+#[test]
+fn mutex_kinds() {
+    mock_tests::test_mock_mutex_kinds().unwrap();
+}
+
+#[test]
+fn event_group_wait() {
+    mock_tests::test_mock_event_group_wait().unwrap();
+}
+
+#[test]
+fn thread_spawn() {
+    mock_tests::test_mock_thread_spawn().unwrap();
+}
+
+#[test]
+fn thread_join() {
+    mock_tests::test_mock_thread_join().unwrap();
+}
+
+#[test]
+fn thread_join_deleted() {
+    mock_tests::test_mock_thread_join_deleted().unwrap();
+}
+
+#[test]
+fn thread_create_fails() {
+    mock_tests::test_mock_thread_create_fails().unwrap();
+}
+
+#[test]
+fn thread_control() {
+    mock_tests::test_mock_thread_control().unwrap();
+}
+
+#[test]
+fn thread_notification_indexed() {
+    mock_tests::test_mock_thread_notification_indexed().unwrap();
+}
+
+#[test]
+fn timer() {
+    mock_tests::test_mock_timer().unwrap();
+}
+
+#[test]
+fn timer_param() {
+    mock_tests::test_mock_timer_param().unwrap();
+}
+
+#[test]
+fn timer_delete() {
+    mock_tests::test_mock_timer_delete().unwrap();
+}
+
+#[test]
+fn static_thread() {
+    mock_tests::test_mock_static_thread().unwrap();
+}
+
+#[test]
+fn static_objects() {
+    mock_tests::test_mock_static_objects().unwrap();
+}
+
+#[test]
+fn delay() {
+    mock_tests::test_mock_delay().unwrap();
+}
+
+#[test]
+fn ticks_from_duration() {
+    mock_tests::test_mock_ticks_from_duration().unwrap();
+}
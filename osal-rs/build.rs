@@ -17,7 +17,7 @@
  *
  ***************************************************************************/
 
-use osal_rs_build::FreeRtosTypeGenerator;
+use osal_rs_build::{FreeRtosPosixPort, FreeRtosTypeGenerator};
 use std::env;
 use std::path::PathBuf;
 
@@ -38,6 +38,15 @@ fn main() {
     let freertos_config = workspace_root.join("inc/hhg-config/pico/FreeRTOSConfig.h");
     
     // Generate FreeRTOS type mappings and configuration constants
-    let generator = FreeRtosTypeGenerator::with_config_path(freertos_config);
+    let mut generator = FreeRtosTypeGenerator::with_config_path(freertos_config);
+
+    // FreeRTOS on Linux is the Posix_GCC simulator port: its base types are
+    // as wide as `long`, take them from the kernel headers when available
+    if env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("linux")
+        && let Some(port) = manifest_path.parent().and_then(FreeRtosPosixPort::locate) {
+        for dir in port.include_dirs() {
+            generator.add_include_dir(dir);
+        }
+    }
     generator.generate_all();
 }
@@ -21,10 +21,15 @@ pub mod allocator;
 pub mod config;
 pub mod duration;
 pub mod event_group;
+pub mod message_buffer;
 mod ffi;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod mutex;
 pub mod queue;
+pub mod queue_set;
 pub mod semaphore;
+pub mod stream_buffer;
 pub mod system;
 pub mod thread;
 pub mod timer;
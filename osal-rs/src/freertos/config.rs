@@ -19,6 +19,7 @@
 
 use crate::os::types::StackType;
 
+#[cfg(not(feature = "mock"))]
 pub mod ffi {
     use crate::freertos::types::{TickType, StackType};
 
@@ -28,6 +29,40 @@ pub mod ffi {
         pub fn osal_rs_config_max_priorities() -> u32;
         pub fn osal_rs_config_minimal_stack_size() -> StackType;
         pub fn osal_rs_config_max_task_name_len() -> u32;
+        pub fn osal_rs_config_task_notification_array_entries() -> u32;
+    }
+}
+
+/// Configuration of the recording kernel, the usual FreeRTOS defaults
+#[cfg(feature = "mock")]
+// Constants kept `unsafe` to match the C shim, whose callers use `unsafe` blocks
+#[allow(clippy::missing_safety_doc)]
+pub mod ffi {
+    use crate::freertos::types::{TickType, StackType};
+
+    pub unsafe fn osal_rs_config_cpu_clock_hz() -> u64 {
+        125_000_000
+    }
+
+    pub unsafe fn osal_rs_config_tick_rate_hz() -> TickType {
+        1_000
+    }
+
+    pub unsafe fn osal_rs_config_max_priorities() -> u32 {
+        32
+    }
+
+    pub unsafe fn osal_rs_config_minimal_stack_size() -> StackType {
+        128
+    }
+
+    pub unsafe fn osal_rs_config_max_task_name_len() -> u32 {
+        16
+    }
+
+    /// More than the default of 1, so that indexed notifications can be tested
+    pub unsafe fn osal_rs_config_task_notification_array_entries() -> u32 {
+        3
     }
 }
 
@@ -73,4 +108,11 @@ macro_rules! max_task_name_len {
     () => {
         (unsafe { $crate::os::config::ffi::osal_rs_config_max_task_name_len() })
     };
-}
\ No newline at end of file
+}
+
+#[macro_export]
+macro_rules! task_notification_array_entries {
+    () => {
+        (unsafe { $crate::os::config::ffi::osal_rs_config_task_notification_array_entries() })
+    };
+}
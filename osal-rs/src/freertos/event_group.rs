@@ -21,10 +21,10 @@ use core::fmt::{Debug, Display, Formatter};
 use core::ops::Deref;
 use core::ptr::null_mut;
 
-use super::ffi::{EventGroupHandle, pdFAIL, pdFALSE, vEventGroupDelete, xEventGroupClearBits, xEventGroupClearBitsFromISR, xEventGroupCreate, xEventGroupGetBitsFromISR, xEventGroupSetBits, xEventGroupSetBitsFromISR};
+use super::ffi::{EventGroupHandle, StaticEventGroupBuffer, pdFAIL, pdFALSE, pdTRUE, vEventGroupDelete, xEventGroupClearBits, xEventGroupClearBitsFromISR, xEventGroupCreate, xEventGroupCreateStatic, xEventGroupGetBitsFromISR, xEventGroupSetBits, xEventGroupSetBitsFromISR, xEventGroupSync, xEventGroupWaitBits};
 use super::system::System;
-use super::types::{BaseType, EventBits, TickType};
-use crate::traits::{ToTick, EventGroupFn, SystemFn};
+use super::types::{BaseType, EventBits, TickType, STATIC_EVENT_GROUP_SIZE};
+use crate::traits::{ToTick, EventGroupFn, EventWaitOptions, SystemFn};
 use crate::utils::{Result, Error};
 use crate::xEventGroupGetBits;
 
@@ -39,6 +39,31 @@ impl EventGroup {
     }
 }
 
+/// Storage of an event group that does not use the FreeRTOS heap
+pub struct StaticEventGroup(StaticEventGroupBuffer);
+
+impl StaticEventGroup {
+    pub const fn new() -> Self {
+        Self (StaticEventGroupBuffer([0; STATIC_EVENT_GROUP_SIZE]))
+    }
+
+    /// Creates the event group with `xEventGroupCreateStatic`
+    pub fn create(&'static mut self) -> Result<EventGroup> {
+        let handle = unsafe { xEventGroupCreateStatic(&mut self.0) };
+        if handle.is_null() {
+            Err(Error::NullPtr)
+        } else {
+            Ok(EventGroup (handle))
+        }
+    }
+}
+
+impl Default for StaticEventGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EventGroupFn for EventGroup {
     fn new() -> Result<Self> {
         let handle = unsafe { xEventGroupCreate() };
@@ -92,7 +117,7 @@ impl EventGroupFn for EventGroup {
 
     fn wait(&self, mask: EventBits, timeout_ticks: TickType) -> EventBits {
         unsafe {
-            crate::freertos::ffi::xEventGroupWaitBits(
+            xEventGroupWaitBits(
                 self.0,
                 mask,
                 pdFALSE, 
@@ -102,6 +127,34 @@ impl EventGroupFn for EventGroup {
         }
     }
 
+    fn wait_bits(&self, mask: EventBits, options: EventWaitOptions, timeout: impl ToTick) -> Result<EventBits> {
+        let bits = unsafe {
+            xEventGroupWaitBits(
+                self.0,
+                mask,
+                if options.clear_on_exit { pdTRUE } else { pdFALSE },
+                if options.wait_for_all { pdTRUE } else { pdFALSE },
+                timeout.to_ticks(),
+            )
+        };
+
+        // The kernel returns the bits on timeout too, the condition tells apart
+        if options.is_met(mask, bits) {
+            Ok(bits)
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+
+    fn sync(&self, set_bits: EventBits, wait_bits: EventBits, timeout: impl ToTick) -> Result<EventBits> {
+        let bits = unsafe { xEventGroupSync(self.0, set_bits, wait_bits, timeout.to_ticks()) };
+        if bits & wait_bits == wait_bits {
+            Ok(bits)
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+
     fn delete(&mut self) {
         unsafe {
             vEventGroupDelete(self.0);
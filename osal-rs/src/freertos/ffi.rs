@@ -23,7 +23,7 @@
 use core::ffi::{c_char, c_uint, c_void};
 use core::ptr;
 
-use super::types::{BaseType, StackType, UBaseType, TickType, EventBits};
+use super::types::{BaseType, StackType, UBaseType, TickType, EventBits, STATIC_TASK_SIZE, STATIC_QUEUE_SIZE, STATIC_EVENT_GROUP_SIZE, STATIC_TIMER_SIZE};
 
 pub type ThreadHandle = *const c_void;
 pub type QueueHandle = *const c_void;
@@ -31,7 +31,13 @@ pub type SemaphoreHandle = *const c_void;
 pub type EventGroupHandle = *const c_void;
 pub type TimerHandle = *const c_void;
 pub type MutexHandle = *const c_void;
+pub type QueueSetHandle = *const c_void;
+pub type QueueSetMemberHandle = *const c_void;
+pub type StreamBufferHandle = *const c_void;
+pub type MessageBufferHandle = StreamBufferHandle;
 pub type TimerCallback = unsafe extern "C" fn(timer: TimerHandle);
+pub type PendedFunction = unsafe extern "C" fn(parameter1: *mut c_void, parameter2: u32);
+pub type StreamBufferCallback = unsafe extern "C" fn(stream_buffer: StreamBufferHandle, is_inside_isr: BaseType, higher_priority_task_woken: *mut BaseType);
 pub type TaskState = c_uint;
 
 pub const RUNNING: TaskState = 0;
@@ -76,6 +82,10 @@ pub const queueQUEUE_TYPE_RECURSIVE_MUTEX: u8 = 4;
 
 pub const queueQUEUE_TYPE_SET: u8 = 5;
 
+pub const sbTYPE_STREAM_BUFFER: BaseType = 0;
+
+pub const sbTYPE_MESSAGE_BUFFER: BaseType = 1;
+
 
 
 #[repr(C)]
@@ -108,8 +118,28 @@ impl Default for TaskStatus {
     }
 }
 
+/// Opaque storage for `StaticTask_t`, sized by the build script
+#[repr(C, align(8))]
+pub struct StaticTaskBuffer(pub [u8; STATIC_TASK_SIZE]);
+
+/// Opaque storage for `StaticQueue_t`, also used by `StaticSemaphore_t`
+#[repr(C, align(8))]
+pub struct StaticQueueBuffer(pub [u8; STATIC_QUEUE_SIZE]);
+
+/// Opaque storage for `StaticEventGroup_t`
+#[repr(C, align(8))]
+pub struct StaticEventGroupBuffer(pub [u8; STATIC_EVENT_GROUP_SIZE]);
+
+/// Opaque storage for `StaticTimer_t`
+#[repr(C, align(8))]
+pub struct StaticTimerBuffer(pub [u8; STATIC_TIMER_SIZE]);
+
 pub type TaskFunction = Option<unsafe extern "C" fn(arg: *mut c_void)>;
 
+#[cfg(feature = "mock")]
+pub use super::mock::*;
+
+#[cfg(not(feature = "mock"))]
 unsafe extern "C" {
     
 
@@ -167,6 +197,16 @@ unsafe extern "C" {
         pxCreatedTask: *mut ThreadHandle,
     ) -> BaseType;
 
+    pub fn xTaskCreateStatic(
+        pxTaskCode: TaskFunction,
+        pcName: *const c_char,
+        uxStackDepth: StackType,
+        pvParameters: *mut c_void,
+        uxPriority: UBaseType,
+        puxStackBuffer: *mut StackType,
+        pxTaskBuffer: *mut StaticTaskBuffer,
+    ) -> ThreadHandle;
+
     pub fn vTaskDelete(xTaskToDelete: ThreadHandle);
 
     pub fn vTaskSuspend(xTaskToSuspend: ThreadHandle);
@@ -201,6 +241,14 @@ unsafe extern "C" {
     ) -> BaseType;
 
 
+    pub fn xTaskGenericNotifyStateClear(xTask: ThreadHandle, uxIndexToClear: UBaseType) -> BaseType;
+
+    pub fn vTaskGenericNotifyGiveFromISR(
+        xTaskToNotify: ThreadHandle,
+        uxIndexToNotify: UBaseType,
+        pxHigherPriorityTaskWoken: *mut BaseType,
+    );
+
     pub fn xTaskGenericNotifyFromISR(
         xTaskToNotify: ThreadHandle,
         uxIndexToNotify: UBaseType,
@@ -218,6 +266,13 @@ unsafe extern "C" {
         xTicksToWait: TickType,
     ) -> EventBits;
 
+    pub fn xEventGroupSync(
+        xEventGroup: EventGroupHandle,
+        uxBitsToSet: EventBits,
+        uxBitsToWaitFor: EventBits,
+        xTicksToWait: TickType,
+    ) -> EventBits;
+
     pub fn xEventGroupClearBits(
         xEventGroup: EventGroupHandle,
         uxBitsToClear: EventBits,
@@ -246,6 +301,8 @@ unsafe extern "C" {
 
     pub fn xEventGroupCreate() -> EventGroupHandle;
 
+    pub fn xEventGroupCreateStatic(pxEventGroupBuffer: *mut StaticEventGroupBuffer) -> EventGroupHandle;
+
     pub fn osal_rs_critical_section_enter();
 
     pub fn osal_rs_critical_section_exit();
@@ -261,6 +318,12 @@ unsafe extern "C" {
         uxInitialCount: UBaseType,
     ) -> QueueHandle;
 
+    pub fn xQueueCreateCountingSemaphoreStatic(
+        uxMaxCount: UBaseType,
+        uxInitialCount: UBaseType,
+        pxStaticQueue: *mut StaticQueueBuffer,
+    ) -> QueueHandle;
+
     pub fn xQueueSemaphoreTake(xQueue: QueueHandle, xTicksToWait: TickType) -> BaseType;
 
     pub fn xQueueReceiveFromISR(
@@ -289,12 +352,88 @@ unsafe extern "C" {
         ucQueueType: u8,
     ) -> QueueHandle;
 
+    pub fn xQueueGenericCreateStatic(
+        uxQueueLength: UBaseType,
+        uxItemSize: UBaseType,
+        pucQueueStorage: *mut u8,
+        pxStaticQueue: *mut StaticQueueBuffer,
+        ucQueueType: u8,
+    ) -> QueueHandle;
+
     pub fn xQueueReceive(
         xQueue: QueueHandle,
         pvBuffer: *mut c_void,
         xTicksToWait: TickType,
     ) -> BaseType;
 
+    pub fn xQueuePeek(
+        xQueue: QueueHandle,
+        pvBuffer: *mut c_void,
+        xTicksToWait: TickType,
+    ) -> BaseType;
+
+    pub fn xQueuePeekFromISR(xQueue: QueueHandle, pvBuffer: *mut c_void) -> BaseType;
+
+    pub fn xQueueGenericReset(xQueue: QueueHandle, xNewQueue: BaseType) -> BaseType;
+
+    pub fn xQueueCreateSet(uxEventQueueLength: UBaseType) -> QueueSetHandle;
+
+    pub fn xQueueAddToSet(xQueueOrSemaphore: QueueSetMemberHandle, xQueueSet: QueueSetHandle) -> BaseType;
+
+    pub fn xQueueRemoveFromSet(xQueueOrSemaphore: QueueSetMemberHandle, xQueueSet: QueueSetHandle) -> BaseType;
+
+    pub fn xQueueSelectFromSet(xQueueSet: QueueSetHandle, xTicksToWait: TickType) -> QueueSetMemberHandle;
+
+    pub fn xQueueSelectFromSetFromISR(xQueueSet: QueueSetHandle) -> QueueSetMemberHandle;
+
+    pub fn xStreamBufferGenericCreate(
+        xBufferSizeBytes: usize,
+        xTriggerLevelBytes: usize,
+        xStreamBufferType: BaseType,
+        pxSendCompletedCallback: Option<StreamBufferCallback>,
+        pxReceiveCompletedCallback: Option<StreamBufferCallback>,
+    ) -> StreamBufferHandle;
+
+    pub fn xStreamBufferSend(
+        xStreamBuffer: StreamBufferHandle,
+        pvTxData: *const c_void,
+        xDataLengthBytes: usize,
+        xTicksToWait: TickType,
+    ) -> usize;
+
+    pub fn xStreamBufferSendFromISR(
+        xStreamBuffer: StreamBufferHandle,
+        pvTxData: *const c_void,
+        xDataLengthBytes: usize,
+        pxHigherPriorityTaskWoken: *mut BaseType,
+    ) -> usize;
+
+    pub fn xStreamBufferReceive(
+        xStreamBuffer: StreamBufferHandle,
+        pvRxData: *mut c_void,
+        xBufferLengthBytes: usize,
+        xTicksToWait: TickType,
+    ) -> usize;
+
+    pub fn xStreamBufferReceiveFromISR(
+        xStreamBuffer: StreamBufferHandle,
+        pvRxData: *mut c_void,
+        xBufferLengthBytes: usize,
+        pxHigherPriorityTaskWoken: *mut BaseType,
+    ) -> usize;
+
+    pub fn xStreamBufferBytesAvailable(xStreamBuffer: StreamBufferHandle) -> usize;
+
+    pub fn xStreamBufferSpacesAvailable(xStreamBuffer: StreamBufferHandle) -> usize;
+
+    pub fn xStreamBufferNextMessageLengthBytes(xStreamBuffer: StreamBufferHandle) -> usize;
+
+    pub fn xStreamBufferSetTriggerLevel(xStreamBuffer: StreamBufferHandle, xTriggerLevel: usize) -> BaseType;
+
+    pub fn xStreamBufferReset(xStreamBuffer: StreamBufferHandle) -> BaseType;
+
+    pub fn vStreamBufferDelete(xStreamBuffer: StreamBufferHandle);
+
     pub fn xQueueGenericSendFromISR(
         xQueue: QueueHandle,
         pvItemToQueue: *const c_void,
@@ -318,6 +457,15 @@ unsafe extern "C" {
         pxCallbackFunction: Option<TimerCallback>,
     ) -> TimerHandle;
 
+    pub fn xTimerCreateStatic(
+        pcTimerName: *const c_char,
+        xTimerPeriodInTicks: TickType,
+        xAutoReload: BaseType,
+        pvTimerID: *mut c_void,
+        pxCallbackFunction: Option<TimerCallback>,
+        pxTimerBuffer: *mut StaticTimerBuffer,
+    ) -> TimerHandle;
+
     pub fn osal_rs_timer_start(xTimer: TimerHandle, xTicksToWait: TickType) -> BaseType;
 
     pub fn osal_rs_timer_stop(xTimer: TimerHandle, xTicksToWait: TickType) -> BaseType;
@@ -334,6 +482,13 @@ unsafe extern "C" {
 
     pub fn pvTimerGetTimerID(xTimer: TimerHandle) -> *mut c_void;
 
+    pub fn xTimerPendFunctionCall(
+        xFunctionToPend: Option<PendedFunction>,
+        pvParameter1: *mut c_void,
+        ulParameter2: u32,
+        xTicksToWait: TickType,
+    ) -> BaseType;
+
     pub fn printf(fmt: *const u8, ...) -> i32; 
 }
 
@@ -432,6 +587,19 @@ macro_rules! xEventGroupGetBits {
     };
 }
 
+#[macro_export]
+macro_rules! xSemaphoreCreateBinary {
+    () => {
+        unsafe {
+            $crate::freertos::ffi::xQueueGenericCreate(
+                $crate::freertos::ffi::semBINARY_SEMAPHORE_QUEUE_LENGTH as $crate::freertos::types::UBaseType,
+                $crate::freertos::ffi::semSEMAPHORE_QUEUE_ITEM_LENGTH as $crate::freertos::types::UBaseType,
+                $crate::freertos::ffi::queueQUEUE_TYPE_BINARY_SEMAPHORE
+            )
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! xSemaphoreCreateCounting {
     ($uxMaxCount:expr, $uxInitialCount:expr) => {
@@ -504,6 +672,36 @@ macro_rules! vSemaphoreDelete {
     };
 }
 
+#[macro_export]
+macro_rules! xStreamBufferCreate {
+    ($xBufferSizeBytes:expr, $xTriggerLevelBytes:expr) => {
+        unsafe {
+            $crate::freertos::ffi::xStreamBufferGenericCreate(
+                $xBufferSizeBytes,
+                $xTriggerLevelBytes,
+                $crate::freertos::ffi::sbTYPE_STREAM_BUFFER,
+                None,
+                None
+            )
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! xMessageBufferCreate {
+    ($xBufferSizeBytes:expr) => {
+        unsafe {
+            $crate::freertos::ffi::xStreamBufferGenericCreate(
+                $xBufferSizeBytes,
+                0,
+                $crate::freertos::ffi::sbTYPE_MESSAGE_BUFFER,
+                None,
+                None
+            )
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! xQueueCreate {
     ($uxQueueLength:expr, $uxItemSize:expr) => {
@@ -545,6 +743,85 @@ macro_rules! xQueueSendToBack {
     };
 }
 
+#[macro_export]
+macro_rules! xQueueSendToFront {
+    ($xQueue:expr, $pvItemToQueue:expr, $xTicksToWait:expr) => {
+        unsafe {
+            $crate::freertos::ffi::xQueueGenericSend(
+                $xQueue,
+                $pvItemToQueue,
+                $xTicksToWait,
+                $crate::freertos::ffi::queueSEND_TO_FRONT
+            )
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! xQueueSendToFrontFromISR {
+    ($xQueue:expr, $pvItemToQueue:expr, $pxHigherPriorityTaskWoken:expr) => {
+        unsafe {
+            $crate::freertos::ffi::xQueueGenericSendFromISR(
+                $xQueue,
+                $pvItemToQueue,
+                $pxHigherPriorityTaskWoken,
+                $crate::freertos::ffi::queueSEND_TO_FRONT
+            )
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! xQueueOverwrite {
+    ($xQueue:expr, $pvItemToQueue:expr) => {
+        unsafe {
+            $crate::freertos::ffi::xQueueGenericSend(
+                $xQueue,
+                $pvItemToQueue,
+                0,
+                $crate::freertos::ffi::queueOVERWRITE
+            )
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! xQueueOverwriteFromISR {
+    ($xQueue:expr, $pvItemToQueue:expr, $pxHigherPriorityTaskWoken:expr) => {
+        unsafe {
+            $crate::freertos::ffi::xQueueGenericSendFromISR(
+                $xQueue,
+                $pvItemToQueue,
+                $pxHigherPriorityTaskWoken,
+                $crate::freertos::ffi::queueOVERWRITE
+            )
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! xQueueReset {
+    ($xQueue:expr) => {
+        unsafe {
+            $crate::freertos::ffi::xQueueGenericReset(
+                $xQueue,
+                $crate::freertos::ffi::pdFALSE
+            )
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! xSemaphoreCreateMutex {
+    () => {
+        unsafe {
+            $crate::freertos::ffi::xQueueCreateMutex(
+                $crate::freertos::ffi::queueQUEUE_TYPE_MUTEX
+            )
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! xSemaphoreCreateRecursiveMutex {
     () => {
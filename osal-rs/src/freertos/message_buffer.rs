@@ -0,0 +1,265 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::ffi::c_void;
+use core::fmt::{Debug, Display};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::ptr::null_mut;
+
+use alloc::vec;
+
+use super::ffi::{MessageBufferHandle, pdFALSE, pdPASS, vStreamBufferDelete, xStreamBufferNextMessageLengthBytes, xStreamBufferReceive, xStreamBufferReceiveFromISR, xStreamBufferReset, xStreamBufferSend, xStreamBufferSendFromISR, xStreamBufferSpacesAvailable};
+use super::system::System;
+use super::types::BaseType;
+use crate::traits::{MessageBufferFn, MessageBufferStreamedFn, SystemFn, ToTick, ToBytes, FromBytes};
+use crate::utils::{Result, Error};
+use crate::xMessageBufferCreate;
+
+/// FreeRTOS message buffer: a stream buffer that keeps message boundaries
+pub struct MessageBuffer (MessageBufferHandle);
+
+unsafe impl Send for MessageBuffer {}
+unsafe impl Sync for MessageBuffer {}
+
+impl MessageBuffer {
+    /// A receive of 0 bytes is a timeout, unless the next message does
+    /// not fit in `buffer`
+    fn received(&self, received: usize, buffer: &[u8]) -> Result<usize> {
+        if received > 0 {
+            Ok(received)
+        } else if self.next_length() > buffer.len() {
+            Err(Error::Unhandled("Buffer too small for the next message"))
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+}
+
+impl MessageBufferFn for MessageBuffer {
+    fn new(size: usize) -> Result<Self> {
+        if size <= size_of::<usize>() {
+            return Err(Error::InvalidQueueSize);
+        }
+
+        let handle = xMessageBufferCreate!(size);
+        if handle.is_null() {
+            Err(Error::OutOfMemory)
+        } else {
+            Ok(Self (handle))
+        }
+    }
+
+    fn send(&self, message: &[u8], ticks_to_wait: impl ToTick) -> Result<()> {
+        let sent = unsafe {
+            xStreamBufferSend(
+                self.0,
+                message.as_ptr() as *const c_void,
+                message.len(),
+                ticks_to_wait.to_ticks(),
+            )
+        };
+
+        if sent == 0 && !message.is_empty() {
+            Err(Error::Timeout)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn send_from_isr(&self, message: &[u8]) -> Result<()> {
+        let mut higher_priority_task_woken: BaseType = pdFALSE;
+
+        let sent = unsafe {
+            xStreamBufferSendFromISR(
+                self.0,
+                message.as_ptr() as *const c_void,
+                message.len(),
+                &mut higher_priority_task_woken,
+            )
+        };
+
+        if sent == 0 && !message.is_empty() {
+            Err(Error::Timeout)
+        } else {
+            System::yield_from_isr(higher_priority_task_woken);
+            Ok(())
+        }
+    }
+
+    fn receive(&self, buffer: &mut [u8], ticks_to_wait: impl ToTick) -> Result<usize> {
+        let received = unsafe {
+            xStreamBufferReceive(
+                self.0,
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer.len(),
+                ticks_to_wait.to_ticks(),
+            )
+        };
+        self.received(received, buffer)
+    }
+
+    fn receive_from_isr(&self, buffer: &mut [u8]) -> Result<usize> {
+        let mut higher_priority_task_woken: BaseType = pdFALSE;
+
+        let received = unsafe {
+            xStreamBufferReceiveFromISR(
+                self.0,
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer.len(),
+                &mut higher_priority_task_woken,
+            )
+        };
+
+        System::yield_from_isr(higher_priority_task_woken);
+        self.received(received, buffer)
+    }
+
+    #[inline]
+    fn next_length(&self) -> usize {
+        unsafe { xStreamBufferNextMessageLengthBytes(self.0) }
+    }
+
+    #[inline]
+    fn spaces_available(&self) -> usize {
+        unsafe { xStreamBufferSpacesAvailable(self.0) }
+    }
+
+    fn reset(&self) -> Result<()> {
+        if unsafe { xStreamBufferReset(self.0) } != pdPASS {
+            Err(Error::Unhandled("Message buffer reset while a task is blocked on it"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn delete(&mut self) {
+        unsafe {
+            vStreamBufferDelete(self.0);
+            self.0 = null_mut();
+        }
+    }
+}
+
+impl Drop for MessageBuffer {
+    fn drop(&mut self) {
+        if self.0.is_null() {
+            return;
+        }
+        self.delete();
+    }
+}
+
+impl Deref for MessageBuffer {
+    type Target = MessageBufferHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for MessageBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MessageBuffer")
+            .field("handle", &self.0)
+            .finish()
+    }
+}
+
+impl Display for MessageBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "MessageBuffer {{ handle: {:?} }}", self.0)
+    }
+}
+
+/// Typed `MessageBuffer`, the size is kept to receive the longest message
+pub struct MessageBufferStreamed<T: ToBytes + FromBytes> (MessageBuffer, usize, PhantomData<T>);
+
+unsafe impl<T: ToBytes + FromBytes> Send for MessageBufferStreamed<T> {}
+unsafe impl<T: ToBytes + FromBytes> Sync for MessageBufferStreamed<T> {}
+
+impl<T> MessageBufferStreamedFn<T> for MessageBufferStreamed<T> 
+where 
+    T: ToBytes + FromBytes {
+
+    #[inline]
+    fn new(size: usize) -> Result<Self> {
+        Ok(Self (MessageBuffer::new(size)?, size, PhantomData))
+    }
+
+    #[inline]
+    fn send(&self, item: &T, ticks_to_wait: impl ToTick) -> Result<()> {
+        self.0.send(item.to_bytes(), ticks_to_wait)
+    }
+
+    #[inline]
+    fn send_from_isr(&self, item: &T) -> Result<()> {
+        self.0.send_from_isr(item.to_bytes())
+    }
+
+    fn receive(&self, ticks_to_wait: impl ToTick) -> Result<T> {
+        let mut bytes = vec![0u8; self.1];
+        let len = self.0.receive(&mut bytes, ticks_to_wait)?;
+        T::from_bytes(&bytes[..len])
+    }
+
+    fn receive_from_isr(&self) -> Result<T> {
+        let mut bytes = vec![0u8; self.1];
+        let len = self.0.receive_from_isr(&mut bytes)?;
+        T::from_bytes(&bytes[..len])
+    }
+
+    #[inline]
+    fn reset(&self) -> Result<()> {
+        self.0.reset()
+    }
+
+    #[inline]
+    fn delete(&mut self) {
+        self.0.delete()
+    }
+}
+
+impl<T> Deref for MessageBufferStreamed<T> 
+where 
+    T: ToBytes + FromBytes {
+    type Target = MessageBufferHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.0
+    }
+}
+
+impl<T> Debug for MessageBufferStreamed<T> 
+where 
+    T: ToBytes + FromBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MessageBufferStreamed")
+            .field("handle", &self.0.0)
+            .finish()
+    }
+}
+
+impl<T> Display for MessageBufferStreamed<T> 
+where 
+    T: ToBytes + FromBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "MessageBufferStreamed {{ handle: {:?} }}", self.0.0)
+    }
+}
@@ -0,0 +1,920 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+#![allow(non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+
+//! Recording stand-in for the FreeRTOS kernel, enabled by the `mock` feature.
+//!
+//! Every kernel function of `ffi` is replaced by a Rust function that records
+//! the call with its arguments and returns a scripted value, so that the
+//! wrappers can be unit-tested on the host with `cargo test`. Unless scripted
+//! with `Mock::set_return` or `Mock::push_return`, calls succeed:
+//!
+//! - creation functions hand out fresh fake handles, never dereferenced;
+//! - tasks and timers are remembered, `Mock::run_task` and `Mock::fire_timer`
+//!   run their entry point on the calling thread;
+//! - `vTaskDelay` and `xTaskDelayUntil` advance the tick count, nothing blocks;
+//! - `pvPortMalloc` and `vPortFree` use the host heap.
+//!
+//! The recorder is global: tests hold a `MockSession` to run one at a time.
+
+use core::ffi::{c_char, c_void};
+use core::ptr::null_mut;
+
+use std::alloc::{Layout, alloc, dealloc};
+use std::collections::{BTreeMap, VecDeque};
+use std::ffi::{CStr, CString};
+use std::string::String;
+use std::sync::{Mutex, MutexGuard};
+use std::vec::Vec;
+
+use super::ffi::{
+    DELETED, EventGroupHandle, INVALID, PendedFunction, QueueHandle, QueueSetHandle, QueueSetMemberHandle, READY, StreamBufferCallback, StreamBufferHandle, SUSPENDED, StaticEventGroupBuffer, StaticQueueBuffer, StaticTaskBuffer, StaticTimerBuffer, TaskFunction, TaskState, TaskStatus, ThreadHandle, TimerCallback, TimerHandle, pdFALSE, pdPASS, pdTRUE
+};
+use super::thread::ThreadState;
+use super::types::{BaseType, EventBits, StackType, TickType, UBaseType};
+
+/// First fake handle, handles are spaced so that they never look aligned to
+/// real objects of the host
+const HANDLE_BASE: usize = 0x1000;
+
+/// Argument of a recorded call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Arg {
+    /// Integer argument: ticks, sizes, priorities, flags
+    Int(i64),
+    /// Handle or pointer argument, as an address
+    Ptr(usize),
+    /// C string argument (task and timer names), copied at call time
+    Str(String),
+}
+
+/// A kernel call captured by the recorder
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Call {
+    pub name: &'static str,
+    pub args: Vec<Arg>,
+}
+
+#[derive(Default)]
+struct Script {
+    /// Values returned by the next calls, oldest first
+    once: VecDeque<i64>,
+    /// Value returned once `once` is exhausted
+    always: Option<i64>,
+}
+
+struct Task {
+    entry: TaskFunction,
+    param: usize,
+    name: CString,
+    priority: UBaseType,
+    number: UBaseType,
+    state: TaskState,
+}
+
+struct MockTimer {
+    callback: Option<TimerCallback>,
+    id: usize,
+}
+
+struct Recorder {
+    calls: Vec<Call>,
+    scripts: BTreeMap<&'static str, Script>,
+    next_handle: usize,
+    tasks: BTreeMap<usize, Task>,
+    timers: BTreeMap<usize, MockTimer>,
+    tick_count: TickType,
+    /// Value written back by `xTaskGenericNotifyWait`
+    notification_value: u32,
+    /// Task whose entry point is being run by `Mock::run_task`
+    current: usize,
+}
+
+impl Recorder {
+    const fn new() -> Self {
+        Self {
+            calls: Vec::new(),
+            scripts: BTreeMap::new(),
+            next_handle: HANDLE_BASE,
+            tasks: BTreeMap::new(),
+            timers: BTreeMap::new(),
+            tick_count: 0,
+            notification_value: 0,
+            current: 0,
+        }
+    }
+
+    /// Records a call and returns its scripted value, if any
+    fn record(&mut self, name: &'static str, args: Vec<Arg>) -> Option<i64> {
+        self.calls.push(Call { name, args });
+        let script = self.scripts.get_mut(name)?;
+        script.once.pop_front().or(script.always)
+    }
+
+    fn new_handle(&mut self) -> usize {
+        self.next_handle += 0x10;
+        self.next_handle
+    }
+}
+
+static RECORDER: Mutex<Recorder> = Mutex::new(Recorder::new());
+
+static SESSION: Mutex<()> = Mutex::new(());
+
+/// The recorder stays usable after a failed assertion in another test
+fn recorder() -> MutexGuard<'static, Recorder> {
+    RECORDER.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn record(name: &'static str, args: Vec<Arg>) -> Option<i64> {
+    recorder().record(name, args)
+}
+
+/// Base types are 64 bits wide on some ports, keep their bit pattern
+fn int(value: impl Into<i128>) -> Arg {
+    Arg::Int(value.into() as i64)
+}
+
+fn ptr<T>(value: *const T) -> Arg {
+    Arg::Ptr(value as usize)
+}
+
+fn string(value: *const c_char) -> Arg {
+    if value.is_null() {
+        Arg::Ptr(0)
+    } else {
+        Arg::Str(unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned())
+    }
+}
+
+/// Records a call and returns its scripted value or `default`
+fn reply(name: &'static str, args: Vec<Arg>, default: BaseType) -> BaseType {
+    record(name, args).map_or(default, |value| value as BaseType)
+}
+
+/// Records a call to a creation function and returns its scripted handle or a
+/// fresh one, 0 stands for an allocation failure
+fn create(name: &'static str, args: Vec<Arg>) -> *const c_void {
+    let mut recorder = recorder();
+    match recorder.record(name, args) {
+        Some(handle) => handle as usize as *const c_void,
+        None => recorder.new_handle() as *const c_void,
+    }
+}
+
+/// Exclusive use of the recorder, reset when the session starts
+pub struct MockSession {
+    _guard: MutexGuard<'static, ()>,
+}
+
+/// Entry point of the recording kernel
+pub struct Mock;
+
+impl Mock {
+    /// Waits for the other sessions to end, then clears every recorded call,
+    /// script, task and timer
+    pub fn session() -> MockSession {
+        let guard = SESSION.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        Self::reset();
+        MockSession { _guard: guard }
+    }
+
+    pub fn reset() {
+        *recorder() = Recorder::new();
+    }
+
+    /// Every call recorded since the last reset, oldest first
+    pub fn calls() -> Vec<Call> {
+        recorder().calls.clone()
+    }
+
+    /// Calls to the kernel function `name`, oldest first
+    pub fn calls_to(name: &str) -> Vec<Call> {
+        recorder().calls.iter().filter(|call| call.name == name).cloned().collect()
+    }
+
+    pub fn count(name: &str) -> usize {
+        recorder().calls.iter().filter(|call| call.name == name).count()
+    }
+
+    pub fn last(name: &str) -> Option<Call> {
+        recorder().calls.iter().rev().find(|call| call.name == name).cloned()
+    }
+
+    /// Makes every following call to `name` return `value` (handles are given
+    /// as addresses: 0 is a null handle)
+    pub fn set_return(name: &'static str, value: i64) {
+        recorder().scripts.entry(name).or_default().always = Some(value);
+    }
+
+    /// Makes the next call to `name` return `value`, before any value given to
+    /// `set_return`. Values pushed for the same function are used in order.
+    pub fn push_return(name: &'static str, value: i64) {
+        recorder().scripts.entry(name).or_default().once.push_back(value);
+    }
+
+    /// Tick count seen by the wrappers, advanced by the delay functions
+    pub fn set_tick_count(ticks: TickType) {
+        recorder().tick_count = ticks;
+    }
+
+    /// Notification value received by the next `xTaskGenericNotifyWait` calls,
+    /// and previous value reported by `xTaskGenericNotify`
+    pub fn set_notification_value(value: u32) {
+        recorder().notification_value = value;
+    }
+
+    /// Runs the entry point given to `xTaskCreate` for `handle` on the calling
+    /// thread, as if the scheduler had started the task. Returns `false` if
+    /// the task is unknown or already deleted.
+    pub fn run_task(handle: ThreadHandle) -> bool {
+        let (entry, param) = {
+            let mut recorder = recorder();
+            let Some(task) = recorder.tasks.get(&(handle as usize)) else {
+                return false;
+            };
+            if task.state == DELETED {
+                return false;
+            }
+            let entry = (task.entry, task.param);
+            recorder.current = handle as usize;
+            entry
+        };
+
+        if let Some(entry) = entry {
+            unsafe { entry(param as *mut c_void) };
+        }
+        recorder().current = 0;
+        true
+    }
+
+    /// Runs the callback given to `xTimerCreate` for `handle`, as the timer
+    /// service task does on expiry. Returns `false` if the timer is unknown.
+    pub fn fire_timer(handle: TimerHandle) -> bool {
+        let key = handle as usize;
+        let callback = match recorder().timers.get(&key) {
+            Some(timer) => timer.callback,
+            None => return false,
+        };
+
+        if let Some(callback) = callback {
+            unsafe { callback(key as TimerHandle) };
+        }
+        true
+    }
+
+    /// State of a task created through `xTaskCreate`
+    pub fn task_state(handle: ThreadHandle) -> ThreadState {
+        match recorder().tasks.get(&(handle as usize)).map_or(INVALID, |task| task.state) {
+            READY => ThreadState::Ready,
+            SUSPENDED => ThreadState::Suspended,
+            DELETED => ThreadState::Deleted,
+            _ => ThreadState::Invalid,
+        }
+    }
+}
+
+/// Size stored in front of every `pvPortMalloc` block, so that `vPortFree`
+/// can rebuild its layout
+const HEADER: usize = 16;
+
+pub unsafe fn pvPortMalloc(size: usize) -> *mut c_void {
+    if let Some(address) = record("pvPortMalloc", vec![int(size as i64)]) {
+        return address as usize as *mut c_void;
+    }
+    let Ok(layout) = Layout::from_size_align(size + HEADER, HEADER) else {
+        return null_mut();
+    };
+    unsafe {
+        let block = alloc(layout);
+        if block.is_null() {
+            return null_mut();
+        }
+        *(block as *mut usize) = size;
+        block.add(HEADER) as *mut c_void
+    }
+}
+
+pub unsafe fn vPortFree(pv: *mut c_void) {
+    record("vPortFree", vec![ptr(pv)]);
+    if pv.is_null() {
+        return;
+    }
+    unsafe {
+        let block = (pv as *mut u8).sub(HEADER);
+        let size = *(block as *const usize);
+        dealloc(block, Layout::from_size_align_unchecked(size + HEADER, HEADER));
+    }
+}
+
+pub unsafe fn vTaskDelay(xTicksToDelay: TickType) {
+    let mut recorder = recorder();
+    recorder.record("vTaskDelay", vec![int(xTicksToDelay)]);
+    recorder.tick_count = recorder.tick_count.wrapping_add(xTicksToDelay);
+}
+
+pub unsafe fn xTaskDelayUntil(pxPreviousWakeTime: *mut TickType, xTimeIncrement: TickType) -> BaseType {
+    let mut recorder = recorder();
+    let previous = unsafe { *pxPreviousWakeTime };
+    let scripted = recorder.record("xTaskDelayUntil", vec![int(previous), int(xTimeIncrement)]);
+
+    let wake_time = previous.wrapping_add(xTimeIncrement);
+    unsafe { *pxPreviousWakeTime = wake_time };
+    let remaining = wake_time.wrapping_sub(recorder.tick_count);
+    let delayed = remaining != 0 && remaining <= xTimeIncrement;
+    if delayed {
+        recorder.tick_count = wake_time;
+    }
+    scripted.map_or(if delayed { pdTRUE } else { pdFALSE }, |value| value as BaseType)
+}
+
+pub unsafe fn xTaskGetTickCount() -> TickType {
+    let mut recorder = recorder();
+    let ticks = recorder.tick_count;
+    recorder.record("xTaskGetTickCount", vec![]).map_or(ticks, |value| value as TickType)
+}
+
+pub unsafe fn vTaskStartScheduler() {
+    record("vTaskStartScheduler", vec![]);
+}
+
+pub unsafe fn vTaskEndScheduler() {
+    record("vTaskEndScheduler", vec![]);
+}
+
+pub unsafe fn vTaskSuspendAll() {
+    record("vTaskSuspendAll", vec![]);
+}
+
+pub unsafe fn xTaskResumeAll() -> BaseType {
+    reply("xTaskResumeAll", vec![], pdFALSE)
+}
+
+pub unsafe fn xTaskGetCurrentTaskHandle() -> ThreadHandle {
+    let mut recorder = recorder();
+    let current = recorder.current;
+    recorder.record("xTaskGetCurrentTaskHandle", vec![]).map_or(current, |value| value as usize) as ThreadHandle
+}
+
+pub unsafe fn eTaskGetState(xTask: ThreadHandle) -> TaskState {
+    let mut recorder = recorder();
+    let state = recorder.tasks.get(&(xTask as usize)).map_or(INVALID, |task| task.state);
+    recorder.record("eTaskGetState", vec![ptr(xTask)]).map_or(state, |value| value as TaskState)
+}
+
+pub unsafe fn uxTaskGetNumberOfTasks() -> UBaseType {
+    let mut recorder = recorder();
+    let count = recorder.tasks.values().filter(|task| task.state != DELETED).count() as UBaseType;
+    recorder.record("uxTaskGetNumberOfTasks", vec![]).map_or(count, |value| value as UBaseType)
+}
+
+fn task_status(handle: usize, task: &Task) -> TaskStatus {
+    TaskStatus {
+        xHandle: handle as ThreadHandle,
+        pcTaskName: task.name.as_ptr(),
+        xTaskNumber: task.number,
+        eCurrentState: task.state,
+        uxCurrentPriority: task.priority,
+        uxBasePriority: task.priority,
+        ..TaskStatus::default()
+    }
+}
+
+pub unsafe fn uxTaskGetSystemState(pxTaskStatusArray: *mut TaskStatus, uxArraySize: UBaseType, pulTotalRunTime: *mut u32) -> UBaseType {
+    let mut recorder = recorder();
+    let scripted = recorder.record("uxTaskGetSystemState", vec![ptr(pxTaskStatusArray), int(uxArraySize), ptr(pulTotalRunTime)]);
+
+    let mut filled: UBaseType = 0;
+    for (handle, task) in recorder.tasks.iter().filter(|(_, task)| task.state != DELETED) {
+        if filled >= uxArraySize {
+            break;
+        }
+        unsafe { *pxTaskStatusArray.add(filled as usize) = task_status(*handle, task) };
+        filled += 1;
+    }
+    if !pulTotalRunTime.is_null() {
+        unsafe { *pulTotalRunTime = 0 };
+    }
+    scripted.map_or(filled, |value| value as UBaseType)
+}
+
+pub unsafe fn xTaskCreate(
+    pxTaskCode: TaskFunction,
+    pcName: *const c_char,
+    uxStackDepth: StackType,
+    pvParameters: *mut c_void,
+    uxPriority: UBaseType,
+    pxCreatedTask: *mut ThreadHandle,
+) -> BaseType {
+    let mut recorder = recorder();
+    let args = vec![
+        Arg::Ptr(pxTaskCode.map_or(0, |code| code as usize)),
+        string(pcName),
+        int(uxStackDepth),
+        ptr(pvParameters),
+        int(uxPriority),
+        ptr(pxCreatedTask),
+    ];
+    if let Some(value) = recorder.record("xTaskCreate", args) {
+        if value as BaseType != pdPASS {
+            return value as BaseType;
+        }
+    }
+
+    let handle = recorder.new_handle();
+    add_task(&mut recorder, handle, pxTaskCode, pcName, pvParameters, uxPriority);
+    if !pxCreatedTask.is_null() {
+        unsafe { *pxCreatedTask = handle as ThreadHandle };
+    }
+    pdPASS
+}
+
+pub unsafe fn xTaskCreateStatic(
+    pxTaskCode: TaskFunction,
+    pcName: *const c_char,
+    uxStackDepth: StackType,
+    pvParameters: *mut c_void,
+    uxPriority: UBaseType,
+    puxStackBuffer: *mut StackType,
+    pxTaskBuffer: *mut StaticTaskBuffer,
+) -> ThreadHandle {
+    let mut recorder = recorder();
+    let args = vec![
+        Arg::Ptr(pxTaskCode.map_or(0, |code| code as usize)),
+        string(pcName),
+        int(uxStackDepth),
+        ptr(pvParameters),
+        int(uxPriority),
+        ptr(puxStackBuffer),
+        ptr(pxTaskBuffer),
+    ];
+    let handle = match recorder.record("xTaskCreateStatic", args) {
+        Some(handle) => handle as usize,
+        None => recorder.new_handle(),
+    };
+    if handle != 0 {
+        add_task(&mut recorder, handle, pxTaskCode, pcName, pvParameters, uxPriority);
+    }
+    handle as ThreadHandle
+}
+
+/// Remembers a created task, so that `Mock::run_task` can run it
+fn add_task(recorder: &mut Recorder, handle: usize, entry: TaskFunction, name: *const c_char, param: *mut c_void, priority: UBaseType) {
+    let number = recorder.tasks.len() as UBaseType + 1;
+    let name = if name.is_null() { CString::default() } else { unsafe { CStr::from_ptr(name) }.into() };
+    recorder.tasks.insert(handle, Task {
+        entry,
+        param: param as usize,
+        name,
+        priority,
+        number,
+        state: READY,
+    });
+}
+
+/// Applies `state` to `handle`, or to the running task when null
+fn set_task_state(recorder: &mut Recorder, handle: ThreadHandle, state: TaskState) {
+    let handle = if handle.is_null() { recorder.current } else { handle as usize };
+    if let Some(task) = recorder.tasks.get_mut(&handle) {
+        task.state = state;
+    }
+}
+
+pub unsafe fn vTaskDelete(xTaskToDelete: ThreadHandle) {
+    let mut recorder = recorder();
+    recorder.record("vTaskDelete", vec![ptr(xTaskToDelete)]);
+    set_task_state(&mut recorder, xTaskToDelete, DELETED);
+}
+
+pub unsafe fn vTaskSuspend(xTaskToSuspend: ThreadHandle) {
+    let mut recorder = recorder();
+    recorder.record("vTaskSuspend", vec![ptr(xTaskToSuspend)]);
+    set_task_state(&mut recorder, xTaskToSuspend, SUSPENDED);
+}
+
+pub unsafe fn vTaskResume(xTaskToResume: ThreadHandle) {
+    let mut recorder = recorder();
+    recorder.record("vTaskResume", vec![ptr(xTaskToResume)]);
+    set_task_state(&mut recorder, xTaskToResume, READY);
+}
+
+/// Empty name for the tasks the recorder does not know
+static NO_NAME: &CStr = c"";
+
+pub unsafe fn vTaskGetInfo(xTask: ThreadHandle, pxTaskStatus: *mut TaskStatus, xGetFreeStackSpace: BaseType, eState: TaskState) {
+    let mut recorder = recorder();
+    recorder.record("vTaskGetInfo", vec![ptr(xTask), ptr(pxTaskStatus), int(xGetFreeStackSpace), int(eState)]);
+
+    let handle = if xTask.is_null() { recorder.current } else { xTask as usize };
+    let status = match recorder.tasks.get(&handle) {
+        Some(task) => task_status(handle, task),
+        None => TaskStatus { pcTaskName: NO_NAME.as_ptr(), ..TaskStatus::default() },
+    };
+    unsafe { *pxTaskStatus = status };
+}
+
+pub unsafe fn ulTaskGenericNotifyTake(uxIndexToWaitOn: UBaseType, xClearCountOnExit: BaseType, xTicksToWait: TickType) -> u32 {
+    record("ulTaskGenericNotifyTake", vec![int(uxIndexToWaitOn), int(xClearCountOnExit), int(xTicksToWait)])
+        .map_or(0, |value| value as u32)
+}
+
+/// Writes back the value given to `Mock::set_notification_value`
+pub unsafe fn xTaskGenericNotifyWait(
+    uxIndexToWaitOn: UBaseType,
+    ulBitsToClearOnEntry: u32,
+    ulBitsToClearOnExit: u32,
+    pulNotificationValue: *mut u32,
+    xTicksToWait: TickType,
+) -> BaseType {
+    let mut recorder = recorder();
+    let ret = recorder.record("xTaskGenericNotifyWait", vec![
+        int(uxIndexToWaitOn),
+        int(ulBitsToClearOnEntry),
+        int(ulBitsToClearOnExit),
+        ptr(pulNotificationValue),
+        int(xTicksToWait),
+    ]).map_or(pdTRUE, |value| value as BaseType);
+
+    if !pulNotificationValue.is_null() {
+        unsafe { *pulNotificationValue = recorder.notification_value };
+    }
+    ret
+}
+
+pub unsafe fn xTaskGenericNotify(
+    xTaskToNotify: ThreadHandle,
+    uxIndexToNotify: UBaseType,
+    ulValue: u32,
+    eAction: u32,
+    pulPreviousNotificationValue: *mut u32,
+) -> BaseType {
+    let mut recorder = recorder();
+    let ret = recorder.record("xTaskGenericNotify", vec![
+        ptr(xTaskToNotify),
+        int(uxIndexToNotify),
+        int(ulValue),
+        int(eAction),
+        ptr(pulPreviousNotificationValue),
+    ]).map_or(pdPASS, |value| value as BaseType);
+
+    // The value given to `Mock::set_notification_value` is the previous one
+    if !pulPreviousNotificationValue.is_null() {
+        unsafe { *pulPreviousNotificationValue = recorder.notification_value };
+    }
+    ret
+}
+
+pub unsafe fn xTaskGenericNotifyStateClear(xTask: ThreadHandle, uxIndexToClear: UBaseType) -> BaseType {
+    reply("xTaskGenericNotifyStateClear", vec![ptr(xTask), int(uxIndexToClear)], pdFALSE)
+}
+
+pub unsafe fn vTaskGenericNotifyGiveFromISR(xTaskToNotify: ThreadHandle, uxIndexToNotify: UBaseType, pxHigherPriorityTaskWoken: *mut BaseType) {
+    record("vTaskGenericNotifyGiveFromISR", vec![ptr(xTaskToNotify), int(uxIndexToNotify), ptr(pxHigherPriorityTaskWoken)]);
+}
+
+pub unsafe fn xTaskGenericNotifyFromISR(
+    xTaskToNotify: ThreadHandle,
+    uxIndexToNotify: UBaseType,
+    ulValue: u32,
+    eAction: u32,
+    pulPreviousNotificationValue: *mut u32,
+    pxHigherPriorityTaskWoken: *mut BaseType,
+) -> BaseType {
+    reply("xTaskGenericNotifyFromISR", vec![
+        ptr(xTaskToNotify),
+        int(uxIndexToNotify),
+        int(ulValue),
+        int(eAction),
+        ptr(pulPreviousNotificationValue),
+        ptr(pxHigherPriorityTaskWoken),
+    ], pdPASS)
+}
+
+pub unsafe fn xEventGroupWaitBits(
+    xEventGroup: EventGroupHandle,
+    uxBitsToWaitFor: EventBits,
+    xClearOnExit: BaseType,
+    xWaitForAllBits: BaseType,
+    xTicksToWait: TickType,
+) -> EventBits {
+    record("xEventGroupWaitBits", vec![ptr(xEventGroup), int(uxBitsToWaitFor), int(xClearOnExit), int(xWaitForAllBits), int(xTicksToWait)])
+        .map_or(0, |value| value as EventBits)
+}
+
+pub unsafe fn xEventGroupSync(xEventGroup: EventGroupHandle, uxBitsToSet: EventBits, uxBitsToWaitFor: EventBits, xTicksToWait: TickType) -> EventBits {
+    record("xEventGroupSync", vec![ptr(xEventGroup), int(uxBitsToSet), int(uxBitsToWaitFor), int(xTicksToWait)])
+        .map_or(0, |value| value as EventBits)
+}
+
+pub unsafe fn xEventGroupClearBits(xEventGroup: EventGroupHandle, uxBitsToClear: EventBits) -> EventBits {
+    record("xEventGroupClearBits", vec![ptr(xEventGroup), int(uxBitsToClear)]).map_or(0, |value| value as EventBits)
+}
+
+pub unsafe fn xEventGroupClearBitsFromISR(xEventGroup: EventGroupHandle, uxBitsToClear: EventBits) -> BaseType {
+    reply("xEventGroupClearBitsFromISR", vec![ptr(xEventGroup), int(uxBitsToClear)], pdPASS)
+}
+
+pub unsafe fn xEventGroupSetBits(xEventGroup: EventGroupHandle, uxBitsToSet: EventBits) -> EventBits {
+    record("xEventGroupSetBits", vec![ptr(xEventGroup), int(uxBitsToSet)]).map_or(uxBitsToSet, |value| value as EventBits)
+}
+
+pub unsafe fn xEventGroupSetBitsFromISR(xEventGroup: EventGroupHandle, uxBitsToSet: EventBits, pxHigherPriorityTaskWoken: *mut BaseType) -> BaseType {
+    reply("xEventGroupSetBitsFromISR", vec![ptr(xEventGroup), int(uxBitsToSet), ptr(pxHigherPriorityTaskWoken)], pdPASS)
+}
+
+pub unsafe fn xEventGroupGetBitsFromISR(xEventGroup: EventGroupHandle) -> EventBits {
+    record("xEventGroupGetBitsFromISR", vec![ptr(xEventGroup)]).map_or(0, |value| value as EventBits)
+}
+
+pub unsafe fn vEventGroupDelete(xEventGroup: EventGroupHandle) {
+    record("vEventGroupDelete", vec![ptr(xEventGroup)]);
+}
+
+pub unsafe fn xEventGroupCreate() -> EventGroupHandle {
+    create("xEventGroupCreate", vec![])
+}
+
+pub unsafe fn xEventGroupCreateStatic(pxEventGroupBuffer: *mut StaticEventGroupBuffer) -> EventGroupHandle {
+    create("xEventGroupCreateStatic", vec![ptr(pxEventGroupBuffer)])
+}
+
+pub unsafe fn osal_rs_critical_section_enter() {
+    record("osal_rs_critical_section_enter", vec![]);
+}
+
+pub unsafe fn osal_rs_critical_section_exit() {
+    record("osal_rs_critical_section_exit", vec![]);
+}
+
+pub unsafe fn osal_rs_port_yield_from_isr(pxHigherPriorityTaskWoken: BaseType) {
+    record("osal_rs_port_yield_from_isr", vec![int(pxHigherPriorityTaskWoken)]);
+}
+
+pub unsafe fn osal_rs_port_end_switching_isr(xSwitchRequired: BaseType) {
+    record("osal_rs_port_end_switching_isr", vec![int(xSwitchRequired)]);
+}
+
+pub unsafe fn xQueueCreateMutex(ucQueueType: u8) -> QueueHandle {
+    create("xQueueCreateMutex", vec![int(ucQueueType)])
+}
+
+pub unsafe fn xQueueCreateCountingSemaphore(uxMaxCount: UBaseType, uxInitialCount: UBaseType) -> QueueHandle {
+    create("xQueueCreateCountingSemaphore", vec![int(uxMaxCount), int(uxInitialCount)])
+}
+
+pub unsafe fn xQueueCreateCountingSemaphoreStatic(uxMaxCount: UBaseType, uxInitialCount: UBaseType, pxStaticQueue: *mut StaticQueueBuffer) -> QueueHandle {
+    create("xQueueCreateCountingSemaphoreStatic", vec![int(uxMaxCount), int(uxInitialCount), ptr(pxStaticQueue)])
+}
+
+pub unsafe fn xQueueSemaphoreTake(xQueue: QueueHandle, xTicksToWait: TickType) -> BaseType {
+    reply("xQueueSemaphoreTake", vec![ptr(xQueue), int(xTicksToWait)], pdTRUE)
+}
+
+pub unsafe fn xQueueReceiveFromISR(xQueue: QueueHandle, pvBuffer: *mut c_void, pxHigherPriorityTaskWoken: *mut BaseType) -> BaseType {
+    reply("xQueueReceiveFromISR", vec![ptr(xQueue), ptr(pvBuffer), ptr(pxHigherPriorityTaskWoken)], pdTRUE)
+}
+
+pub unsafe fn xQueueGenericSend(xQueue: QueueHandle, pvItemToQueue: *const c_void, xTicksToWait: TickType, xCopyPosition: BaseType) -> BaseType {
+    reply("xQueueGenericSend", vec![ptr(xQueue), ptr(pvItemToQueue), int(xTicksToWait), int(xCopyPosition)], pdTRUE)
+}
+
+pub unsafe fn xQueueGiveFromISR(xQueue: QueueHandle, pxHigherPriorityTaskWoken: *mut BaseType) -> BaseType {
+    reply("xQueueGiveFromISR", vec![ptr(xQueue), ptr(pxHigherPriorityTaskWoken)], pdTRUE)
+}
+
+pub unsafe fn vQueueDelete(xQueue: QueueHandle) {
+    record("vQueueDelete", vec![ptr(xQueue)]);
+}
+
+pub unsafe fn xQueueGenericCreate(uxQueueLength: UBaseType, uxItemSize: UBaseType, ucQueueType: u8) -> QueueHandle {
+    create("xQueueGenericCreate", vec![int(uxQueueLength), int(uxItemSize), int(ucQueueType)])
+}
+
+pub unsafe fn xQueueGenericCreateStatic(
+    uxQueueLength: UBaseType,
+    uxItemSize: UBaseType,
+    pucQueueStorage: *mut u8,
+    pxStaticQueue: *mut StaticQueueBuffer,
+    ucQueueType: u8,
+) -> QueueHandle {
+    create("xQueueGenericCreateStatic", vec![int(uxQueueLength), int(uxItemSize), ptr(pucQueueStorage), ptr(pxStaticQueue), int(ucQueueType)])
+}
+
+pub unsafe fn xQueueReceive(xQueue: QueueHandle, pvBuffer: *mut c_void, xTicksToWait: TickType) -> BaseType {
+    reply("xQueueReceive", vec![ptr(xQueue), ptr(pvBuffer), int(xTicksToWait)], pdTRUE)
+}
+
+pub unsafe fn xQueuePeek(xQueue: QueueHandle, pvBuffer: *mut c_void, xTicksToWait: TickType) -> BaseType {
+    reply("xQueuePeek", vec![ptr(xQueue), ptr(pvBuffer), int(xTicksToWait)], pdTRUE)
+}
+
+pub unsafe fn xQueuePeekFromISR(xQueue: QueueHandle, pvBuffer: *mut c_void) -> BaseType {
+    reply("xQueuePeekFromISR", vec![ptr(xQueue), ptr(pvBuffer)], pdTRUE)
+}
+
+pub unsafe fn xQueueGenericReset(xQueue: QueueHandle, xNewQueue: BaseType) -> BaseType {
+    reply("xQueueGenericReset", vec![ptr(xQueue), int(xNewQueue)], pdPASS)
+}
+
+pub unsafe fn xQueueCreateSet(uxEventQueueLength: UBaseType) -> QueueSetHandle {
+    create("xQueueCreateSet", vec![int(uxEventQueueLength)])
+}
+
+pub unsafe fn xQueueAddToSet(xQueueOrSemaphore: QueueSetMemberHandle, xQueueSet: QueueSetHandle) -> BaseType {
+    reply("xQueueAddToSet", vec![ptr(xQueueOrSemaphore), ptr(xQueueSet)], pdPASS)
+}
+
+pub unsafe fn xQueueRemoveFromSet(xQueueOrSemaphore: QueueSetMemberHandle, xQueueSet: QueueSetHandle) -> BaseType {
+    reply("xQueueRemoveFromSet", vec![ptr(xQueueOrSemaphore), ptr(xQueueSet)], pdPASS)
+}
+
+/// Returns the scripted member, none (a timeout) by default
+pub unsafe fn xQueueSelectFromSet(xQueueSet: QueueSetHandle, xTicksToWait: TickType) -> QueueSetMemberHandle {
+    record("xQueueSelectFromSet", vec![ptr(xQueueSet), int(xTicksToWait)]).map_or(0, |value| value as usize) as QueueSetMemberHandle
+}
+
+pub unsafe fn xQueueSelectFromSetFromISR(xQueueSet: QueueSetHandle) -> QueueSetMemberHandle {
+    record("xQueueSelectFromSetFromISR", vec![ptr(xQueueSet)]).map_or(0, |value| value as usize) as QueueSetMemberHandle
+}
+
+pub unsafe fn xStreamBufferGenericCreate(
+    xBufferSizeBytes: usize,
+    xTriggerLevelBytes: usize,
+    xStreamBufferType: BaseType,
+    pxSendCompletedCallback: Option<StreamBufferCallback>,
+    pxReceiveCompletedCallback: Option<StreamBufferCallback>,
+) -> StreamBufferHandle {
+    let send_completed = pxSendCompletedCallback.map_or(0, |callback| callback as usize);
+    let receive_completed = pxReceiveCompletedCallback.map_or(0, |callback| callback as usize);
+    create("xStreamBufferGenericCreate", vec![int(xBufferSizeBytes as u64), int(xTriggerLevelBytes as u64), int(xStreamBufferType), Arg::Ptr(send_completed), Arg::Ptr(receive_completed)])
+}
+
+/// Writes the whole of the data unless scripted
+pub unsafe fn xStreamBufferSend(xStreamBuffer: StreamBufferHandle, pvTxData: *const c_void, xDataLengthBytes: usize, xTicksToWait: TickType) -> usize {
+    record("xStreamBufferSend", vec![ptr(xStreamBuffer), ptr(pvTxData), int(xDataLengthBytes as u64), int(xTicksToWait)]).map_or(xDataLengthBytes, |value| value as usize)
+}
+
+pub unsafe fn xStreamBufferSendFromISR(xStreamBuffer: StreamBufferHandle, pvTxData: *const c_void, xDataLengthBytes: usize, pxHigherPriorityTaskWoken: *mut BaseType) -> usize {
+    record("xStreamBufferSendFromISR", vec![ptr(xStreamBuffer), ptr(pvTxData), int(xDataLengthBytes as u64), ptr(pxHigherPriorityTaskWoken)]).map_or(xDataLengthBytes, |value| value as usize)
+}
+
+/// Receives nothing (a timeout) unless scripted, the buffer is left untouched
+pub unsafe fn xStreamBufferReceive(xStreamBuffer: StreamBufferHandle, pvRxData: *mut c_void, xBufferLengthBytes: usize, xTicksToWait: TickType) -> usize {
+    record("xStreamBufferReceive", vec![ptr(xStreamBuffer), ptr(pvRxData), int(xBufferLengthBytes as u64), int(xTicksToWait)]).map_or(0, |value| value as usize)
+}
+
+pub unsafe fn xStreamBufferReceiveFromISR(xStreamBuffer: StreamBufferHandle, pvRxData: *mut c_void, xBufferLengthBytes: usize, pxHigherPriorityTaskWoken: *mut BaseType) -> usize {
+    record("xStreamBufferReceiveFromISR", vec![ptr(xStreamBuffer), ptr(pvRxData), int(xBufferLengthBytes as u64), ptr(pxHigherPriorityTaskWoken)]).map_or(0, |value| value as usize)
+}
+
+pub unsafe fn xStreamBufferBytesAvailable(xStreamBuffer: StreamBufferHandle) -> usize {
+    record("xStreamBufferBytesAvailable", vec![ptr(xStreamBuffer)]).map_or(0, |value| value as usize)
+}
+
+pub unsafe fn xStreamBufferSpacesAvailable(xStreamBuffer: StreamBufferHandle) -> usize {
+    record("xStreamBufferSpacesAvailable", vec![ptr(xStreamBuffer)]).map_or(0, |value| value as usize)
+}
+
+pub unsafe fn xStreamBufferNextMessageLengthBytes(xStreamBuffer: StreamBufferHandle) -> usize {
+    record("xStreamBufferNextMessageLengthBytes", vec![ptr(xStreamBuffer)]).map_or(0, |value| value as usize)
+}
+
+pub unsafe fn xStreamBufferSetTriggerLevel(xStreamBuffer: StreamBufferHandle, xTriggerLevel: usize) -> BaseType {
+    reply("xStreamBufferSetTriggerLevel", vec![ptr(xStreamBuffer), int(xTriggerLevel as u64)], pdPASS)
+}
+
+pub unsafe fn xStreamBufferReset(xStreamBuffer: StreamBufferHandle) -> BaseType {
+    reply("xStreamBufferReset", vec![ptr(xStreamBuffer)], pdPASS)
+}
+
+pub unsafe fn vStreamBufferDelete(xStreamBuffer: StreamBufferHandle) {
+    record("vStreamBufferDelete", vec![ptr(xStreamBuffer)]);
+}
+
+pub unsafe fn xQueueGenericSendFromISR(
+    xQueue: QueueHandle,
+    pvItemToQueue: *const c_void,
+    pxHigherPriorityTaskWoken: *mut BaseType,
+    xCopyPosition: BaseType,
+) -> BaseType {
+    reply("xQueueGenericSendFromISR", vec![ptr(xQueue), ptr(pvItemToQueue), ptr(pxHigherPriorityTaskWoken), int(xCopyPosition)], pdTRUE)
+}
+
+pub unsafe fn xQueueTakeMutexRecursive(xMutex: QueueHandle, xTicksToWait: TickType) -> BaseType {
+    reply("xQueueTakeMutexRecursive", vec![ptr(xMutex), int(xTicksToWait)], pdTRUE)
+}
+
+pub unsafe fn xQueueGiveMutexRecursive(xMutex: QueueHandle) -> BaseType {
+    reply("xQueueGiveMutexRecursive", vec![ptr(xMutex)], pdTRUE)
+}
+
+pub unsafe fn xPortGetFreeHeapSize() -> usize {
+    record("xPortGetFreeHeapSize", vec![]).map_or(0, |value| value as usize)
+}
+
+pub unsafe fn xTimerCreateTimerTask() -> BaseType {
+    reply("xTimerCreateTimerTask", vec![], pdPASS)
+}
+
+pub unsafe fn xTimerCreate(
+    pcTimerName: *const c_char,
+    xTimerPeriodInTicks: TickType,
+    xAutoReload: BaseType,
+    pvTimerID: *mut c_void,
+    pxCallbackFunction: Option<TimerCallback>,
+) -> TimerHandle {
+    let handle = create("xTimerCreate", vec![
+        string(pcTimerName),
+        int(xTimerPeriodInTicks),
+        int(xAutoReload),
+        ptr(pvTimerID),
+        Arg::Ptr(pxCallbackFunction.map_or(0, |callback| callback as usize)),
+    ]);
+    if !handle.is_null() {
+        recorder().timers.insert(handle as usize, MockTimer { callback: pxCallbackFunction, id: pvTimerID as usize });
+    }
+    handle
+}
+
+pub unsafe fn xTimerCreateStatic(
+    pcTimerName: *const c_char,
+    xTimerPeriodInTicks: TickType,
+    xAutoReload: BaseType,
+    pvTimerID: *mut c_void,
+    pxCallbackFunction: Option<TimerCallback>,
+    pxTimerBuffer: *mut StaticTimerBuffer,
+) -> TimerHandle {
+    let handle = create("xTimerCreateStatic", vec![
+        string(pcTimerName),
+        int(xTimerPeriodInTicks),
+        int(xAutoReload),
+        ptr(pvTimerID),
+        Arg::Ptr(pxCallbackFunction.map_or(0, |callback| callback as usize)),
+        ptr(pxTimerBuffer),
+    ]);
+    if !handle.is_null() {
+        recorder().timers.insert(handle as usize, MockTimer { callback: pxCallbackFunction, id: pvTimerID as usize });
+    }
+    handle
+}
+
+pub unsafe fn osal_rs_timer_start(xTimer: TimerHandle, xTicksToWait: TickType) -> BaseType {
+    reply("osal_rs_timer_start", vec![ptr(xTimer), int(xTicksToWait)], pdPASS)
+}
+
+pub unsafe fn osal_rs_timer_stop(xTimer: TimerHandle, xTicksToWait: TickType) -> BaseType {
+    reply("osal_rs_timer_stop", vec![ptr(xTimer), int(xTicksToWait)], pdPASS)
+}
+
+pub unsafe fn osal_rs_timer_reset(xTimer: TimerHandle, xTicksToWait: TickType) -> BaseType {
+    reply("osal_rs_timer_reset", vec![ptr(xTimer), int(xTicksToWait)], pdPASS)
+}
+
+pub unsafe fn osal_rs_timer_change_period(xTimer: TimerHandle, xNewPeriodInTicks: TickType, xTicksToWait: TickType) -> BaseType {
+    reply("osal_rs_timer_change_period", vec![ptr(xTimer), int(xNewPeriodInTicks), int(xTicksToWait)], pdPASS)
+}
+
+pub unsafe fn osal_rs_timer_delete(xTimer: TimerHandle, xTicksToWait: TickType) -> BaseType {
+    let mut recorder = recorder();
+    let ret = recorder.record("osal_rs_timer_delete", vec![ptr(xTimer), int(xTicksToWait)]).map_or(pdPASS, |value| value as BaseType);
+    if ret == pdPASS {
+        recorder.timers.remove(&(xTimer as usize));
+    }
+    ret
+}
+
+/// Runs the function at once when the call succeeds: the recorder has no
+/// timer task, so every command sent before it is already processed
+pub unsafe fn xTimerPendFunctionCall(xFunctionToPend: Option<PendedFunction>, pvParameter1: *mut c_void, ulParameter2: u32, xTicksToWait: TickType) -> BaseType {
+    let args = vec![Arg::Ptr(xFunctionToPend.map_or(0, |function| function as usize)), ptr(pvParameter1), int(ulParameter2), int(xTicksToWait)];
+    let ret = reply("xTimerPendFunctionCall", args, pdPASS);
+    if ret == pdPASS {
+        if let Some(function) = xFunctionToPend {
+            unsafe { function(pvParameter1, ulParameter2) };
+        }
+    }
+    ret
+}
+
+pub unsafe fn pvTimerGetTimerID(xTimer: TimerHandle) -> *mut c_void {
+    let mut recorder = recorder();
+    let id = recorder.timers.get(&(xTimer as usize)).map_or(0, |timer| timer.id);
+    recorder.record("pvTimerGetTimerID", vec![ptr(xTimer)]).map_or(id, |value| value as usize) as *mut c_void
+}
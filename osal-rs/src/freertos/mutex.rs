@@ -24,12 +24,10 @@ use core::marker::PhantomData;
 
 use alloc::sync::Arc;
 
-use super::ffi::{MutexHandle, pdFALSE, pdTRUE};
-use super::system::System;
-use crate::traits::SystemFn;
-use crate::traits::{MutexGuardFn, RawMutexFn, MutexFn, ToTick};
+use super::ffi::{MutexHandle, pdTRUE};
+use crate::traits::{MutexGuardFn, RawMutexFn, MutexFn, RecursiveMutexFn, ToTick};
 use crate::utils::{Result, Error, OsalRsBool, MAX_DELAY};
-use crate::{vSemaphoreDelete, xSemaphoreCreateRecursiveMutex, xSemaphoreGiveFromISR, xSemaphoreGiveRecursive, xSemaphoreTake, xSemaphoreTakeFromISR, xSemaphoreTakeRecursive};
+use crate::{vSemaphoreDelete, xSemaphoreCreateMutex, xSemaphoreCreateRecursiveMutex, xSemaphoreGive, xSemaphoreGiveRecursive, xSemaphoreTake, xSemaphoreTakeRecursive};
 
 
 struct RawMutex(MutexHandle);
@@ -39,7 +37,7 @@ unsafe impl Sync for RawMutex {}
 
 impl RawMutexFn for RawMutex {
     fn new() -> Result<Self> {
-        let handle = xSemaphoreCreateRecursiveMutex!();
+        let handle = xSemaphoreCreateMutex!();
         if handle.is_null() {
             Err(Error::OutOfMemory)
         } else {
@@ -48,7 +46,7 @@ impl RawMutexFn for RawMutex {
     }
     
     fn lock(&self) -> OsalRsBool {
-        let res = xSemaphoreTakeRecursive!(self.0, MAX_DELAY.to_ticks());
+        let res = xSemaphoreTake!(self.0, MAX_DELAY.to_ticks());
         if res == pdTRUE {
             OsalRsBool::True
         } else {
@@ -56,13 +54,9 @@ impl RawMutexFn for RawMutex {
         }
     }
 
-    fn lock_from_isr(&self) -> OsalRsBool {
-        let mut higher_priority_task_woken = pdFALSE;
-        let res = xSemaphoreTakeFromISR!(self.0, &mut higher_priority_task_woken);
+    fn try_lock(&self) -> OsalRsBool {
+        let res = xSemaphoreTake!(self.0, 0);
         if res == pdTRUE {
-
-            System::yield_from_isr(higher_priority_task_woken);
-
             OsalRsBool::True
         } else {
             OsalRsBool::False
@@ -70,7 +64,7 @@ impl RawMutexFn for RawMutex {
     }
 
     fn unlock(&self) -> OsalRsBool {
-        let res = xSemaphoreGiveRecursive!(self.0);
+        let res = xSemaphoreGive!(self.0);
         if res == pdTRUE {
             OsalRsBool::True
         } else {
@@ -78,20 +72,6 @@ impl RawMutexFn for RawMutex {
         }
     }
 
-
-    fn unlock_from_isr(&self) -> OsalRsBool {
-        let mut higher_priority_task_woken = pdFALSE;
-        let res = xSemaphoreGiveFromISR!(self.0, &mut higher_priority_task_woken);
-        if res == pdTRUE {
-            
-            System::yield_from_isr(higher_priority_task_woken);
-            
-            OsalRsBool::True
-        } else {
-            OsalRsBool::False
-        }
-    }
-
     fn delete(&mut self) {
         vSemaphoreDelete!(self.0);
         self.0 = core::ptr::null();
@@ -130,6 +110,77 @@ impl Display for RawMutex {
     }
 }
 
+struct RawRecursiveMutex(MutexHandle);
+
+unsafe impl Send for RawRecursiveMutex {}
+unsafe impl Sync for RawRecursiveMutex {}
+
+impl RawMutexFn for RawRecursiveMutex {
+    fn new() -> Result<Self> {
+        let handle = xSemaphoreCreateRecursiveMutex!();
+        if handle.is_null() {
+            Err(Error::OutOfMemory)
+        } else {
+            Ok(RawRecursiveMutex(handle))
+        }
+    }
+    
+    fn lock(&self) -> OsalRsBool {
+        let res = xSemaphoreTakeRecursive!(self.0, MAX_DELAY.to_ticks());
+        if res == pdTRUE {
+            OsalRsBool::True
+        } else {
+            OsalRsBool::False
+        }
+    }
+
+    fn try_lock(&self) -> OsalRsBool {
+        let res = xSemaphoreTakeRecursive!(self.0, 0);
+        if res == pdTRUE {
+            OsalRsBool::True
+        } else {
+            OsalRsBool::False
+        }
+    }
+
+    fn unlock(&self) -> OsalRsBool {
+        let res = xSemaphoreGiveRecursive!(self.0);
+        if res == pdTRUE {
+            OsalRsBool::True
+        } else {
+            OsalRsBool::False
+        }
+    }
+
+    fn delete(&mut self) {
+        vSemaphoreDelete!(self.0);
+        self.0 = core::ptr::null();
+    }
+}
+
+impl Drop for RawRecursiveMutex {
+    fn drop(&mut self) {
+        if self.0.is_null() {
+            return;
+        }
+        self.delete();
+    }
+}
+
+impl Debug for RawRecursiveMutex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RawRecursiveMutex")
+            .field("handle", &self.0)
+            .finish()
+    }
+}
+
+impl Display for RawRecursiveMutex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "RawRecursiveMutex {{ handle: {:?} }}", self.0)
+    }
+}
+
 pub struct Mutex<T: ?Sized> {
     inner: RawMutex,
     data: UnsafeCell<T>
@@ -141,7 +192,6 @@ unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
 
 impl<T: ?Sized> MutexFn<T> for Mutex<T> {
     type Guard<'a> = MutexGuard<'a, T> where Self: 'a, T: 'a;
-    type GuardFromIsr<'a> = MutexGuardFromIsr<'a, T> where Self: 'a, T: 'a;
 
     fn new(data: T) -> Self
     where 
@@ -163,9 +213,9 @@ impl<T: ?Sized> MutexFn<T> for Mutex<T> {
         }
     }
 
-    fn lock_from_isr(&self) -> Result<Self::GuardFromIsr<'_>> {
-        match self.inner.lock_from_isr() {
-            OsalRsBool::True => Ok(MutexGuardFromIsr {
+    fn try_lock(&self) -> Result<Self::Guard<'_>> {
+        match self.inner.try_lock() {
+            OsalRsBool::True => Ok(MutexGuard {
                 mutex: self,
                 _phantom: PhantomData,
             }),
@@ -186,19 +236,6 @@ impl<T: ?Sized> MutexFn<T> for Mutex<T> {
     }
 }
 
-impl<T: ?Sized> Mutex<T> {
-    /// Acquires the mutex from ISR context, returning a specific ISR guard
-    pub fn lock_from_isr_explicit(&self) -> Result<MutexGuardFromIsr<'_, T>> {
-        match self.inner.lock_from_isr() {
-            OsalRsBool::True => Ok(MutexGuardFromIsr {
-                mutex: self,
-                _phantom: PhantomData,
-            }),
-            OsalRsBool::False => Err(Error::MutexLockFailed),
-        }
-    }
-}
-
 impl<T> Mutex<T> {
     /// Creates a new mutex wrapped in an Arc for easy sharing between threads.
     /// This is a convenience method that combines `Arc::new(Mutex::new(data))`.
@@ -259,29 +296,101 @@ impl<'a, T: ?Sized> Drop for MutexGuard<'a, T> {
 
 impl<'a, T: ?Sized> MutexGuardFn<'a, T> for MutexGuard<'a, T> {}
 
-pub struct MutexGuardFromIsr<'a, T: ?Sized + 'a> {
-    mutex: &'a Mutex<T>,
-    _phantom: PhantomData<&'a mut T>,
+pub struct RecursiveMutex<T: ?Sized> {
+    inner: RawRecursiveMutex,
+    data: UnsafeCell<T>
 }
 
-impl<'a, T: ?Sized> Deref for MutexGuardFromIsr<'a, T> {
-    type Target = T;
+unsafe impl<T: ?Sized + Send> Send for RecursiveMutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for RecursiveMutex<T> {}
 
-    fn deref(&self) -> &T {
-        unsafe { &*self.mutex.data.get() }
+impl<T: ?Sized> RecursiveMutexFn<T> for RecursiveMutex<T> {
+    type Guard<'a> = RecursiveMutexGuard<'a, T> where Self: 'a, T: 'a;
+
+    fn new(data: T) -> Self
+    where 
+        T: Sized
+    {
+        Self {
+            inner: RawRecursiveMutex::new().unwrap(),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    fn lock(&self) -> Result<Self::Guard<'_>> {
+        match self.inner.lock() {
+            OsalRsBool::True => Ok(RecursiveMutexGuard {
+                mutex: self,
+                _phantom: PhantomData,
+            }),
+            OsalRsBool::False => Err(Error::MutexLockFailed),
+        }
+    }
+
+    fn try_lock(&self) -> Result<Self::Guard<'_>> {
+        match self.inner.try_lock() {
+            OsalRsBool::True => Ok(RecursiveMutexGuard {
+                mutex: self,
+                _phantom: PhantomData,
+            }),
+            OsalRsBool::False => Err(Error::MutexLockFailed),
+        }
+    }
+
+    fn into_inner(self) -> Result<T> 
+    where 
+        Self: Sized, 
+        T: Sized 
+    {
+        Ok(self.data.into_inner())
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
     }
 }
 
-impl<'a, T: ?Sized> DerefMut for MutexGuardFromIsr<'a, T> {
-    fn deref_mut(&mut self) -> &mut T {
-        unsafe { &mut *self.mutex.data.get() }
+impl<T> RecursiveMutex<T> {
+    /// Creates a new recursive mutex wrapped in an Arc, like `Mutex::new_arc`
+    pub fn new_arc(data: T) -> Arc<Self> {
+        Arc::new(Self::new(data))
     }
 }
 
-impl<'a, T: ?Sized> Drop for MutexGuardFromIsr<'a, T> {
-    fn drop(&mut self) {
-        self.mutex.inner.unlock_from_isr();
+impl<T> Debug for RecursiveMutex<T> 
+where 
+    T: ?Sized {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RecursiveMutex")
+            .field("inner", &self.inner)
+            .finish()
     }
 }
 
-impl<'a, T: ?Sized> MutexGuardFn<'a, T> for MutexGuardFromIsr<'a, T> {}
\ No newline at end of file
+impl<T> Display for RecursiveMutex<T> 
+where 
+    T: ?Sized {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "RecursiveMutex {{ inner: {} }}", self.inner)
+    }   
+}
+
+/// RAII guard returned by `RecursiveMutex::lock`, shared access only
+pub struct RecursiveMutexGuard<'a, T: ?Sized + 'a> {
+    mutex: &'a RecursiveMutex<T>,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T: ?Sized> Deref for RecursiveMutexGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<'a, T: ?Sized> Drop for RecursiveMutexGuard<'a, T> {
+    fn drop(&mut self) {
+        self.mutex.inner.unlock();
+    }
+}
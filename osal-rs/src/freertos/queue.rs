@@ -20,16 +20,17 @@
 use core::ffi::c_void;
 use core::fmt::{Debug, Display};
 use core::marker::PhantomData;
+use core::mem::{MaybeUninit, size_of};
 use core::ops::Deref;
 
 use alloc::vec;
 
-use super::ffi::{QueueHandle, pdFALSE, vQueueDelete, xQueueCreateCountingSemaphore, xQueueReceive, xQueueReceiveFromISR};
-use super::types::{BaseType, UBaseType, TickType};
+use super::ffi::{QueueHandle, StaticQueueBuffer, pdFALSE, pdPASS, queueQUEUE_TYPE_BASE, vQueueDelete, xQueueGenericCreateStatic, xQueuePeek, xQueuePeekFromISR, xQueueReceive, xQueueReceiveFromISR};
+use super::types::{BaseType, UBaseType, TickType, STATIC_QUEUE_SIZE};
 use super::system::System;
 use crate::traits::{ToTick, QueueFn, SystemFn, QueueStreamedFn, ToBytes, BytesHasLen, FromBytes};
 use crate::utils::{Result, Error};
-use crate::{xQueueSendToBack, xQueueSendToBackFromISR};
+use crate::{xQueueCreate, xQueueOverwrite, xQueueOverwriteFromISR, xQueueReset, xQueueSendToBack, xQueueSendToBackFromISR, xQueueSendToFront, xQueueSendToFrontFromISR};
 
 
 pub struct Queue (QueueHandle);
@@ -51,7 +52,7 @@ impl Queue {
 
 impl QueueFn for Queue {
     fn new (size: UBaseType, message_size: super::types::UBaseType) -> Result<Self> {
-        let handle = unsafe { xQueueCreateCountingSemaphore(size, message_size) };
+        let handle = xQueueCreate!(size, message_size);
         if handle.is_null() {
             Err(Error::OutOfMemory)
         } else {
@@ -128,6 +129,110 @@ impl QueueFn for Queue {
         }
     }
 
+    fn post_to_front(&self, item: &[u8], time: TickType) -> Result<()> {
+        let ret = xQueueSendToFront!(
+                            self.0,
+                            item.as_ptr() as *const c_void,
+                            time
+                        );
+
+        if ret == 0 {
+            Err(Error::Timeout)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn post_to_front_from_isr(&self, item: &[u8]) -> Result<()> {
+
+        let mut task_woken_by_receive: BaseType = pdFALSE;
+
+        let ret = xQueueSendToFrontFromISR!(
+                            self.0,
+                            item.as_ptr() as *const c_void,
+                            &mut task_woken_by_receive
+                        );
+
+        if ret == 0 {
+            Err(Error::Timeout)
+        } else {
+            System::yield_from_isr(task_woken_by_receive);
+
+            Ok(())
+        }
+    }
+
+    /// The queue must have been created with a size of 1, as required by
+    /// `xQueueOverwrite`
+    fn overwrite(&self, item: &[u8]) -> Result<()> {
+        let ret = xQueueOverwrite!(
+                            self.0,
+                            item.as_ptr() as *const c_void
+                        );
+
+        if ret == 0 {
+            Err(Error::QueueFull)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn overwrite_from_isr(&self, item: &[u8]) -> Result<()> {
+
+        let mut task_woken_by_receive: BaseType = pdFALSE;
+
+        let ret = xQueueOverwriteFromISR!(
+                            self.0,
+                            item.as_ptr() as *const c_void,
+                            &mut task_woken_by_receive
+                        );
+
+        if ret == 0 {
+            Err(Error::QueueFull)
+        } else {
+            System::yield_from_isr(task_woken_by_receive);
+
+            Ok(())
+        }
+    }
+
+    fn peek(&self, buffer: &mut [u8], time: TickType) -> Result<()> {
+        let ret = unsafe {
+            xQueuePeek(
+                self.0,
+                buffer.as_mut_ptr() as *mut c_void,
+                time,
+            )
+        };
+        if ret == 0 {
+            Err(Error::Timeout)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn peek_from_isr(&self, buffer: &mut [u8]) -> Result<()> {
+        let ret = unsafe {
+            xQueuePeekFromISR(
+                self.0,
+                buffer.as_mut_ptr() as *mut c_void,
+            )
+        };
+        if ret == 0 {
+            Err(Error::Timeout)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn reset(&self) -> Result<()> {
+        if xQueueReset!(self.0) != pdPASS {
+            Err(Error::Unhandled("Failed to reset the queue"))
+        } else {
+            Ok(())
+        }
+    }
+
     fn delete(&mut self) {
         unsafe {
             vQueueDelete(self.0);
@@ -167,6 +272,210 @@ impl Display for Queue {
     }
 }
 
+/// Storage of a queue that does not use the FreeRTOS heap: the queue
+/// control block and room for `N` items of `size_of::<T>()` bytes.
+pub struct StaticQueue<T: Copy, const N: usize> {
+    queue: StaticQueueBuffer,
+    storage: [MaybeUninit<T>; N],
+}
+
+impl<T: Copy, const N: usize> StaticQueue<T, N> {
+    pub const fn new() -> Self {
+        Self {
+            queue: StaticQueueBuffer([0; STATIC_QUEUE_SIZE]),
+            storage: [const { MaybeUninit::uninit() }; N],
+        }
+    }
+
+    /// Creates the queue with `xQueueGenericCreateStatic`, items are posted
+    /// and fetched as `T`
+    pub fn create(&'static mut self) -> Result<QueueTyped<T>> {
+        if N == 0 {
+            return Err(Error::InvalidQueueSize);
+        }
+
+        let handle = unsafe {
+            xQueueGenericCreateStatic(
+                N as UBaseType,
+                size_of::<T>() as UBaseType,
+                self.storage.as_mut_ptr() as *mut u8,
+                &mut self.queue,
+                queueQUEUE_TYPE_BASE,
+            )
+        };
+
+        if handle.is_null() {
+            Err(Error::NullPtr)
+        } else {
+            Ok(QueueTyped (Queue (handle), PhantomData))
+        }
+    }
+}
+
+impl<T: Copy, const N: usize> Default for StaticQueue<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Queue of `T` items, each copied in and out by the kernel as the
+/// `size_of::<T>()` bytes the queue was created with
+pub struct QueueTyped<T: Copy> (Queue, PhantomData<T>);
+
+unsafe impl<T: Copy + Send> Send for QueueTyped<T> {}
+unsafe impl<T: Copy + Send> Sync for QueueTyped<T> {}
+
+impl<T: Copy> QueueTyped<T> {
+    /// Receive with `xQueueReceive` or peek with `xQueuePeek`
+    fn receive(&self, time: TickType, peek: bool) -> Result<T> {
+        let mut item = MaybeUninit::<T>::uninit();
+        let buffer = item.as_mut_ptr() as *mut c_void;
+        let ret = unsafe {
+            if peek {
+                xQueuePeek(self.0.0, buffer, time)
+            } else {
+                xQueueReceive(self.0.0, buffer, time)
+            }
+        };
+        if ret == 0 {
+            Err(Error::Timeout)
+        } else {
+            Ok(unsafe { item.assume_init() })
+        }
+    }
+
+    fn receive_from_isr(&self, peek: bool) -> Result<T> {
+        let mut item = MaybeUninit::<T>::uninit();
+        let buffer = item.as_mut_ptr() as *mut c_void;
+        let mut task_woken_by_receive: BaseType = pdFALSE;
+        let ret = unsafe {
+            if peek {
+                xQueuePeekFromISR(self.0.0, buffer)
+            } else {
+                xQueueReceiveFromISR(self.0.0, buffer, &mut task_woken_by_receive)
+            }
+        };
+        if ret == 0 {
+            Err(Error::Timeout)
+        } else {
+            System::yield_from_isr(task_woken_by_receive);
+            Ok(unsafe { item.assume_init() })
+        }
+    }
+
+    #[inline]
+    pub fn fetch(&self, time: TickType) -> Result<T> {
+        self.receive(time, false)
+    }
+
+    #[inline]
+    pub fn fetch_with_to_tick(&self, time: impl ToTick) -> Result<T> {
+        self.fetch(time.to_ticks())
+    }
+
+    #[inline]
+    pub fn fetch_from_isr(&self) -> Result<T> {
+        self.receive_from_isr(false)
+    }
+
+    #[inline]
+    pub fn peek(&self, time: TickType) -> Result<T> {
+        self.receive(time, true)
+    }
+
+    #[inline]
+    pub fn peek_from_isr(&self) -> Result<T> {
+        self.receive_from_isr(true)
+    }
+
+    pub fn post(&self, item: &T, time: TickType) -> Result<()> {
+        let ret = xQueueSendToBack!(self.0.0, item as *const T as *const c_void, time);
+        if ret == 0 {
+            Err(Error::Timeout)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    pub fn post_with_to_tick(&self, item: &T, time: impl ToTick) -> Result<()> {
+        self.post(item, time.to_ticks())
+    }
+
+    pub fn post_from_isr(&self, item: &T) -> Result<()> {
+        let mut task_woken_by_receive: BaseType = pdFALSE;
+        let ret = xQueueSendToBackFromISR!(self.0.0, item as *const T as *const c_void, &mut task_woken_by_receive);
+        if ret == 0 {
+            Err(Error::Timeout)
+        } else {
+            System::yield_from_isr(task_woken_by_receive);
+            Ok(())
+        }
+    }
+
+    pub fn post_to_front(&self, item: &T, time: TickType) -> Result<()> {
+        let ret = xQueueSendToFront!(self.0.0, item as *const T as *const c_void, time);
+        if ret == 0 {
+            Err(Error::Timeout)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn post_to_front_from_isr(&self, item: &T) -> Result<()> {
+        let mut task_woken_by_receive: BaseType = pdFALSE;
+        let ret = xQueueSendToFrontFromISR!(self.0.0, item as *const T as *const c_void, &mut task_woken_by_receive);
+        if ret == 0 {
+            Err(Error::Timeout)
+        } else {
+            System::yield_from_isr(task_woken_by_receive);
+            Ok(())
+        }
+    }
+
+    /// The queue must have a length of 1, as required by `xQueueOverwrite`
+    pub fn overwrite(&self, item: &T) -> Result<()> {
+        let ret = xQueueOverwrite!(self.0.0, item as *const T as *const c_void);
+        if ret == 0 {
+            Err(Error::QueueFull)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn overwrite_from_isr(&self, item: &T) -> Result<()> {
+        let mut task_woken_by_receive: BaseType = pdFALSE;
+        let ret = xQueueOverwriteFromISR!(self.0.0, item as *const T as *const c_void, &mut task_woken_by_receive);
+        if ret == 0 {
+            Err(Error::QueueFull)
+        } else {
+            System::yield_from_isr(task_woken_by_receive);
+            Ok(())
+        }
+    }
+
+    #[inline]
+    pub fn reset(&self) -> Result<()> {
+        self.0.reset()
+    }
+}
+
+impl<T: Copy> Deref for QueueTyped<T> {
+    type Target = QueueHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.0
+    }
+}
+
+impl<T: Copy> Debug for QueueTyped<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("QueueTyped")
+            .field("handle", &self.0.0)
+            .finish()
+    }
+}
+
 pub struct QueueStreamed<T: ToBytes + BytesHasLen + FromBytes> (Queue, PhantomData<T>);
 
 unsafe impl<T: ToBytes + BytesHasLen + FromBytes> Send for QueueStreamed<T> {}
@@ -227,6 +536,53 @@ where
         self.0.post_from_isr(&item.to_bytes())
     }
 
+    #[inline]
+    fn post_to_front(&self, item: &T, time: TickType) -> Result<()> {
+        self.0.post_to_front(item.to_bytes(), time)
+    }
+
+    #[inline]
+    fn post_to_front_from_isr(&self, item: &T) -> Result<()> {
+        self.0.post_to_front_from_isr(item.to_bytes())
+    }
+
+    #[inline]
+    fn overwrite(&self, item: &T) -> Result<()> {
+        self.0.overwrite(item.to_bytes())
+    }
+
+    #[inline]
+    fn overwrite_from_isr(&self, item: &T) -> Result<()> {
+        self.0.overwrite_from_isr(item.to_bytes())
+    }
+
+    fn peek(&self, buffer: &mut T, time: TickType) -> Result<()> {
+        let mut buf_bytes = vec![0u8; buffer.len()];        
+
+        if let Ok(()) = self.0.peek(&mut buf_bytes, time) {
+            *buffer = T::from_bytes(&buf_bytes)?;
+            Ok(())
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+
+    fn peek_from_isr(&self, buffer: &mut T) -> Result<()> {
+        let mut buf_bytes = vec![0u8; buffer.len()];        
+
+        if let Ok(()) = self.0.peek_from_isr(&mut buf_bytes) {
+            *buffer = T::from_bytes(&buf_bytes)?;
+            Ok(())
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+
+    #[inline]
+    fn reset(&self) -> Result<()> {
+        self.0.reset()
+    }
+
     #[inline]
     fn delete(&mut self) {
         self.0.delete()
@@ -0,0 +1,163 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::fmt::{Debug, Display};
+use core::ops::Deref;
+use core::ptr::null_mut;
+
+use super::ffi::{QueueSetHandle, QueueSetMemberHandle, pdPASS, vQueueDelete, xQueueAddToSet, xQueueCreateSet, xQueueRemoveFromSet, xQueueSelectFromSet, xQueueSelectFromSetFromISR};
+use super::queue::{Queue, QueueStreamed, QueueTyped};
+use super::semaphore::{BinarySemaphore, Semaphore};
+use super::types::{TickType, UBaseType};
+use crate::traits::{QueueSetFn, QueueSetMember, ToTick, ToBytes, BytesHasLen, FromBytes};
+use crate::utils::{Result, Error};
+
+pub struct QueueSet (QueueSetHandle);
+
+unsafe impl Send for QueueSet {}
+unsafe impl Sync for QueueSet {}
+
+impl QueueSet {
+    #[inline]
+    pub fn select_with_to_tick(&self, time: impl ToTick) -> Result<QueueSetMemberHandle> {
+        self.select(time.to_ticks())
+    }
+}
+
+impl QueueSetFn for QueueSet {
+    fn new(size: UBaseType) -> Result<Self> {
+        let handle = unsafe { xQueueCreateSet(size) };
+        if handle.is_null() {
+            Err(Error::OutOfMemory)
+        } else {
+            Ok(Self (handle))
+        }
+    }
+
+    /// Fails if the member is not empty or already belongs to a set
+    fn add(&self, member: &impl QueueSetMember) -> Result<()> {
+        if unsafe { xQueueAddToSet(member.member_handle(), self.0) } != pdPASS {
+            Err(Error::Unhandled("Failed to add to the queue set"))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Fails if the member is not empty or does not belong to the set
+    fn remove(&self, member: &impl QueueSetMember) -> Result<()> {
+        if unsafe { xQueueRemoveFromSet(member.member_handle(), self.0) } != pdPASS {
+            Err(Error::Unhandled("Failed to remove from the queue set"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn select(&self, time: TickType) -> Result<QueueSetMemberHandle> {
+        let member = unsafe { xQueueSelectFromSet(self.0, time) };
+        if member.is_null() {
+            Err(Error::Timeout)
+        } else {
+            Ok(member)
+        }
+    }
+
+    fn select_from_isr(&self) -> Result<QueueSetMemberHandle> {
+        let member = unsafe { xQueueSelectFromSetFromISR(self.0) };
+        if member.is_null() {
+            Err(Error::Timeout)
+        } else {
+            Ok(member)
+        }
+    }
+
+    fn delete(&mut self) {
+        unsafe {
+            vQueueDelete(self.0);
+            self.0 = null_mut();
+        }
+    }
+}
+
+impl Drop for QueueSet {
+    fn drop(&mut self) {
+        if self.0.is_null() {
+            return;
+        }
+        self.delete();
+    }
+}
+
+impl Deref for QueueSet {
+    type Target = QueueSetHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for QueueSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("QueueSet")
+            .field("handle", &self.0)
+            .finish()
+    }
+}
+
+impl Display for QueueSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "QueueSet {{ handle: {:?} }}", self.0)
+    }
+}
+
+impl QueueSetMember for Queue {
+    #[inline]
+    fn member_handle(&self) -> QueueSetMemberHandle {
+        **self
+    }
+}
+
+impl<T> QueueSetMember for QueueStreamed<T> 
+where 
+    T: ToBytes + BytesHasLen + FromBytes {
+    #[inline]
+    fn member_handle(&self) -> QueueSetMemberHandle {
+        **self
+    }
+}
+
+impl<T: Copy> QueueSetMember for QueueTyped<T> {
+    #[inline]
+    fn member_handle(&self) -> QueueSetMemberHandle {
+        **self
+    }
+}
+
+impl QueueSetMember for Semaphore {
+    #[inline]
+    fn member_handle(&self) -> QueueSetMemberHandle {
+        **self
+    }
+}
+
+impl QueueSetMember for BinarySemaphore {
+    #[inline]
+    fn member_handle(&self) -> QueueSetMemberHandle {
+        **self
+    }
+}
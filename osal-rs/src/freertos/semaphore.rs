@@ -21,18 +21,43 @@ use core::fmt::{Debug, Display};
 use core::ops::Deref;
 use core::ptr::null_mut;
 
-use super::ffi::{SemaphoreHandle, pdFAIL, pdFALSE};
+use super::ffi::{SemaphoreHandle, StaticQueueBuffer, pdFAIL, pdFALSE, xQueueCreateCountingSemaphoreStatic};
 use super::system::System;
-use super::types::{BaseType, UBaseType};
-use crate::traits::{SemaphoreFn, SystemFn, ToTick};
+use super::types::{BaseType, UBaseType, STATIC_QUEUE_SIZE};
+use crate::traits::{BinarySemaphoreFn, SemaphoreFn, SystemFn, ToTick};
 use crate::utils::{Error, Result, OsalRsBool};
-use crate::{vSemaphoreDelete, xSemaphoreCreateCounting, xSemaphoreGive, xSemaphoreGiveFromISR, xSemaphoreTake, xSemaphoreTakeFromISR};
+use crate::{vSemaphoreDelete, xSemaphoreCreateBinary, xSemaphoreCreateCounting, xSemaphoreGive, xSemaphoreGiveFromISR, xSemaphoreTake, xSemaphoreTakeFromISR};
 
 pub struct Semaphore (SemaphoreHandle);
 
 unsafe impl Send for Semaphore {}
 unsafe impl Sync for Semaphore {}
 
+/// Storage of a counting semaphore that does not use the FreeRTOS heap
+pub struct StaticSemaphore(StaticQueueBuffer);
+
+impl StaticSemaphore {
+    pub const fn new() -> Self {
+        Self (StaticQueueBuffer([0; STATIC_QUEUE_SIZE]))
+    }
+
+    /// Creates the semaphore with `xQueueCreateCountingSemaphoreStatic`
+    pub fn create(&'static mut self, max_count: UBaseType, initial_count: UBaseType) -> Result<Semaphore> {
+        let handle = unsafe { xQueueCreateCountingSemaphoreStatic(max_count, initial_count, &mut self.0) };
+        if handle.is_null() {
+            Err(Error::NullPtr)
+        } else {
+            Ok(Semaphore (handle))
+        }
+    }
+}
+
+impl Default for StaticSemaphore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 
 impl SemaphoreFn for Semaphore {
     fn new(max_count: UBaseType, initial_count: UBaseType) -> Result<Self> {
@@ -132,4 +157,61 @@ impl Display for Semaphore {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Semaphore {{ handle: {:?} }}", self.0)
     }
-}
\ No newline at end of file
+}
+
+/// Binary semaphore created with `xSemaphoreCreateBinary`, it shares the
+/// implementation of the counting `Semaphore` with a maximum count of one
+pub struct BinarySemaphore (Semaphore);
+
+impl BinarySemaphoreFn for BinarySemaphore {
+    fn new() -> Result<Self> {
+        let handle = xSemaphoreCreateBinary!();
+        if handle.is_null() {
+            Err(Error::OutOfMemory)
+        } else {
+            Ok(Self (Semaphore (handle)))
+        }
+    }
+
+    fn wait(&self, ticks_to_wait: impl ToTick) -> OsalRsBool {
+        self.0.wait(ticks_to_wait)
+    }
+
+    fn wait_from_isr(&self) -> OsalRsBool {
+        self.0.wait_from_isr()
+    }
+
+    fn signal(&self) -> OsalRsBool {
+        self.0.signal()
+    }
+
+    fn signal_from_isr(&self) -> OsalRsBool {
+        self.0.signal_from_isr()
+    }
+
+    fn delete(&mut self) {
+        self.0.delete();
+    }
+}
+
+impl Deref for BinarySemaphore {
+    type Target = SemaphoreHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.0
+    }
+}
+
+impl Debug for BinarySemaphore {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BinarySemaphore")
+            .field("handle", &self.0.0)
+            .finish()
+    }
+}
+
+impl Display for BinarySemaphore {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "BinarySemaphore {{ handle: {:?} }}", self.0.0)
+    }
+}
@@ -0,0 +1,259 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::ffi::c_void;
+use core::fmt::{Debug, Display};
+use core::ops::Deref;
+use core::ptr::null_mut;
+
+use super::ffi::{StreamBufferHandle, pdFALSE, pdPASS, vStreamBufferDelete, xStreamBufferBytesAvailable, xStreamBufferReceive, xStreamBufferReceiveFromISR, xStreamBufferReset, xStreamBufferSend, xStreamBufferSendFromISR, xStreamBufferSetTriggerLevel, xStreamBufferSpacesAvailable};
+use super::system::System;
+use super::types::BaseType;
+use crate::traits::{StreamBufferFn, SystemFn, ToTick};
+use crate::utils::{Result, Error, MAX_DELAY};
+use crate::xStreamBufferCreate;
+
+/// FreeRTOS stream buffer, usable through `embedded_io::Read` and `Write`
+pub struct StreamBuffer (StreamBufferHandle);
+
+unsafe impl Send for StreamBuffer {}
+unsafe impl Sync for StreamBuffer {}
+
+impl StreamBufferFn for StreamBuffer {
+    fn new(size: usize, trigger_level: usize) -> Result<Self> {
+        if size == 0 || trigger_level > size {
+            return Err(Error::InvalidQueueSize);
+        }
+
+        let handle = xStreamBufferCreate!(size, trigger_level);
+        if handle.is_null() {
+            Err(Error::OutOfMemory)
+        } else {
+            Ok(Self (handle))
+        }
+    }
+
+    fn send(&self, data: &[u8], ticks_to_wait: impl ToTick) -> usize {
+        unsafe {
+            xStreamBufferSend(
+                self.0,
+                data.as_ptr() as *const c_void,
+                data.len(),
+                ticks_to_wait.to_ticks(),
+            )
+        }
+    }
+
+    fn send_from_isr(&self, data: &[u8]) -> usize {
+        let mut higher_priority_task_woken: BaseType = pdFALSE;
+
+        let sent = unsafe {
+            xStreamBufferSendFromISR(
+                self.0,
+                data.as_ptr() as *const c_void,
+                data.len(),
+                &mut higher_priority_task_woken,
+            )
+        };
+
+        System::yield_from_isr(higher_priority_task_woken);
+        sent
+    }
+
+    fn receive(&self, buffer: &mut [u8], ticks_to_wait: impl ToTick) -> usize {
+        unsafe {
+            xStreamBufferReceive(
+                self.0,
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer.len(),
+                ticks_to_wait.to_ticks(),
+            )
+        }
+    }
+
+    fn receive_from_isr(&self, buffer: &mut [u8]) -> usize {
+        let mut higher_priority_task_woken: BaseType = pdFALSE;
+
+        let received = unsafe {
+            xStreamBufferReceiveFromISR(
+                self.0,
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer.len(),
+                &mut higher_priority_task_woken,
+            )
+        };
+
+        System::yield_from_isr(higher_priority_task_woken);
+        received
+    }
+
+    #[inline]
+    fn bytes_available(&self) -> usize {
+        unsafe { xStreamBufferBytesAvailable(self.0) }
+    }
+
+    #[inline]
+    fn spaces_available(&self) -> usize {
+        unsafe { xStreamBufferSpacesAvailable(self.0) }
+    }
+
+    fn set_trigger_level(&self, trigger_level: usize) -> Result<()> {
+        if unsafe { xStreamBufferSetTriggerLevel(self.0, trigger_level) } != pdPASS {
+            Err(Error::InvalidQueueSize)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn reset(&self) -> Result<()> {
+        if unsafe { xStreamBufferReset(self.0) } != pdPASS {
+            Err(Error::Unhandled("Stream buffer reset while a task is blocked on it"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn delete(&mut self) {
+        unsafe {
+            vStreamBufferDelete(self.0);
+            self.0 = null_mut();
+        }
+    }
+}
+
+impl Drop for StreamBuffer {
+    fn drop(&mut self) {
+        if self.0.is_null() {
+            return;
+        }
+        self.delete();
+    }
+}
+
+impl Deref for StreamBuffer {
+    type Target = StreamBufferHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for StreamBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StreamBuffer")
+            .field("handle", &self.0)
+            .finish()
+    }
+}
+
+impl Display for StreamBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "StreamBuffer {{ handle: {:?} }}", self.0)
+    }
+}
+
+impl embedded_io::ErrorType for &StreamBuffer {
+    type Error = Error;
+}
+
+/// Blocks until at least one byte is available
+impl embedded_io::Read for &StreamBuffer {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let received = self.receive(buf, MAX_DELAY);
+            if received > 0 {
+                return Ok(received);
+            }
+        }
+    }
+}
+
+impl embedded_io::ReadReady for &StreamBuffer {
+    fn read_ready(&mut self) -> Result<bool> {
+        Ok(self.bytes_available() > 0)
+    }
+}
+
+/// Blocks until at least one byte is written
+impl embedded_io::Write for &StreamBuffer {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let sent = self.send(buf, MAX_DELAY);
+            if sent > 0 {
+                return Ok(sent);
+            }
+        }
+    }
+
+    /// The data is handed to the reader as soon as it is written
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl embedded_io::WriteReady for &StreamBuffer {
+    fn write_ready(&mut self) -> Result<bool> {
+        Ok(self.spaces_available() > 0)
+    }
+}
+
+impl embedded_io::ErrorType for StreamBuffer {
+    type Error = Error;
+}
+
+impl embedded_io::Read for StreamBuffer {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        (&*self).read(buf)
+    }
+}
+
+impl embedded_io::ReadReady for StreamBuffer {
+    #[inline]
+    fn read_ready(&mut self) -> Result<bool> {
+        (&*self).read_ready()
+    }
+}
+
+impl embedded_io::Write for StreamBuffer {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        (&*self).write(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        (&*self).flush()
+    }
+}
+
+impl embedded_io::WriteReady for StreamBuffer {
+    #[inline]
+    fn write_ready(&mut self) -> Result<bool> {
+        (&*self).write_ready()
+    }
+}
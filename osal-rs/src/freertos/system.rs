@@ -104,9 +104,8 @@ impl SystemFn for System {
         Duration::from_millis( 1_000 * ticks as u64 / tick_period_ms!() as u64 )
     }
 
-    fn get_us_from_tick(duration: &Duration) -> TickType {
-        let millis = duration.as_millis() as TickType;
-        millis / (1_000 * tick_period_ms!() as TickType) 
+    fn get_ticks_from_duration(duration: &Duration) -> TickType {
+        duration.to_ticks()
     }
 
     fn count_threads() -> usize {
@@ -18,23 +18,25 @@
  ***************************************************************************/
 
 use core::any::Any;
-use core::ffi::{c_char, c_void};
+use core::cell::UnsafeCell;
+use core::ffi::{CStr, c_char, c_void};
 use core::fmt::{Debug, Display, Formatter};
 use core::ops::Deref;
 use core::ptr::null_mut;
+use core::sync::atomic::{AtomicBool, Ordering};
 
 use alloc::boxed::Box;
 use alloc::ffi::CString;
 use alloc::string::{String, ToString};
 use alloc::sync::Arc;
 
-use super::ffi::{INVALID, TaskStatus, ThreadHandle, pdPASS, pdTRUE, vTaskDelete, vTaskGetInfo, vTaskResume, vTaskSuspend, xTaskCreate, xTaskGetCurrentTaskHandle};
-use super::types::{StackType, UBaseType, BaseType, TickType};
+use super::ffi::{INVALID, StaticTaskBuffer, TaskStatus, ThreadHandle, pdFAIL, pdFALSE, pdPASS, pdTRUE, tskDEFAULT_INDEX_TO_NOTIFY, ulTaskGenericNotifyTake, vTaskDelete, vTaskGenericNotifyGiveFromISR, vTaskGetInfo, vTaskResume, vTaskSuspend, xTaskCreate, xTaskCreateStatic, xTaskGenericNotify, xTaskGenericNotifyFromISR, xTaskGenericNotifyStateClear, xTaskGenericNotifyWait, xTaskGetCurrentTaskHandle};
+use super::semaphore::Semaphore;
+use super::types::{StackType, UBaseType, BaseType, TickType, STATIC_TASK_SIZE};
 use super::thread::ThreadState::*;
-use crate::os::ThreadSimpleFnPtr;
-use crate::traits::{ThreadFn, ThreadParam, ThreadFnPtr, ThreadNotification, ToTick, ToPriority};
-use crate::utils::{Result, Error, DoublePtr};
-use crate::{from_c_str, xTaskNotify, xTaskNotifyFromISR, xTaskNotifyWait};
+use crate::traits::{JoinHandleFn, SemaphoreFn, ThreadFn, ThreadParam, ThreadFnPtr, ThreadNotification, ToTick, ToPriority};
+use crate::utils::{Result, Error, DoublePtr, MAX_DELAY};
+use crate::{from_c_str, task_notification_array_entries, xSemaphoreGive, xSemaphoreTake};
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[repr(u8)]
@@ -86,7 +88,7 @@ impl From<(ThreadHandle,TaskStatus)> for ThreadMetadata {
             state,
             current_priority: status.1.uxCurrentPriority,
             base_priority: status.1.uxBasePriority,
-            run_time_counter: status.1.ulRunTimeCounter,
+            run_time_counter: status.1.ulRunTimeCounter as UBaseType,
             stack_high_water_mark: status.1.usStackHighWaterMark,
         }
     }
@@ -109,6 +111,51 @@ impl Default for ThreadMetadata {
     }
 }
 
+/// Signalled by a spawned thread right before it deletes itself, so that
+/// `join` can wait for its callback to return, or by `delete` when the
+/// callback never will
+struct Exit {
+    finished: AtomicBool,
+    deleted: AtomicBool,
+    done: Semaphore,
+}
+
+impl Exit {
+    fn new() -> Result<Arc<Self>> {
+        Ok(Arc::new(Self {
+            finished: AtomicBool::new(false),
+            deleted: AtomicBool::new(false),
+            done: Semaphore::new(1, 0)?,
+        }))
+    }
+
+    fn signal(&self) {
+        self.finished.store(true, Ordering::SeqCst);
+        xSemaphoreGive!(*self.done);
+    }
+
+    /// Releases the joiners of a task deleted before its callback returned
+    fn cancel(&self) {
+        self.deleted.store(true, Ordering::SeqCst);
+        self.signal();
+    }
+
+    /// Each joiner gives the semaphore back for the next one
+    fn wait(&self, timeout_ticks: TickType) -> bool {
+        if self.finished.load(Ordering::SeqCst) {
+            return true;
+        }
+        if xSemaphoreTake!(*self.done, timeout_ticks) == pdFAIL {
+            return false;
+        }
+        xSemaphoreGive!(*self.done);
+        true
+    }
+}
+
+/// Closure run once by a spawned thread and the exit it signals afterwards
+type Entry = (Box<dyn FnOnce() + Send>, Arc<Exit>);
+
 #[derive(Clone)]
 pub struct Thread {
     handle: ThreadHandle,
@@ -116,7 +163,8 @@ pub struct Thread {
     stack_depth: StackType,
     priority: UBaseType,
     callback: Option<Arc<ThreadFnPtr>>,
-    param: Option<ThreadParam>
+    param: Option<ThreadParam>,
+    exit: Option<Arc<Exit>>,
 }
 
 unsafe impl Send for Thread {}
@@ -132,7 +180,8 @@ impl Thread {
             stack_depth, 
             priority: priority.to_priority(), 
             callback: None,
-            param: None 
+            param: None,
+            exit: None,
         }
     }
 
@@ -146,10 +195,12 @@ impl Thread {
             stack_depth, 
             priority: priority.to_priority(), 
             callback: None,
-            param: None 
+            param: None,
+            exit: None,
         })
     }
 
+    #[cfg_attr(feature = "mock", allow(clippy::not_unsafe_ptr_arg_deref))]
     pub fn get_metadata_from_handle(handle: ThreadHandle) -> ThreadMetadata {
         let mut status = TaskStatus::default();
         unsafe {
@@ -165,6 +216,17 @@ impl Thread {
         Self::get_metadata_from_handle(thread.handle)
     }
 
+    /// The kernel asserts on an index out of the notification array
+    fn check_notification_index(&self, index: UBaseType) -> Result<()> {
+        if self.handle.is_null() {
+            return Err(Error::NullPtr);
+        }
+        if index >= task_notification_array_entries!() as UBaseType {
+            return Err(Error::OutOfIndex);
+        }
+        Ok(())
+    }
+
     #[inline]
     pub fn wait_notification_with_to_tick(&self, bits_to_clear_on_entry: u32, bits_to_clear_on_exit: u32 , timeout_ticks: impl ToTick) -> Result<u32> {
         if self.handle.is_null() {
@@ -173,6 +235,114 @@ impl Thread {
         self.wait_notification(bits_to_clear_on_entry, bits_to_clear_on_exit, timeout_ticks.to_ticks())
     }
 
+    /// Creates the task running `entry` once, the task signals its exit and
+    /// deletes itself when `entry` returns
+    fn create(&self, entry: Box<dyn FnOnce() + Send>) -> Result<Self> {
+        // Convert name to CString to ensure null termination and proper lifetime
+        let c_name = CString::new(self.name.as_str())
+            .map_err(|_| Error::Unhandled("Failed to convert thread name to CString"))?;
+
+        let exit = Exit::new()?;
+        let entry: *mut Entry = Box::into_raw(Box::new((entry, exit.clone())));
+        let mut handle: ThreadHandle = null_mut();
+
+        let ret = unsafe {
+            xTaskCreate(
+                Some(simple_callback_wrapper),
+                c_name.as_ptr(),
+                self.stack_depth,
+                entry as *mut _,
+                self.priority,
+                &mut handle,
+            )
+        };
+
+        if ret != pdPASS {
+            drop(unsafe { Box::from_raw(entry) });
+            return Err(Error::OutOfMemory);
+        }
+
+        Ok(Self {
+            handle,
+            exit: Some(exit),
+            ..self.clone()
+        })
+    }
+
+}
+
+/// Value returned by the callback of a joinable thread, written by the thread
+/// before it signals its exit and taken by `join` once the exit is seen
+struct ResultSlot<T>(UnsafeCell<Option<T>>);
+
+unsafe impl<T: Send> Send for ResultSlot<T> {}
+unsafe impl<T: Send> Sync for ResultSlot<T> {}
+
+impl<T> ResultSlot<T> {
+    fn new() -> Self {
+        Self(UnsafeCell::new(None))
+    }
+
+    fn set(&self, value: T) {
+        unsafe { *self.0.get() = Some(value) };
+    }
+
+    fn take(&self) -> Option<T> {
+        unsafe { (*self.0.get()).take() }
+    }
+}
+
+/// Thread spawned with `spawn_joinable`, `join` yields the value returned by
+/// its callback.
+///
+/// # Example
+/// ```ignore
+/// let mut worker = Thread::new("worker", 1024, 3).spawn_joinable(move || checksum(&block))?;
+/// let sum = worker.join(MAX_DELAY.to_ticks())?;
+/// ```
+pub struct JoinHandle<T> {
+    thread: Thread,
+    result: Arc<ResultSlot<T>>,
+}
+
+impl<T> JoinHandle<T> {
+    /// The running thread, e.g. to notify it
+    pub fn thread(&self) -> &Thread {
+        &self.thread
+    }
+
+    #[inline]
+    pub fn join_with_to_tick(&mut self, timeout_ticks: impl ToTick) -> Result<T> {
+        self.join(timeout_ticks.to_ticks())
+    }
+}
+
+impl<T> JoinHandleFn<T> for JoinHandle<T> {
+    /// The task may still be deleting itself when this returns, its stack is
+    /// freed later by the idle task
+    fn join(&mut self, timeout_ticks: TickType) -> Result<T> {
+        let exit = self.thread.exit.as_ref().ok_or(Error::NullPtr)?;
+        if !exit.wait(timeout_ticks) {
+            return Err(Error::Timeout);
+        }
+        if exit.deleted.load(Ordering::SeqCst) {
+            return Err(Error::Unhandled("Thread deleted"));
+        }
+        self.result.take().ok_or(Error::Unhandled("Thread already joined"))
+    }
+
+    fn is_finished(&self) -> bool {
+        self.thread.exit.as_ref().is_some_and(|exit| exit.finished.load(Ordering::SeqCst))
+    }
+}
+
+impl<T> Debug for JoinHandle<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("JoinHandle")
+            .field("thread", &self.thread)
+            .field("finished", &self.is_finished())
+            .finish()
+    }
 }
 
 unsafe extern "C" fn callback_c_wrapper(param_ptr: *mut c_void) {
@@ -194,7 +364,14 @@ unsafe extern "C" fn callback_c_wrapper(param_ptr: *mut c_void) {
         let _ = callback(thread_instance, param_arc);
     }
 
-    thread.delete();
+    if let Some(exit) = &thread.exit {
+        exit.signal();
+    }
+
+    // vTaskDelete does not return: nothing left on this stack is dropped
+    let handle = thread.handle;
+    drop(thread);
+    unsafe { vTaskDelete(handle); }
 }
 
 unsafe extern "C" fn simple_callback_wrapper(param_ptr: *mut c_void) {
@@ -202,12 +379,90 @@ unsafe extern "C" fn simple_callback_wrapper(param_ptr: *mut c_void) {
         return;
     }
 
-    let func: Box<Arc<ThreadSimpleFnPtr>> = unsafe { Box::from_raw(param_ptr as *mut _) };
+    let entry: Box<Entry> = unsafe { Box::from_raw(param_ptr as *mut _) };
+    let (func, exit) = *entry;
+    func();
+    exit.signal();
+    // vTaskDelete does not return: the last reference frees the semaphore
+    drop(exit);
+
+    unsafe { vTaskDelete( xTaskGetCurrentTaskHandle()); } 
+}
+
+unsafe extern "C" fn static_callback_wrapper(param_ptr: *mut c_void) {
+    if param_ptr.is_null() {
+        return;
+    }
+
+    let func: fn() = unsafe { core::mem::transmute(param_ptr) };
     func();
 
     unsafe { vTaskDelete( xTaskGetCurrentTaskHandle()); } 
 }
 
+/// Storage of a thread that does not use the FreeRTOS heap: the TCB and a
+/// stack of `STACK` words, usually placed in a `static`.
+///
+/// # Example
+/// ```ignore
+/// static mut WORKER: StaticThread<512> = StaticThread::new();
+///
+/// let worker = unsafe { &mut *core::ptr::addr_of_mut!(WORKER) };
+/// let thread = worker.spawn(c"worker", 3, || { /* ... */ })?;
+/// ```
+pub struct StaticThread<const STACK: usize> {
+    tcb: StaticTaskBuffer,
+    stack: [StackType; STACK],
+}
+
+impl<const STACK: usize> StaticThread<STACK> {
+    pub const fn new() -> Self {
+        Self {
+            tcb: StaticTaskBuffer([0; STATIC_TASK_SIZE]),
+            stack: [0; STACK],
+        }
+    }
+
+    /// Starts `entry` with `xTaskCreateStatic`, the thread deletes itself
+    /// when `entry` returns.
+    ///
+    /// Nothing is allocated: the name is copied by the kernel only and the
+    /// returned `Thread` has an empty name.
+    pub fn spawn(&'static mut self, name: &CStr, priority: UBaseType, entry: fn()) -> Result<Thread> {
+        let handle = unsafe {
+            xTaskCreateStatic(
+                Some(static_callback_wrapper),
+                name.as_ptr(),
+                STACK as StackType,
+                entry as *mut c_void,
+                priority,
+                self.stack.as_mut_ptr(),
+                &mut self.tcb,
+            )
+        };
+
+        if handle.is_null() {
+            return Err(Error::NullPtr);
+        }
+
+        Ok(Thread {
+            handle,
+            name: String::new(),
+            stack_depth: STACK as StackType,
+            priority,
+            callback: None,
+            param: None,
+            exit: None,
+        })
+    }
+}
+
+impl<const STACK: usize> Default for StaticThread<STACK> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 
 
 impl ThreadFn for Thread {
@@ -219,7 +474,8 @@ impl ThreadFn for Thread {
             stack_depth, 
             priority, 
             callback: None,
-            param: None 
+            param: None,
+            exit: None,
         }
     }
 
@@ -233,7 +489,8 @@ impl ThreadFn for Thread {
             stack_depth, 
             priority, 
             callback: None,
-            param: None 
+            param: None,
+            exit: None,
         })
     }
 
@@ -263,6 +520,7 @@ impl ThreadFn for Thread {
         
         self.callback = Some(func);
         self.param = param.clone();
+        self.exit = Some(Exit::new()?);
 
         let boxed_thread = Box::new(self.clone());
 
@@ -312,39 +570,50 @@ impl ThreadFn for Thread {
     where
         F: Fn() + Send + Sync + 'static,
     {
-        let func: Arc<ThreadSimpleFnPtr> = Arc::new(callback);
-        let boxed_func = Box::new(func);
-        
-        let mut handle: ThreadHandle = null_mut();
-
-        // Convert name to CString to ensure null termination and proper lifetime
-        let c_name = CString::new(self.name.as_str())
-            .map_err(|_| Error::Unhandled("Failed to convert thread name to CString"))?;
-
-        let ret = unsafe {
-            xTaskCreate(
-                Some(simple_callback_wrapper),
-                c_name.as_ptr(),
-                self.stack_depth,
-                Box::into_raw(boxed_func) as *mut _,
-                self.priority,
-                &mut handle,
-            )
-        };
+        self.create(Box::new(callback))
+    }
 
-        if ret != pdPASS {
-            return Err(Error::OutOfMemory);
-        }
+    fn spawn_joinable<F, R>(&mut self, callback: F) -> Result<JoinHandle<R>>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let result = Arc::new(ResultSlot::new());
+        let slot = Arc::clone(&result);
+        let thread = self.create(Box::new(move || slot.set(callback())))?;
+        Ok(JoinHandle { thread, result })
+    }
 
-        Ok(Self {
-            handle,
-            ..self.clone()
-        })
+    fn spawn_with_param<P, F, R>(&mut self, param: P, callback: F) -> Result<JoinHandle<R>>
+    where
+        P: Send + 'static,
+        F: FnOnce(P) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.spawn_joinable(move || callback(param))
     }
 
+    /// Deletes the task, the joiners of a thread spawned through `Thread`
+    /// are released with an error. A task that already returned from its
+    /// callback has deleted itself and is left alone.
     fn delete(&self) {
-        if !self.handle.is_null() {
-            unsafe { vTaskDelete( self.handle ); } 
+        if self.handle.is_null() {
+            return;
+        }
+        match &self.exit {
+            Some(exit) if exit.finished.load(Ordering::SeqCst) => {}
+            Some(exit) => {
+                // A task deleting itself does not return from vTaskDelete
+                let own = self.handle == unsafe { xTaskGetCurrentTaskHandle() };
+                if own {
+                    exit.cancel();
+                }
+                unsafe { vTaskDelete( self.handle ); }
+                if !own {
+                    exit.cancel();
+                }
+            }
+            None => unsafe { vTaskDelete( self.handle ); }
         }
     }
 
@@ -360,9 +629,21 @@ impl ThreadFn for Thread {
         }
     }
 
+    /// Blocks until the callback has returned, `retval` is left untouched:
+    /// the result of the callback is only delivered by a `JoinHandle`.
+    ///
+    /// The wait has no timeout (`MAX_DELAY`), `JoinHandle::join` takes one.
+    /// Joining does not delete the task, it deleted itself when its callback
+    /// returned. A task removed with `delete` fails at once.
+    ///
+    /// Only threads spawned through `Thread` can be joined: the kernel keeps
+    /// no trace of a deleted task, so a `StaticThread` or a handle wrapped with
+    /// `new_with_handle` fails with `Error::NullPtr`.
     fn join(&self, _retval: DoublePtr) -> Result<i32> {
-        if !self.handle.is_null() {
-            unsafe { vTaskDelete( self.handle ); } 
+        let exit = self.exit.as_ref().ok_or(Error::NullPtr)?;
+        exit.wait(MAX_DELAY.to_ticks());
+        if exit.deleted.load(Ordering::SeqCst) {
+            return Err(Error::Unhandled("Thread deleted"));
         }
         Ok(0)
     }
@@ -385,65 +666,86 @@ impl ThreadFn for Thread {
             priority: metadata.priority,
             callback: None,
             param: None,
+            exit: None,
         }
     }
 
     fn notify(&self, notification: ThreadNotification) -> Result<()> {
-        if self.handle.is_null() {
-            return Err(Error::NullPtr);
-        }
+        self.notify_indexed(tskDEFAULT_INDEX_TO_NOTIFY, notification)
+    }
+
+    fn notify_from_isr(&self, notification: ThreadNotification, higher_priority_task_woken: &mut BaseType) -> Result<()> {
+        self.notify_indexed_from_isr(tskDEFAULT_INDEX_TO_NOTIFY, notification, higher_priority_task_woken)
+    }
+
+    fn wait_notification(&self, bits_to_clear_on_entry: u32, bits_to_clear_on_exit: u32 , timeout_ticks: TickType) -> Result<u32> {
+        self.wait_notification_indexed(tskDEFAULT_INDEX_TO_NOTIFY, bits_to_clear_on_entry, bits_to_clear_on_exit, timeout_ticks)
+    }
+
+    fn notify_indexed(&self, index: UBaseType, notification: ThreadNotification) -> Result<()> {
+        self.notify_and_query(index, notification).map(|_| ())
+    }
+
+    fn notify_indexed_from_isr(&self, index: UBaseType, notification: ThreadNotification, higher_priority_task_woken: &mut BaseType) -> Result<()> {
+        self.check_notification_index(index)?;
 
         let (action, value) = notification.into();
 
-        let ret = xTaskNotify!(
-            self.handle,
-            value,
-            action
-        );
-        
+        let ret = unsafe {
+            xTaskGenericNotifyFromISR(
+                self.handle,
+                index,
+                value,
+                action,
+                null_mut(),
+                higher_priority_task_woken
+            )
+        };
+
         if ret != pdPASS {
             Err(Error::QueueFull)
         } else {
             Ok(())
         }
-
     }
 
-    fn notify_from_isr(&self, notification: ThreadNotification, higher_priority_task_woken: &mut BaseType) -> Result<()> {
-        if self.handle.is_null() {
-            return Err(Error::NullPtr);
-        }
+    fn notify_and_query(&self, index: UBaseType, notification: ThreadNotification) -> Result<u32> {
+        self.check_notification_index(index)?;
 
         let (action, value) = notification.into();
+        let mut previous_value: u32 = 0;
 
-        let ret = xTaskNotifyFromISR!(
-            self.handle,
-            value,
-            action,
-            higher_priority_task_woken
-        );
-
+        let ret = unsafe {
+            xTaskGenericNotify(
+                self.handle,
+                index,
+                value,
+                action,
+                &mut previous_value
+            )
+        };
+        
         if ret != pdPASS {
             Err(Error::QueueFull)
         } else {
-            Ok(())
+            Ok(previous_value)
         }
     }
 
-    fn wait_notification(&self, bits_to_clear_on_entry: u32, bits_to_clear_on_exit: u32 , timeout_ticks: TickType) -> Result<u32> {
-        if self.handle.is_null() {
-            return Err(Error::NullPtr);
-        }
+    fn wait_notification_indexed(&self, index: UBaseType, bits_to_clear_on_entry: u32, bits_to_clear_on_exit: u32, timeout_ticks: TickType) -> Result<u32> {
+        self.check_notification_index(index)?;
 
         let mut notification_value: u32 = 0;
 
-        let ret = xTaskNotifyWait!(
-            bits_to_clear_on_entry,
-            bits_to_clear_on_exit,
-            &mut notification_value,
-            timeout_ticks
-        );
-        
+        let ret = unsafe {
+            xTaskGenericNotifyWait(
+                index,
+                bits_to_clear_on_entry,
+                bits_to_clear_on_exit,
+                &mut notification_value,
+                timeout_ticks
+            )
+        };
 
         if ret == pdTRUE {
             Ok(notification_value)
@@ -452,6 +754,31 @@ impl ThreadFn for Thread {
         }
     }
 
+    fn notify_give(&self, index: UBaseType) -> Result<()> {
+        self.notify_indexed(index, ThreadNotification::Increment)
+    }
+
+    fn notify_give_from_isr(&self, index: UBaseType, higher_priority_task_woken: &mut BaseType) -> Result<()> {
+        self.check_notification_index(index)?;
+        unsafe { vTaskGenericNotifyGiveFromISR(self.handle, index, higher_priority_task_woken) };
+        Ok(())
+    }
+
+    fn notify_take(&self, index: UBaseType, clear_on_exit: bool, timeout_ticks: TickType) -> Result<u32> {
+        self.check_notification_index(index)?;
+
+        let clear_on_exit = if clear_on_exit { pdTRUE } else { pdFALSE };
+        match unsafe { ulTaskGenericNotifyTake(index, clear_on_exit, timeout_ticks) } {
+            0 => Err(Error::Timeout),
+            count => Ok(count),
+        }
+    }
+
+    fn clear_state(&self, index: UBaseType) -> Result<bool> {
+        self.check_notification_index(index)?;
+        Ok(unsafe { xTaskGenericNotifyStateClear(self.handle, index) } == pdTRUE)
+    }
+
 }
 
 
@@ -17,7 +17,7 @@
  *
  ***************************************************************************/
 
-use core::any::Any;
+use core::ffi::{CStr, c_void};
 use core::fmt::{Debug, Display};
 use core::ops::Deref;
 use core::ptr::null_mut;
@@ -30,15 +30,17 @@ use crate::freertos::ffi::pdPASS;
 use crate::to_c_str;
 use crate::traits::{ToTick, TimerParam, TimerFn, TimerFnPtr};
 use crate::utils::{OsalRsBool, Result, Error};
-use super::ffi::{TimerHandle, pvTimerGetTimerID, xTimerCreate, osal_rs_timer_start, osal_rs_timer_change_period, osal_rs_timer_delete, osal_rs_timer_reset, osal_rs_timer_stop};
-use super::types::{TickType};
+use super::ffi::{StaticTimerBuffer, TimerHandle, pvTimerGetTimerID, xTimerCreate, xTimerCreateStatic, xTimerPendFunctionCall, osal_rs_timer_start, osal_rs_timer_change_period, osal_rs_timer_delete, osal_rs_timer_reset, osal_rs_timer_stop};
+use super::types::{TickType, STATIC_TIMER_SIZE};
 
-#[derive(Clone)]
 pub struct Timer {
     pub handle: TimerHandle,
     name: String, 
     callback: Option<Arc<TimerFnPtr>>,
     param: Option<TimerParam>, 
+    /// `false` for the instance kept as timer ID and the one handed to the
+    /// callback, which must not delete the timer when dropped
+    owned: bool,
 }
 
 unsafe impl Send for Timer {}
@@ -88,16 +90,89 @@ extern "C" fn callback_c_wrapper(handle: TimerHandle) {
         pvTimerGetTimerID(handle) 
     };
     
-    let mut timer_instance: Box<Timer> = unsafe { Box::from_raw(param_ptr as *mut _) };
+    // The ID stays owned by the timer, it is read again on the next expiry
+    let timer = unsafe { &*(param_ptr as *const Timer) };
+
+    if let Some(callback) = &timer.callback {
+        let instance = Timer {
+            handle,
+            name: timer.name.clone(),
+            callback: timer.callback.clone(),
+            param: timer.param.clone(),
+            owned: false,
+        };
+        let _ = callback(Box::new(instance), timer.param.clone());
+    }
+}
+
+/// Pended by `delete` behind the delete command, so it runs in the timer task
+/// once no expiry of the timer can read its ID anymore
+extern "C" fn free_timer_id(id: *mut c_void, _: u32) {
+    if !id.is_null() {
+        drop(unsafe { Box::from_raw(id as *mut Timer) });
+    }
+}
+
+extern "C" fn static_callback_wrapper(handle: TimerHandle) {
+
+    if handle.is_null() {
+        return;
+    }
+
+    let param_ptr = unsafe {
+        pvTimerGetTimerID(handle) 
+    };
 
-    timer_instance.as_mut().handle = handle;
+    if param_ptr.is_null() {
+        return;
+    }
 
-    let param_arc: Option<Arc<dyn Any + Send + Sync>> = timer_instance
-        .param
-        .clone();
+    let callback: fn() = unsafe { core::mem::transmute(param_ptr) };
+    callback();
+}
 
-    if let Some(callback) = &timer_instance.callback.clone() {
-        let _ = callback(timer_instance, param_arc);
+/// Storage of a software timer that does not use the FreeRTOS heap
+pub struct StaticTimer(StaticTimerBuffer);
+
+impl StaticTimer {
+    pub const fn new() -> Self {
+        Self (StaticTimerBuffer([0; STATIC_TIMER_SIZE]))
+    }
+
+    /// Creates the timer with `xTimerCreateStatic`, `callback` runs in the
+    /// timer service task on every expiry.
+    ///
+    /// The kernel keeps a pointer to `name`, hence `'static`; the returned
+    /// `Timer` has an empty name and nothing is allocated.
+    pub fn create(&'static mut self, name: &'static CStr, timer_period_in_ticks: TickType, auto_reload: bool, callback: fn()) -> Result<Timer> {
+        let handle = unsafe {
+            xTimerCreateStatic(
+                name.as_ptr(),
+                timer_period_in_ticks,
+                if auto_reload { 1 } else { 0 },
+                callback as *mut c_void,
+                Some(super::timer::static_callback_wrapper),
+                &mut self.0,
+            )
+        };
+
+        if handle.is_null() {
+            Err(Error::NullPtr)
+        } else {
+            Ok(Timer {
+                handle,
+                name: String::new(),
+                callback: None,
+                param: None,
+                owned: true,
+            })
+        }
+    }
+}
+
+impl Default for StaticTimer {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -106,31 +181,53 @@ impl TimerFn for Timer {
     where
         F: Fn(Box<dyn TimerFn>, Option<TimerParam>) -> Result<TimerParam> + Send + Sync + Clone + 'static {
 
-            let mut boxed_timer = Box::new(Self {
+            let callback: Arc<TimerFnPtr> = Arc::new(callback);
+
+            let id = Box::into_raw(Box::new(Self {
                 handle: core::ptr::null_mut(),
                 name: name.to_string(),
-                callback: Some(Arc::new(callback.clone())),
+                callback: Some(callback.clone()),
                 param: param.clone(),
-            });
+                owned: false,
+            }));
 
             let handle = unsafe {
                 xTimerCreate( to_c_str!(name), 
                     timer_period_in_ticks, 
                     if auto_reload { 1 } else { 0 }, 
-                    Box::into_raw(boxed_timer.clone()) as *mut _, 
+                    id as *mut _, 
                     Some(super::timer::callback_c_wrapper)
                 )
             };
 
             if handle.is_null() {
+                drop(unsafe { Box::from_raw(id) });
                 Err(Error::NullPtr)
             } else {
-                boxed_timer.as_mut().handle = handle;
-                Ok(*boxed_timer)
+                Ok(Self {
+                    handle,
+                    name: name.to_string(),
+                    callback: Some(callback),
+                    param,
+                    owned: true,
+                })
             }
 
     }
 
+    fn new_with_param<P, F>(name: &str, timer_period_in_ticks: TickType, auto_reload: bool, param: P, callback: F) -> Result<Self>
+    where
+        P: Send + Sync + 'static,
+        F: Fn(&P) + Send + Sync + 'static {
+
+            let param = Arc::new(param);
+            let callback = Arc::new(callback);
+            Self::new(name, timer_period_in_ticks, auto_reload, None, move |_timer, _param| {
+                callback(&param);
+                Ok(param.clone() as TimerParam)
+            })
+    }
+
     fn start(&self, ticks_to_wait: TickType) -> OsalRsBool {
         if unsafe {
             osal_rs_timer_start(self.handle, ticks_to_wait)
@@ -171,22 +268,56 @@ impl TimerFn for Timer {
         }
     }
 
+    /// The delete is only queued to the timer task, an expiry processed
+    /// before it still reads the timer ID. The ID of a timer created with
+    /// `new` is therefore freed by the timer task after the delete, it is
+    /// leaked if that call cannot be queued (`INCLUDE_xTimerPendFunctionCall`).
+    ///
+    /// A timer whose delete could not be queued keeps running.
     fn delete(&mut self, ticks_to_wait: TickType) -> OsalRsBool {
+        if self.handle.is_null() {
+            return OsalRsBool::False;
+        }
+
+        let id = if self.owned && self.callback.is_some() {
+            unsafe { pvTimerGetTimerID(self.handle) }
+        } else {
+            null_mut()
+        };
+
         if unsafe {
             osal_rs_timer_delete(self.handle, ticks_to_wait)
         } != pdPASS {
-            self.handle = null_mut();
-            OsalRsBool::False
-        } else {
-            self.handle = null_mut();
-            OsalRsBool::True
+            return OsalRsBool::False;
+        }
+        self.handle = null_mut();
+
+        if !id.is_null() {
+            unsafe { xTimerPendFunctionCall(Some(free_timer_id), id, 0, ticks_to_wait) };
+        }
+        OsalRsBool::True
+    }
+}
+
+/// Clones share the timer but never delete it, only the instance returned by
+/// `new` does, so a clone must not be used once that instance is dropped
+impl Clone for Timer {
+    fn clone(&self) -> Self {
+        Self {
+            handle: self.handle,
+            name: self.name.clone(),
+            callback: self.callback.clone(),
+            param: self.param.clone(),
+            owned: false,
         }
     }
 }
 
 impl Drop for Timer {
     fn drop(&mut self) {
-        self.delete(0);
+        if self.owned {
+            self.delete(0);
+        }
     }
 }
 
@@ -19,6 +19,6 @@
 
 include!(concat!(env!("OUT_DIR"), "/types_generated.rs"));    
 
-pub use super::ffi::{ThreadHandle, QueueHandle, SemaphoreHandle, EventGroupHandle, TimerHandle, MutexHandle};
+pub use super::ffi::{ThreadHandle, QueueHandle, SemaphoreHandle, EventGroupHandle, TimerHandle, MutexHandle, QueueSetHandle, QueueSetMemberHandle, StreamBufferHandle, MessageBufferHandle};
 
 pub type EventBits = TickType;
@@ -17,7 +17,7 @@
  *
  ***************************************************************************/
 
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(any(feature = "std", feature = "mock")), no_std)]
 
 // Suppress warnings from FreeRTOS FFI bindings being included in multiple modules
 #![allow(clashing_extern_declarations)]
@@ -25,6 +25,9 @@
 #![allow(unused_imports)]
 extern crate alloc;
 
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
 #[cfg(feature = "freertos")]
 mod freertos;
 
@@ -56,19 +59,42 @@ pub mod os {
     
     pub use crate::osal::duration::*;
     pub use crate::osal::event_group::*;
+    pub use crate::osal::message_buffer::*;
+    #[cfg(feature = "posix")]
+    pub use crate::osal::isr::*;
     pub use crate::osal::mutex::*;
+    #[cfg(feature = "posix")]
+    pub use crate::osal::named_queue::*;
     pub use crate::osal::queue::*;
+    pub use crate::osal::queue_set::*;
     pub use crate::osal::semaphore::*;
+    pub use crate::osal::stream_buffer::*;
     pub use crate::osal::system::*;
     pub use crate::osal::thread::*;
     pub use crate::osal::timer::*;
     pub use crate::traits::*;
     pub use crate::osal::config as config;
     pub use crate::osal::types as types;
+    #[cfg(feature = "virtual_time")]
+    pub use crate::osal::virtual_time::VirtualTime;
+    #[cfg(feature = "mock")]
+    pub use crate::osal::mock;
     
 }
 
 
+/// Called by the panic handler of osal-rs once the message is printed, e.g.
+/// by a test harness to record the failure and park the panicking thread
+/// instead of halting the whole system
+pub type PanicHook = fn(&core::panic::PanicInfo);
+
+static PANIC_HOOK: AtomicPtr<()> = AtomicPtr::new(null_mut());
+
+/// Installs the hook run on panic, `None` restores the default behaviour
+pub fn set_panic_hook(hook: Option<PanicHook>) {
+    PANIC_HOOK.store(hook.map_or(null_mut(), |hook| hook as *mut ()), Ordering::SeqCst);
+}
+
 // Panic handler for no_std library - only when building as final binary
 // Examples with std will provide their own
 #[cfg(not(feature = "disable_panic"))]
@@ -76,6 +102,13 @@ pub mod os {
 
 fn panic(info: &core::panic::PanicInfo) -> ! {
     println!("Panic occurred: {}", info);
+
+    let hook = PANIC_HOOK.load(Ordering::SeqCst);
+    if !hook.is_null() {
+        let hook = unsafe { core::mem::transmute::<*mut (), PanicHook>(hook) };
+        hook(info);
+    }
+
     #[allow(clippy::empty_loop)]
     loop {}
 }
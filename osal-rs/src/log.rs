@@ -17,11 +17,23 @@
  *
  ***************************************************************************/
 
-#[cfg(not(feature = "std"))]
+#[cfg(all(not(feature = "std"), feature = "freertos", not(feature = "mock")))]
+pub mod ffi {
+    use core::ffi::{c_char, c_int};
+
+    unsafe extern "C" {
+        pub fn printf_on_uart(format: *const c_char, ...) -> c_int;
+
+    }
+}
+
+#[cfg(all(not(feature = "std"), any(feature = "posix", feature = "mock")))]
 pub mod ffi {
     use core::ffi::{c_char, c_int};
 
     unsafe extern "C" {
+        /// On the host the console is the process standard output
+        #[link_name = "printf"]
         pub fn printf_on_uart(format: *const c_char, ...) -> c_int;
 
     }
@@ -17,7 +17,22 @@
  *
  ***************************************************************************/
 
-mod posix_allocator;
-
-#[global_allocator]
-static ALLOCATOR: PosixAllocator = PosixAllocator;
\ No newline at end of file
+pub mod allocator;
+pub mod config;
+pub mod duration;
+pub mod event_group;
+pub mod message_buffer;
+mod ffi;
+pub mod isr;
+pub mod mutex;
+pub mod named_queue;
+pub mod queue;
+pub mod queue_set;
+pub mod semaphore;
+pub mod stream_buffer;
+mod sync;
+pub mod system;
+pub mod thread;
+pub mod timer;
+pub mod types;
+pub mod virtual_time;
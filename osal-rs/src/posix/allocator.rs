@@ -18,22 +18,46 @@
  ***************************************************************************/
 
 use core::{alloc::{GlobalAlloc, Layout}, ffi::c_void};
+use core::ptr::null_mut;
 
-use crate::posix::ffi::{free, malloc, realloc};
+use crate::posix::ffi::{free, malloc, posix_memalign, realloc};
+use crate::posix::thread::{hold_suspend, release_suspend};
 
-pub struct POSIXAllocator;
+/// Alignment guaranteed by `malloc` on every supported Linux target
+const MALLOC_ALIGN: usize = 2 * size_of::<usize>();
 
-unsafe impl GlobalAlloc for POSIXAllocator {
+pub struct Allocator;
+
+/// Runs a libc heap call without being suspended: an interrupt handler that
+/// allocates would otherwise wait on the heap lock of a stopped thread
+fn unsuspended<R>(f: impl FnOnce() -> R) -> R {
+    hold_suspend();
+    let ret = f();
+    release_suspend();
+    ret
+}
+
+unsafe impl GlobalAlloc for Allocator {
     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
-        unsafe { 
-            malloc(layout.size() as u64) as *mut u8 
-        }
+        unsuspended(|| {
+            if layout.align() <= MALLOC_ALIGN {
+                unsafe { 
+                    malloc(layout.size()) as *mut u8 
+                }
+            } else {
+                let mut ptr: *mut c_void = null_mut();
+                if unsafe { posix_memalign(&mut ptr, layout.align(), layout.size()) } != 0 {
+                    return null_mut();
+                }
+                ptr as *mut u8
+            }
+        })
     }
 
     unsafe fn dealloc(&self, ptr: *mut u8, _layout: Layout) {
-        unsafe {
+        unsuspended(|| unsafe {
             free(ptr as *mut c_void);
-        }
+        })
     }
 
     unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
@@ -47,13 +71,20 @@ unsafe impl GlobalAlloc for POSIXAllocator {
     }
 
     unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
-        let new_ptr = unsafe { realloc(ptr as *mut c_void, new_size as u64) as *mut u8 };
+        if layout.align() <= MALLOC_ALIGN {
+            // realloc already moves the content and releases the old block
+            return unsuspended(|| unsafe { realloc(ptr as *mut c_void, new_size) as *mut u8 });
+        }
+
+        let new_layout = unsafe { Layout::from_size_align_unchecked(new_size, layout.align()) };
+        let new_ptr = unsafe { self.alloc(new_layout) };
 
         unsafe {
             if !new_ptr.is_null() && !ptr.is_null() {
                 core::ptr::copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+                self.dealloc(ptr, layout);
             }
         }
         new_ptr
     }
-}
\ No newline at end of file
+}
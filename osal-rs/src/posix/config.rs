@@ -0,0 +1,176 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::ffi::c_int;
+use core::sync::atomic::{AtomicBool, AtomicI32, AtomicU32, Ordering};
+
+use super::ffi::{SIGKILL, SIGSTOP, SIGUSR1, SIGUSR2, __libc_current_sigrtmax, __libc_current_sigrtmin};
+use crate::os::types::{StackType, TickType};
+use crate::utils::{Error, Result};
+
+/// Nominal CPU clock exposed to the application, the host clock is not queried
+pub const CPU_CLOCK_HZ: u64 = 1_000_000_000;
+
+/// Default frequency of the emulated tick
+pub const TICK_RATE_HZ: TickType = 1_000;
+
+static TICK_RATE: AtomicU32 = AtomicU32::new(TICK_RATE_HZ);
+
+/// Number of priority levels accepted by `Thread`
+pub const MAX_PRIORITIES: u32 = 32;
+
+/// Minimal stack depth in words, as in `configMINIMAL_STACK_SIZE`
+pub const MINIMAL_STACK_SIZE: StackType = 1_024;
+
+/// Maximum thread name length, including the terminating null
+pub const MAX_TASK_NAME_LEN: u32 = 16;
+
+/// Notification values of each thread, as in `configTASK_NOTIFICATION_ARRAY_ENTRIES`
+pub const TASK_NOTIFICATION_ARRAY_ENTRIES: u32 = 3;
+
+/// Priority of the timer service thread, as in `configTIMER_TASK_PRIORITY`
+pub const TIMER_TASK_PRIORITY: u32 = MAX_PRIORITIES - 1;
+
+/// Number of pending timer commands, as in `configTIMER_QUEUE_LENGTH`
+pub const TIMER_QUEUE_LENGTH: usize = 10;
+
+/// Stack depth of the timer service thread, as in `configTIMER_TASK_STACK_DEPTH`
+pub const TIMER_TASK_STACK_DEPTH: StackType = MINIMAL_STACK_SIZE * 2;
+
+/// Frequency of the emulated tick currently in use
+#[inline]
+pub fn tick_rate_hz() -> TickType {
+    TICK_RATE.load(Ordering::Relaxed)
+}
+
+/// Changes the frequency of the emulated tick, from 1 Hz up to 1 GHz.
+///
+/// The tick count is derived from the time elapsed since the first timing
+/// call, so the rate should be set once at startup, before any delay.
+pub fn set_tick_rate_hz(rate: TickType) -> Result<()> {
+    if rate == 0 || rate as u64 > CPU_CLOCK_HZ {
+        return Err(Error::Unhandled("Invalid tick rate"));
+    }
+    TICK_RATE.store(rate, Ordering::Relaxed);
+    Ok(())
+}
+
+static SUSPEND_SIGNAL: AtomicI32 = AtomicI32::new(SIGUSR1);
+
+static RESUME_SIGNAL: AtomicI32 = AtomicI32::new(SIGUSR2);
+
+/// Set when the first thread is handled, the signals cannot change afterwards
+static SIGNALS_IN_USE: AtomicBool = AtomicBool::new(false);
+
+/// Signal that parks a thread on `Thread::suspend` and while an interrupt
+/// handler runs, `SIGUSR1` by default
+#[inline]
+pub fn suspend_signal() -> c_int {
+    SUSPEND_SIGNAL.load(Ordering::Relaxed)
+}
+
+/// Signal that wakes a parked thread up, `SIGUSR2` by default
+#[inline]
+pub fn resume_signal() -> c_int {
+    RESUME_SIGNAL.load(Ordering::Relaxed)
+}
+
+/// First real-time signal free for the application, as `SIGRTMIN`
+pub fn sigrtmin() -> c_int {
+    unsafe { __libc_current_sigrtmin() }
+}
+
+/// Last real-time signal, as `SIGRTMAX`
+pub fn sigrtmax() -> c_int {
+    unsafe { __libc_current_sigrtmax() }
+}
+
+/// Changes the signals reserved by `Thread`, e.g. to `sigrtmin() + n` when
+/// the application needs `SIGUSR1` and `SIGUSR2`.
+///
+/// The handlers are installed on the first use of a thread, the signals
+/// must be set before: afterwards the call fails.
+pub fn set_thread_signals(suspend: c_int, resume: c_int) -> Result<()> {
+    let valid = |signum: c_int| signum > 0 && signum <= sigrtmax() && signum != SIGKILL && signum != SIGSTOP;
+    if !valid(suspend) || !valid(resume) || suspend == resume {
+        return Err(Error::Unhandled("Invalid thread signals"));
+    }
+    if SIGNALS_IN_USE.load(Ordering::SeqCst) {
+        return Err(Error::Unhandled("Thread signals already in use"));
+    }
+    SUSPEND_SIGNAL.store(suspend, Ordering::Relaxed);
+    RESUME_SIGNAL.store(resume, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Freezes the thread signals, returns the suspend and the resume one
+pub(crate) fn claim_thread_signals() -> (c_int, c_int) {
+    SIGNALS_IN_USE.store(true, Ordering::SeqCst);
+    (suspend_signal(), resume_signal())
+}
+
+#[macro_export]
+macro_rules! tick_period_ms {
+    () => {
+        ($crate::os::config::tick_rate_hz())
+    };
+}
+
+#[macro_export]
+macro_rules! tick_rate_hz {
+    () => {
+        ($crate::os::config::tick_rate_hz())
+    };
+}
+
+
+#[macro_export]
+macro_rules! cpu_clock_hz {
+    () => {
+        ($crate::os::config::CPU_CLOCK_HZ)
+    };
+}
+
+#[macro_export]
+macro_rules! max_priorities {
+    () => {
+        ($crate::os::config::MAX_PRIORITIES)
+    };
+}
+
+#[macro_export]
+macro_rules! minimal_stack_size {
+    () => {
+        ($crate::os::config::MINIMAL_STACK_SIZE)
+    };
+}   
+
+#[macro_export]
+macro_rules! max_task_name_len {
+    () => {
+        ($crate::os::config::MAX_TASK_NAME_LEN)
+    };
+}
+
+#[macro_export]
+macro_rules! task_notification_array_entries {
+    () => {
+        ($crate::os::config::TASK_NOTIFICATION_ARRAY_ENTRIES)
+    };
+}
@@ -0,0 +1,258 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::fmt::{Debug, Display, Formatter};
+use core::ops::Deref;
+use core::ptr::null_mut;
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use super::ffi::EventGroupHandle;
+use super::sync::{Monitor, MonitorGuard};
+use super::system::System;
+use super::types::{BaseType, EventBits, TickType};
+use crate::traits::{ToTick, EventGroupFn, EventWaitOptions, SystemFn};
+use crate::utils::{Error, Result};
+
+/// Top byte reserved by FreeRTOS for its own control flags, never stored
+const EVENT_BITS_CONTROL_BYTES: EventBits = 0xff00_0000;
+
+/// Thread blocked on the group, `released` holds the bits that met its
+/// condition
+struct Waiter {
+    id: u64,
+    mask: EventBits,
+    options: EventWaitOptions,
+    released: Option<EventBits>,
+}
+
+/// Bits of the group and the threads waiting on them.
+///
+/// As in FreeRTOS the waiters are released by the thread that sets the bits,
+/// which then clears the bits they consume: every waiter satisfied by the
+/// same set sees the bits before any of them is cleared.
+struct Events {
+    bits: EventBits,
+    waiters: Vec<Waiter>,
+    next_id: u64,
+}
+
+impl Events {
+    /// Releases the waiters whose condition is met, then clears the bits
+    /// they consume. Returns whether a waiter was released
+    fn release(&mut self) -> bool {
+        let bits = self.bits;
+        let mut clear = 0;
+        let mut released = false;
+        for waiter in self.waiters.iter_mut().filter(|waiter| waiter.released.is_none()) {
+            if waiter.options.is_met(waiter.mask, bits) {
+                waiter.released = Some(bits);
+                if waiter.options.clear_on_exit {
+                    clear |= waiter.mask;
+                }
+                released = true;
+            }
+        }
+        self.bits &= !clear;
+        released
+    }
+}
+
+/// Event flags, the handle points to the `Monitor` holding the bits
+pub struct EventGroup (EventGroupHandle);
+
+unsafe impl Send for EventGroup {}
+unsafe impl Sync for EventGroup {}
+
+impl EventGroup {
+    pub fn wait_with_to_tick(&self, mask: EventBits, timeout_ticks: impl ToTick) -> EventBits {
+        self.wait(mask, timeout_ticks.to_ticks())
+    }
+
+    #[inline]
+    fn inner(&self) -> &Monitor<Events> {
+        unsafe { &*(self.0 as *const Monitor<Events>) }
+    }
+
+    /// Returns the bits left once the released waiters consumed theirs, and
+    /// whether a waiting thread was woken
+    fn set_bits(&self, bits: EventBits) -> (EventBits, bool) {
+        let mut events = self.inner().lock();
+        events.bits |= bits & !EVENT_BITS_CONTROL_BYTES;
+        let released = events.release();
+        if released {
+            events.notify_all();
+        }
+        (events.bits, released)
+    }
+
+    /// Same contract as `xEventGroupWaitBits`: returns the bits as they were
+    /// when the wait was satisfied or timed out, before any clear on exit,
+    /// and whether it was satisfied
+    fn wait_condition(&self, mask: EventBits, options: EventWaitOptions, timeout_ticks: TickType) -> (EventBits, bool) {
+        let mask = mask & !EVENT_BITS_CONTROL_BYTES;
+        let mut events = self.inner().lock();
+        let bits = events.bits;
+        if options.is_met(mask, bits) {
+            if options.clear_on_exit {
+                events.bits &= !mask;
+            }
+            return (bits, true);
+        }
+        if mask == 0 {
+            return (bits, false);
+        }
+
+        Self::block(&mut events, mask, options, timeout_ticks)
+    }
+
+    /// Same contract as `xEventGroupSync`
+    fn sync_bits(&self, set_bits: EventBits, wait_bits: EventBits, timeout_ticks: TickType) -> (EventBits, bool) {
+        let set_bits = set_bits & !EVENT_BITS_CONTROL_BYTES;
+        let wait_bits = wait_bits & !EVENT_BITS_CONTROL_BYTES;
+        let mut events = self.inner().lock();
+
+        let bits = events.bits | set_bits;
+        events.bits = bits;
+        if events.release() {
+            events.notify_all();
+        }
+
+        // The last party to arrive releases the others and clears the bits
+        if bits & wait_bits == wait_bits {
+            events.bits &= !wait_bits;
+            return (bits, true);
+        }
+
+        Self::block(&mut events, wait_bits, EventWaitOptions::ALL.clear_on_exit(), timeout_ticks)
+    }
+
+    /// Queues the calling thread as a waiter until a set releases it or the
+    /// timeout expires
+    fn block(events: &mut MonitorGuard<'_, Events>, mask: EventBits, options: EventWaitOptions, timeout_ticks: TickType) -> (EventBits, bool) {
+        let id = events.next_id;
+        events.next_id += 1;
+        events.waiters.push(Waiter { id, mask, options, released: None });
+
+        events.wait_while(timeout_ticks, |events| {
+            events.waiters.iter().any(|waiter| waiter.id == id && waiter.released.is_none())
+        });
+
+        let index = events.waiters.iter().position(|waiter| waiter.id == id).unwrap();
+        let waiter = events.waiters.swap_remove(index);
+        match waiter.released {
+            Some(bits) => (bits, true),
+            None => (events.bits, false),
+        }
+    }
+}
+
+impl EventGroupFn for EventGroup {
+    fn new() -> Result<Self> {
+        let monitor = Monitor::new(Events { bits: 0, waiters: Vec::new(), next_id: 0 })?;
+        Ok(Self (Box::into_raw(Box::new(monitor)) as EventGroupHandle))
+    }
+
+    #[inline]
+    fn set(&self, bits: EventBits) -> EventBits {
+        self.set_bits(bits).0
+    }
+
+    /// Sets the bits directly, there is no timer daemon to defer the call to.
+    /// Requests a context switch if a waiting thread was woken.
+    fn set_from_isr(&self, bits: EventBits) -> Result<()> {
+        let (_, woken) = self.set_bits(bits);
+        System::yield_from_isr(woken as BaseType);
+        Ok(())
+    }
+
+    fn get(&self) -> EventBits {
+        self.inner().lock().bits
+    }
+
+    fn get_from_isr(&self) -> EventBits{
+        self.get()
+    }
+
+    /// Returns the bits as they were before clearing, as `xEventGroupClearBits`
+    fn clear(&self, bits: EventBits) -> EventBits {
+        let mut events = self.inner().lock();
+        let ret = events.bits;
+        events.bits &= !bits;
+        ret
+    }
+
+    fn clear_from_isr(&self, bits: EventBits) -> Result<()> {
+        self.clear(bits);
+        Ok(())
+    }
+
+    fn wait(&self, mask: EventBits, timeout_ticks: TickType) -> EventBits {
+        self.wait_condition(mask, EventWaitOptions::ANY, timeout_ticks).0
+    }
+
+    fn wait_bits(&self, mask: EventBits, options: EventWaitOptions, timeout: impl ToTick) -> Result<EventBits> {
+        match self.wait_condition(mask, options, timeout.to_ticks()) {
+            (bits, true) => Ok(bits),
+            (_, false) => Err(Error::Timeout),
+        }
+    }
+
+    fn sync(&self, set_bits: EventBits, wait_bits: EventBits, timeout: impl ToTick) -> Result<EventBits> {
+        match self.sync_bits(set_bits, wait_bits, timeout.to_ticks()) {
+            (bits, true) => Ok(bits),
+            (_, false) => Err(Error::Timeout),
+        }
+    }
+
+    fn delete(&mut self) {
+        drop(unsafe { Box::from_raw(self.0 as *mut Monitor<Events>) });
+        self.0 = null_mut();
+    }
+}
+
+impl Drop for EventGroup {
+    fn drop(&mut self) {
+        if self.0.is_null() {
+            return;
+        }
+        self.delete();
+    }
+}
+
+impl Deref for EventGroup {
+    type Target = EventGroupHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for EventGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "EventGroup {{ handle: {:?} }}", self.0)
+    }
+}
+
+impl Display for EventGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "EventGroup {{ handle: {:?} }}", self.0)
+    }
+}
@@ -0,0 +1,344 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+#![allow(non_camel_case_types)]
+#![allow(non_upper_case_globals)]
+
+//! Minimal libc bindings used by the POSIX backend.
+//!
+//! Constants and opaque type sizes target Linux (glibc and musl). The opaque
+//! pthread types are over-sized on purpose so that they are large enough on
+//! every supported architecture: they are only ever handled by pointer.
+
+use core::ffi::{c_char, c_int, c_long, c_uint, c_ulong, c_void};
+
+pub type ThreadHandle = *const c_void;
+pub type QueueHandle = *const c_void;
+pub type SemaphoreHandle = *const c_void;
+pub type EventGroupHandle = *const c_void;
+pub type TimerHandle = *const c_void;
+pub type MutexHandle = *const c_void;
+pub type QueueSetHandle = *const c_void;
+pub type QueueSetMemberHandle = *const c_void;
+pub type StreamBufferHandle = *const c_void;
+pub type MessageBufferHandle = *const c_void;
+
+pub type mqd_t = c_int;
+pub type mode_t = c_uint;
+pub type pid_t = c_int;
+pub type pthread_t = c_ulong;
+pub type pthread_key_t = c_uint;
+pub type pthread_once_t = c_int;
+pub type clockid_t = c_int;
+pub type time_t = c_long;
+pub type sighandler_t = usize;
+pub type ThreadStartRoutine = extern "C" fn(arg: *mut c_void) -> *mut c_void;
+pub type KeyDestructor = Option<unsafe extern "C" fn(value: *mut c_void)>;
+pub type SignalHandler = extern "C" fn(signum: c_int);
+
+#[repr(C, align(8))]
+pub struct pthread_attr_t {
+    _data: [u8; 64],
+}
+
+#[repr(C, align(8))]
+pub struct pthread_mutex_t {
+    _data: [u8; 64],
+}
+
+#[repr(C, align(8))]
+pub struct pthread_mutexattr_t {
+    _data: [u8; 8],
+}
+
+#[repr(C, align(8))]
+pub struct pthread_cond_t {
+    _data: [u8; 64],
+}
+
+#[repr(C, align(8))]
+pub struct pthread_condattr_t {
+    _data: [u8; 8],
+}
+
+#[repr(C, align(8))]
+#[derive(Copy, Clone)]
+pub struct sigset_t {
+    _data: [u8; 128],
+}
+
+/// Layout shared by glibc and musl on Linux
+#[repr(C)]
+pub struct sigaction {
+    pub sa_handler: sighandler_t,
+    pub sa_mask: sigset_t,
+    pub sa_flags: c_int,
+    pub sa_restorer: usize,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct mq_attr {
+    pub mq_flags: c_long,
+    pub mq_maxmsg: c_long,
+    pub mq_msgsize: c_long,
+    pub mq_curmsgs: c_long,
+    pub _reserved: [c_long; 4],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct timespec {
+    pub tv_sec: time_t,
+    pub tv_nsec: c_long,
+}
+
+impl pthread_attr_t {
+    pub const fn new() -> Self {
+        Self { _data: [0; 64] }
+    }
+}
+
+impl pthread_mutex_t {
+    /// Equivalent of `PTHREAD_MUTEX_INITIALIZER` (all zeroes on glibc and musl)
+    pub const fn new() -> Self {
+        Self { _data: [0; 64] }
+    }
+}
+
+impl pthread_mutexattr_t {
+    pub const fn new() -> Self {
+        Self { _data: [0; 8] }
+    }
+}
+
+impl pthread_cond_t {
+    /// Equivalent of `PTHREAD_COND_INITIALIZER` (all zeroes on glibc and musl)
+    pub const fn new() -> Self {
+        Self { _data: [0; 64] }
+    }
+}
+
+impl pthread_condattr_t {
+    pub const fn new() -> Self {
+        Self { _data: [0; 8] }
+    }
+}
+
+impl sigset_t {
+    pub const fn new() -> Self {
+        Self { _data: [0; 128] }
+    }
+}
+
+pub const PTHREAD_ONCE_INIT: pthread_once_t = 0;
+
+pub const PTHREAD_MUTEX_RECURSIVE: c_int = 1;
+
+pub const CLOCK_REALTIME: clockid_t = 0;
+
+pub const CLOCK_MONOTONIC: clockid_t = 1;
+
+pub const TIMER_ABSTIME: c_int = 1;
+
+pub const SIGKILL: c_int = 9;
+
+pub const SIGUSR1: c_int = 10;
+
+pub const SIGUSR2: c_int = 12;
+
+pub const SIGSTOP: c_int = 19;
+
+pub const SA_RESTART: c_int = 0x1000_0000;
+
+pub const SIG_BLOCK: c_int = 0;
+
+pub const SIG_UNBLOCK: c_int = 1;
+
+pub const SIG_SETMASK: c_int = 2;
+
+pub const O_RDWR: c_int = 0o2;
+
+pub const O_CREAT: c_int = 0o100;
+
+pub const O_EXCL: c_int = 0o200;
+
+pub const ENOENT: c_int = 2;
+
+pub const EAGAIN: c_int = 11;
+
+pub const ENOMEM: c_int = 12;
+
+pub const EACCES: c_int = 13;
+
+pub const EBUSY: c_int = 16;
+
+pub const EINTR: c_int = 4;
+
+pub const EEXIST: c_int = 17;
+
+pub const EINVAL: c_int = 22;
+
+pub const ENFILE: c_int = 23;
+
+pub const EMFILE: c_int = 24;
+
+pub const ENOSPC: c_int = 28;
+
+pub const EMSGSIZE: c_int = 90;
+
+pub const ETIMEDOUT: c_int = 110;
+
+pub const NSEC_PER_SEC: c_long = 1_000_000_000;
+
+#[link(name = "c")]
+unsafe extern "C" {
+
+    pub fn malloc(size: usize) -> *mut c_void;
+
+    pub fn free(ptr: *mut c_void);
+
+    pub fn realloc(ptr: *mut c_void, size: usize) -> *mut c_void;
+
+    pub fn posix_memalign(memptr: *mut *mut c_void, alignment: usize, size: usize) -> c_int;
+
+    pub fn printf(format: *const c_char, ...) -> c_int;
+
+    pub fn clock_gettime(clockid: clockid_t, tp: *mut timespec) -> c_int;
+
+    pub fn nanosleep(req: *const timespec, rem: *mut timespec) -> c_int;
+
+    pub fn clock_nanosleep(clockid: clockid_t, flags: c_int, req: *const timespec, rem: *mut timespec) -> c_int;
+
+    pub fn sched_yield() -> c_int;
+
+    pub fn getpid() -> pid_t;
+
+    pub fn __errno_location() -> *mut c_int;
+
+    pub fn pthread_create(
+        thread: *mut pthread_t,
+        attr: *const pthread_attr_t,
+        start_routine: ThreadStartRoutine,
+        arg: *mut c_void,
+    ) -> c_int;
+
+    pub fn pthread_join(thread: pthread_t, retval: *mut *mut c_void) -> c_int;
+
+    pub fn pthread_detach(thread: pthread_t) -> c_int;
+
+    pub fn pthread_self() -> pthread_t;
+
+    pub fn pthread_equal(t1: pthread_t, t2: pthread_t) -> c_int;
+
+    pub fn pthread_kill(thread: pthread_t, sig: c_int) -> c_int;
+
+    pub fn pthread_getname_np(thread: pthread_t, name: *mut c_char, len: usize) -> c_int;
+
+    pub fn pthread_setname_np(thread: pthread_t, name: *const c_char) -> c_int;
+
+    pub fn pthread_attr_init(attr: *mut pthread_attr_t) -> c_int;
+
+    pub fn pthread_attr_destroy(attr: *mut pthread_attr_t) -> c_int;
+
+    pub fn pthread_attr_setstacksize(attr: *mut pthread_attr_t, stacksize: usize) -> c_int;
+
+    pub fn pthread_once(once_control: *mut pthread_once_t, init_routine: extern "C" fn()) -> c_int;
+
+    pub fn pthread_key_create(key: *mut pthread_key_t, destructor: KeyDestructor) -> c_int;
+
+    pub fn pthread_getspecific(key: pthread_key_t) -> *mut c_void;
+
+    pub fn pthread_setspecific(key: pthread_key_t, value: *const c_void) -> c_int;
+
+    pub fn pthread_mutexattr_init(attr: *mut pthread_mutexattr_t) -> c_int;
+
+    pub fn pthread_mutexattr_destroy(attr: *mut pthread_mutexattr_t) -> c_int;
+
+    pub fn pthread_mutexattr_settype(attr: *mut pthread_mutexattr_t, kind: c_int) -> c_int;
+
+    pub fn pthread_mutex_init(mutex: *mut pthread_mutex_t, attr: *const pthread_mutexattr_t) -> c_int;
+
+    pub fn pthread_mutex_destroy(mutex: *mut pthread_mutex_t) -> c_int;
+
+    pub fn pthread_mutex_lock(mutex: *mut pthread_mutex_t) -> c_int;
+
+    pub fn pthread_mutex_trylock(mutex: *mut pthread_mutex_t) -> c_int;
+
+    pub fn pthread_mutex_timedlock(mutex: *mut pthread_mutex_t, abstime: *const timespec) -> c_int;
+
+    pub fn pthread_mutex_unlock(mutex: *mut pthread_mutex_t) -> c_int;
+
+    pub fn pthread_condattr_init(attr: *mut pthread_condattr_t) -> c_int;
+
+    pub fn pthread_condattr_destroy(attr: *mut pthread_condattr_t) -> c_int;
+
+    pub fn pthread_condattr_setclock(attr: *mut pthread_condattr_t, clock_id: clockid_t) -> c_int;
+
+    pub fn pthread_cond_init(cond: *mut pthread_cond_t, attr: *const pthread_condattr_t) -> c_int;
+
+    pub fn pthread_cond_destroy(cond: *mut pthread_cond_t) -> c_int;
+
+    pub fn pthread_cond_wait(cond: *mut pthread_cond_t, mutex: *mut pthread_mutex_t) -> c_int;
+
+    pub fn pthread_cond_timedwait(cond: *mut pthread_cond_t, mutex: *mut pthread_mutex_t, abstime: *const timespec) -> c_int;
+
+    pub fn pthread_cond_signal(cond: *mut pthread_cond_t) -> c_int;
+
+    pub fn pthread_cond_broadcast(cond: *mut pthread_cond_t) -> c_int;
+
+    pub fn sigaction(signum: c_int, act: *const sigaction, oldact: *mut sigaction) -> c_int;
+
+    /// `SIGRTMIN` and `SIGRTMAX` are functions of the C library: the first
+    /// real-time signals are taken by its thread implementation
+    pub fn __libc_current_sigrtmin() -> c_int;
+
+    pub fn __libc_current_sigrtmax() -> c_int;
+
+    pub fn sigemptyset(set: *mut sigset_t) -> c_int;
+
+    pub fn sigaddset(set: *mut sigset_t, signum: c_int) -> c_int;
+
+    pub fn sigdelset(set: *mut sigset_t, signum: c_int) -> c_int;
+
+    pub fn sigsuspend(mask: *const sigset_t) -> c_int;
+
+    pub fn pthread_sigmask(how: c_int, set: *const sigset_t, oldset: *mut sigset_t) -> c_int;
+}
+
+// Part of libc since glibc 2.34, librt is kept as an empty stub for compatibility
+#[link(name = "rt")]
+unsafe extern "C" {
+
+    pub fn mq_open(name: *const c_char, oflag: c_int, ...) -> mqd_t;
+
+    pub fn mq_close(mqdes: mqd_t) -> c_int;
+
+    pub fn mq_unlink(name: *const c_char) -> c_int;
+
+    pub fn mq_getattr(mqdes: mqd_t, attr: *mut mq_attr) -> c_int;
+
+    pub fn mq_send(mqdes: mqd_t, msg_ptr: *const c_char, msg_len: usize, msg_prio: c_uint) -> c_int;
+
+    pub fn mq_timedsend(mqdes: mqd_t, msg_ptr: *const c_char, msg_len: usize, msg_prio: c_uint, abstime: *const timespec) -> c_int;
+
+    pub fn mq_receive(mqdes: mqd_t, msg_ptr: *mut c_char, msg_len: usize, msg_prio: *mut c_uint) -> isize;
+
+    pub fn mq_timedreceive(mqdes: mqd_t, msg_ptr: *mut c_char, msg_len: usize, msg_prio: *mut c_uint, abstime: *const timespec) -> isize;
+}
@@ -0,0 +1,269 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+//! Host-side interrupt simulator.
+//!
+//! There are no interrupts on the host: each `Interrupt` is a software line
+//! whose handler runs on a dedicated interrupt thread. Pending lines are
+//! served lowest number first, as with the NVIC, and one at a time. Critical
+//! sections mask interrupts: a handler never runs while a thread is inside
+//! `System::critical_section_enter`.
+//!
+//! A handler preempts the application: every thread spawned through osal-rs
+//! is stopped wherever it is before the handler runs, and restarted once it
+//! returns, so data shared with a handler sees the same atomicity as on
+//! target. As the kernel masks interrupts around its own work, a thread inside
+//! osal-rs (including its heap calls) stops only when it leaves it, so the
+//! handler never waits on a lock of a stopped thread. Threads not created by
+//! osal-rs, e.g. `main`, keep running.
+//!
+//! Handlers must not print: a thread stopped inside `printf` holds the lock
+//! of `stdout`.
+
+use core::ffi::c_void;
+use core::fmt::{Debug, Display, Formatter};
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
+
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::sync::Arc;
+
+use super::ffi::{PTHREAD_ONCE_INIT, pthread_create, pthread_detach, pthread_equal, pthread_once, pthread_once_t, pthread_self, pthread_t, sched_yield};
+use super::sync::{Monitor, WAIT_FOREVER};
+use super::system::System;
+use super::thread;
+use super::virtual_time;
+use crate::traits::{SystemFn, ToTick};
+use crate::utils::{Error, OsalRsBool, Result};
+
+struct Line {
+    handler: Box<dyn Fn() + Send + Sync>,
+    runs: AtomicU32,
+    yields: AtomicU32,
+}
+
+struct Controller {
+    lines: BTreeMap<u32, Arc<Line>>,
+    pending: BTreeSet<u32>,
+    running: bool,
+}
+
+static mut ONCE: pthread_once_t = PTHREAD_ONCE_INIT;
+
+static mut CONTROLLER: *const Monitor<Controller> = null_mut();
+
+/// pthread serving the interrupts, 0 until it is started
+static ISR_PTHREAD: AtomicUsize = AtomicUsize::new(0);
+
+static IN_HANDLER: AtomicBool = AtomicBool::new(false);
+
+/// Set by `System::yield_from_isr` when a handler woke a thread up
+static YIELD_PENDING: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn init_once() {
+    let controller = match Monitor::new(Controller {
+        lines: BTreeMap::new(),
+        pending: BTreeSet::new(),
+        running: false,
+    }) {
+        Ok(controller) => Box::into_raw(Box::new(controller)),
+        Err(_) => return,
+    };
+
+    let mut pthread: pthread_t = 0;
+    virtual_time::thread_created();
+    if unsafe { pthread_create(&mut pthread, core::ptr::null(), interrupt_thread, controller as *mut c_void) } != 0 {
+        virtual_time::thread_not_created();
+        drop(unsafe { Box::from_raw(controller) });
+        return;
+    }
+
+    unsafe {
+        pthread_detach(pthread);
+        CONTROLLER = controller;
+    }
+    ISR_PTHREAD.store(pthread as usize, Ordering::SeqCst);
+}
+
+/// Controller of the simulated interrupts, started on first use
+fn controller() -> Option<&'static Monitor<Controller>> {
+    unsafe {
+        pthread_once(&raw mut ONCE, init_once);
+        CONTROLLER.as_ref()
+    }
+}
+
+extern "C" fn interrupt_thread(arg: *mut c_void) -> *mut c_void {
+    let controller = unsafe { &*(arg as *const Monitor<Controller>) };
+    virtual_time::thread_started();
+
+    loop {
+        let mut state = controller.lock();
+        state.wait_while(WAIT_FOREVER, |state| state.pending.is_empty());
+        state.running = true;
+        drop(state);
+
+        // The line is picked once unmasked, so that the lines triggered inside
+        // a critical section are served by priority when it ends
+        System::critical_section_enter();
+        let mut state = controller.lock();
+        let line = state.pending.pop_first().and_then(|irq| state.lines.get(&irq).cloned());
+        drop(state);
+
+        if let Some(line) = &line {
+            let stopped = thread::stop_threads();
+            YIELD_PENDING.store(false, Ordering::SeqCst);
+            IN_HANDLER.store(true, Ordering::SeqCst);
+
+            (line.handler)();
+
+            IN_HANDLER.store(false, Ordering::SeqCst);
+            thread::restart_threads(&stopped);
+        }
+        System::critical_section_exit();
+
+        if let Some(line) = line {
+            line.runs.fetch_add(1, Ordering::SeqCst);
+            // The context switch requested by the handler: the threads are
+            // running again, give the woken one a chance to run before the
+            // next interrupt stops them
+            if YIELD_PENDING.swap(false, Ordering::SeqCst) {
+                line.yields.fetch_add(1, Ordering::SeqCst);
+                unsafe { sched_yield(); }
+            }
+        }
+
+        let mut state = controller.lock();
+        state.running = false;
+        state.notify_all();
+    }
+}
+
+/// Records the context switch requested through `System::yield_from_isr`
+pub(crate) fn request_yield() {
+    if Interrupt::is_in_isr() {
+        YIELD_PENDING.store(true, Ordering::SeqCst);
+    }
+}
+
+/// A simulated interrupt line with its handler, unregistered on drop
+pub struct Interrupt {
+    irq: u32,
+    line: Arc<Line>,
+}
+
+impl Interrupt {
+    /// Registers `handler` on line `irq`, lower numbers have higher priority
+    pub fn new<F>(irq: u32, handler: F) -> Result<Self>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let controller = controller().ok_or(Error::NullPtr)?;
+
+        let line = Arc::new(Line {
+            handler: Box::new(handler),
+            runs: AtomicU32::new(0),
+            yields: AtomicU32::new(0),
+        });
+
+        let mut state = controller.lock();
+        if state.lines.contains_key(&irq) {
+            return Err(Error::Unhandled("Interrupt line already registered"));
+        }
+        state.lines.insert(irq, line.clone());
+
+        Ok(Self { irq, line })
+    }
+
+    /// Marks the line pending, the handler runs on the interrupt thread once
+    /// the application threads are stopped. As in hardware, triggering a line that is already
+    /// pending does not queue a second run.
+    pub fn trigger(&self) {
+        if let Some(controller) = controller() {
+            let mut state = controller.lock();
+            state.pending.insert(self.irq);
+            state.notify_all();
+        }
+    }
+
+    /// Whether the caller is running inside an interrupt handler
+    pub fn is_in_isr() -> bool {
+        IN_HANDLER.load(Ordering::SeqCst)
+            && unsafe { pthread_equal(pthread_self(), ISR_PTHREAD.load(Ordering::SeqCst) as pthread_t) } != 0
+    }
+
+    /// Waits until no interrupt is pending or running.
+    ///
+    /// Must not be called from a critical section, which masks interrupts.
+    pub fn wait_idle(ticks_to_wait: impl ToTick) -> OsalRsBool {
+        let controller = match controller() {
+            Some(controller) => controller,
+            None => return OsalRsBool::False,
+        };
+
+        let mut state = controller.lock();
+        if state.wait_while(ticks_to_wait.to_ticks(), |state| state.running || !state.pending.is_empty()) {
+            OsalRsBool::True
+        } else {
+            OsalRsBool::False
+        }
+    }
+
+    #[inline]
+    pub fn irq(&self) -> u32 {
+        self.irq
+    }
+
+    /// Number of times the handler has completed
+    #[inline]
+    pub fn run_count(&self) -> u32 {
+        self.line.runs.load(Ordering::SeqCst)
+    }
+
+    /// Number of handler runs that requested a context switch on exit
+    #[inline]
+    pub fn yield_count(&self) -> u32 {
+        self.line.yields.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for Interrupt {
+    fn drop(&mut self) {
+        if let Some(controller) = controller() {
+            let mut state = controller.lock();
+            state.lines.remove(&self.irq);
+            state.pending.remove(&self.irq);
+        }
+    }
+}
+
+impl Debug for Interrupt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Interrupt")
+            .field("irq", &self.irq)
+            .finish()
+    }
+}
+
+impl Display for Interrupt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Interrupt {{ irq: {} }}", self.irq)
+    }
+}
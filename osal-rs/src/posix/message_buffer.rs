@@ -0,0 +1,262 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::fmt::{Debug, Display};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::ptr::null_mut;
+
+use alloc::boxed::Box;
+use alloc::vec;
+
+use super::ffi::MessageBufferHandle;
+use super::stream_buffer::Stream;
+use super::sync::Monitor;
+use super::system::System;
+use super::types::{BaseType, TickType};
+use crate::traits::{MessageBufferFn, MessageBufferStreamedFn, SystemFn, ToTick, ToBytes, FromBytes};
+use crate::utils::{Result, Error};
+
+/// Bytes of the length stored before each message, as
+/// `sbBYTES_TO_STORE_MESSAGE_LENGTH` of FreeRTOS
+const LENGTH_SIZE: usize = size_of::<usize>();
+
+/// Length of the oldest message of `stream`, 0 if empty
+fn next_length(stream: &Stream) -> usize {
+    if stream.len() == 0 {
+        return 0;
+    }
+
+    let mut length = [0u8; LENGTH_SIZE];
+    stream.peek(&mut length);
+    usize::from_ne_bytes(length)
+}
+
+/// Message buffer, the handle points to the `Monitor` holding the length
+/// prefixed messages
+pub struct MessageBuffer (MessageBufferHandle);
+
+unsafe impl Send for MessageBuffer {}
+unsafe impl Sync for MessageBuffer {}
+
+impl MessageBuffer {
+    #[inline]
+    fn inner(&self) -> &Monitor<Stream> {
+        unsafe { &*(self.0 as *const Monitor<Stream>) }
+    }
+
+    /// Returns whether a thread waiting on the buffer was woken
+    fn transmit(&self, message: &[u8], time: TickType) -> Result<bool> {
+        let mut stream = self.inner().lock();
+        let required = message.len() + LENGTH_SIZE;
+        // A message larger than the buffer never fits, it is not waited for
+        if required > stream.capacity() || !stream.wait_while(time, |stream| stream.spaces() < required) {
+            return Err(Error::Timeout);
+        }
+
+        stream.write(&message.len().to_ne_bytes());
+        stream.write(message);
+        stream.notify_all();
+        Ok(stream.has_waiters())
+    }
+
+    /// Returns the length of the message and whether a thread waiting on
+    /// the buffer was woken
+    fn collect(&self, buffer: &mut [u8], time: TickType) -> Result<(usize, bool)> {
+        let mut stream = self.inner().lock();
+        if !stream.wait_while(time, |stream| stream.len() == 0) {
+            return Err(Error::Timeout);
+        }
+
+        let length = next_length(&stream);
+        if length > buffer.len() {
+            return Err(Error::Unhandled("Buffer too small for the next message"));
+        }
+        stream.discard(LENGTH_SIZE);
+        stream.read(&mut buffer[..length]);
+        stream.notify_all();
+        Ok((length, stream.has_waiters()))
+    }
+}
+
+impl MessageBufferFn for MessageBuffer {
+    fn new(size: usize) -> Result<Self> {
+        if size <= LENGTH_SIZE {
+            return Err(Error::InvalidQueueSize);
+        }
+
+        let monitor = Monitor::new(Stream::new(size, 1))?;
+        Ok(Self (Box::into_raw(Box::new(monitor)) as MessageBufferHandle))
+    }
+
+    #[inline]
+    fn send(&self, message: &[u8], ticks_to_wait: impl ToTick) -> Result<()> {
+        self.transmit(message, ticks_to_wait.to_ticks()).map(|_| ())
+    }
+
+    /// Never blocks: fails with `Error::Timeout` if the message does not fit
+    fn send_from_isr(&self, message: &[u8]) -> Result<()> {
+        let woken = self.transmit(message, 0)?;
+        System::yield_from_isr(woken as BaseType);
+        Ok(())
+    }
+
+    #[inline]
+    fn receive(&self, buffer: &mut [u8], ticks_to_wait: impl ToTick) -> Result<usize> {
+        self.collect(buffer, ticks_to_wait.to_ticks()).map(|(length, _)| length)
+    }
+
+    /// Never blocks: fails with `Error::Timeout` if the buffer is empty
+    fn receive_from_isr(&self, buffer: &mut [u8]) -> Result<usize> {
+        let (length, woken) = self.collect(buffer, 0)?;
+        System::yield_from_isr(woken as BaseType);
+        Ok(length)
+    }
+
+    #[inline]
+    fn next_length(&self) -> usize {
+        next_length(&self.inner().lock())
+    }
+
+    #[inline]
+    fn spaces_available(&self) -> usize {
+        self.inner().lock().spaces()
+    }
+
+    fn reset(&self) -> Result<()> {
+        let mut stream = self.inner().lock();
+        if stream.has_waiters() {
+            return Err(Error::Unhandled("Message buffer reset while a task is blocked on it"));
+        }
+        stream.clear();
+        Ok(())
+    }
+
+    fn delete(&mut self) {
+        drop(unsafe { Box::from_raw(self.0 as *mut Monitor<Stream>) });
+        self.0 = null_mut();
+    }
+}
+
+impl Drop for MessageBuffer {
+    fn drop(&mut self) {
+        if self.0.is_null() {
+            return;
+        }
+        self.delete();
+    }
+}
+
+impl Deref for MessageBuffer {
+    type Target = MessageBufferHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for MessageBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MessageBuffer")
+            .field("handle", &self.0)
+            .finish()
+    }
+}
+
+impl Display for MessageBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "MessageBuffer {{ handle: {:?} }}", self.0)
+    }
+}
+
+/// Typed `MessageBuffer`, the size is kept to receive the longest message
+pub struct MessageBufferStreamed<T: ToBytes + FromBytes> (MessageBuffer, usize, PhantomData<T>);
+
+unsafe impl<T: ToBytes + FromBytes> Send for MessageBufferStreamed<T> {}
+unsafe impl<T: ToBytes + FromBytes> Sync for MessageBufferStreamed<T> {}
+
+impl<T> MessageBufferStreamedFn<T> for MessageBufferStreamed<T> 
+where 
+    T: ToBytes + FromBytes {
+
+    #[inline]
+    fn new(size: usize) -> Result<Self> {
+        Ok(Self (MessageBuffer::new(size)?, size, PhantomData))
+    }
+
+    #[inline]
+    fn send(&self, item: &T, ticks_to_wait: impl ToTick) -> Result<()> {
+        self.0.send(item.to_bytes(), ticks_to_wait)
+    }
+
+    #[inline]
+    fn send_from_isr(&self, item: &T) -> Result<()> {
+        self.0.send_from_isr(item.to_bytes())
+    }
+
+    fn receive(&self, ticks_to_wait: impl ToTick) -> Result<T> {
+        let mut bytes = vec![0u8; self.1];
+        let len = self.0.receive(&mut bytes, ticks_to_wait)?;
+        T::from_bytes(&bytes[..len])
+    }
+
+    fn receive_from_isr(&self) -> Result<T> {
+        let mut bytes = vec![0u8; self.1];
+        let len = self.0.receive_from_isr(&mut bytes)?;
+        T::from_bytes(&bytes[..len])
+    }
+
+    #[inline]
+    fn reset(&self) -> Result<()> {
+        self.0.reset()
+    }
+
+    #[inline]
+    fn delete(&mut self) {
+        self.0.delete()
+    }
+}
+
+impl<T> Deref for MessageBufferStreamed<T> 
+where 
+    T: ToBytes + FromBytes {
+    type Target = MessageBufferHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.0
+    }
+}
+
+impl<T> Debug for MessageBufferStreamed<T> 
+where 
+    T: ToBytes + FromBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MessageBufferStreamed")
+            .field("handle", &self.0.0)
+            .finish()
+    }
+}
+
+impl<T> Display for MessageBufferStreamed<T> 
+where 
+    T: ToBytes + FromBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "MessageBufferStreamed {{ handle: {:?} }}", self.0.0)
+    }
+}
@@ -0,0 +1,382 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::cell::UnsafeCell;
+use core::fmt::{Debug, Display, Formatter};
+use core::ops::{Deref, DerefMut};
+use core::marker::PhantomData;
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+
+use super::ffi::MutexHandle;
+use super::isr::Interrupt;
+use super::sync::{OwnerLock, WAIT_FOREVER};
+use super::types::TickType;
+use crate::traits::{MutexGuardFn, RawMutexFn, MutexFn, RecursiveMutexFn};
+use crate::utils::{Result, Error, OsalRsBool};
+
+
+/// Mutex, the POSIX counterpart of `xSemaphoreCreateMutex`. Priority
+/// inheritance is not emulated: threads keep the priority they were given.
+///
+/// The handle points to a heap allocated `OwnerLock` so that it never
+/// moves once initialized.
+struct RawMutex(MutexHandle);
+
+unsafe impl Send for RawMutex {}
+unsafe impl Sync for RawMutex {}
+
+impl RawMutex {
+    fn create(recursive: bool) -> Result<Self> {
+        let lock = OwnerLock::new(recursive)?;
+        Ok(RawMutex(Box::into_raw(Box::new(lock)) as MutexHandle))
+    }
+
+    #[inline]
+    fn raw(&self) -> &OwnerLock {
+        unsafe { &*(self.0 as *const OwnerLock) }
+    }
+
+    /// Fails in interrupt context, where mutexes cannot be used
+    fn take(&self, ticks: TickType) -> OsalRsBool {
+        if Interrupt::is_in_isr() {
+            return OsalRsBool::False;
+        }
+        if self.raw().lock(ticks) {
+            OsalRsBool::True
+        } else {
+            OsalRsBool::False
+        }
+    }
+}
+
+impl RawMutexFn for RawMutex {
+    fn new() -> Result<Self> {
+        Self::create(false)
+    }
+    
+    /// Fails if the calling thread already holds the mutex
+    fn lock(&self) -> OsalRsBool {
+        self.take(WAIT_FOREVER)
+    }
+
+    fn try_lock(&self) -> OsalRsBool {
+        self.take(0)
+    }
+
+    fn unlock(&self) -> OsalRsBool {
+        if self.raw().unlock() {
+            OsalRsBool::True
+        } else {
+            OsalRsBool::False
+        }
+    }
+
+    fn delete(&mut self) {
+        drop(unsafe { Box::from_raw(self.0 as *mut OwnerLock) });
+        self.0 = core::ptr::null();
+    }
+}
+
+impl Drop for RawMutex {
+    fn drop(&mut self) {
+        if self.0.is_null() {
+            return;
+        }
+        self.delete();
+    }
+}
+
+impl Deref for RawMutex {
+    type Target = MutexHandle;
+
+    fn deref(&self) -> &MutexHandle {
+        &self.0
+    }
+}
+
+
+impl Debug for RawMutex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RawMutex")
+            .field("handle", &self.0)
+            .finish()
+    }
+}
+
+impl Display for RawMutex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "RawMutex {{ handle: {:?} }}", self.0)
+    }
+}
+
+/// Recursive mutex, the POSIX counterpart of `xSemaphoreCreateRecursiveMutex`
+struct RawRecursiveMutex(RawMutex);
+
+impl RawMutexFn for RawRecursiveMutex {
+    fn new() -> Result<Self> {
+        Ok(RawRecursiveMutex(RawMutex::create(true)?))
+    }
+
+    fn lock(&self) -> OsalRsBool {
+        self.0.take(WAIT_FOREVER)
+    }
+
+    /// Never blocks: fails if the mutex is held by another thread
+    fn try_lock(&self) -> OsalRsBool {
+        self.0.take(0)
+    }
+
+    fn unlock(&self) -> OsalRsBool {
+        self.0.unlock()
+    }
+
+    fn delete(&mut self) {
+        self.0.delete();
+    }
+}
+
+impl Debug for RawRecursiveMutex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RawRecursiveMutex")
+            .field("handle", &self.0.0)
+            .finish()
+    }
+}
+
+impl Display for RawRecursiveMutex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "RawRecursiveMutex {{ handle: {:?} }}", self.0.0)
+    }
+}
+
+pub struct Mutex<T: ?Sized> {
+    inner: RawMutex,
+    data: UnsafeCell<T>
+}
+
+
+unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
+
+impl<T: ?Sized> MutexFn<T> for Mutex<T> {
+    type Guard<'a> = MutexGuard<'a, T> where Self: 'a, T: 'a;
+
+    fn new(data: T) -> Self
+    where 
+        T: Sized
+    {
+        Self {
+            inner: RawMutex::new().unwrap(),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    fn lock(&self) -> Result<Self::Guard<'_>> {
+        match self.inner.lock() {
+            OsalRsBool::True => Ok(MutexGuard {
+                mutex: self,
+                _phantom: PhantomData,
+            }),
+            OsalRsBool::False => Err(Error::MutexLockFailed),
+        }
+    }
+
+    fn try_lock(&self) -> Result<Self::Guard<'_>> {
+        match self.inner.try_lock() {
+            OsalRsBool::True => Ok(MutexGuard {
+                mutex: self,
+                _phantom: PhantomData,
+            }),
+            OsalRsBool::False => Err(Error::MutexLockFailed),
+        }
+    }
+
+    fn into_inner(self) -> Result<T> 
+    where 
+        Self: Sized, 
+        T: Sized 
+    {
+        Ok(self.data.into_inner())
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+}
+
+impl<T> Mutex<T> {
+    /// Creates a new mutex wrapped in an Arc for easy sharing between threads.
+    /// This is a convenience method that combines `Arc::new(Mutex::new(data))`.
+    /// 
+    /// # Example
+    /// ```ignore
+    /// let shared_data = Mutex::new_arc(0u32);
+    /// let data_clone = Arc::clone(&shared_data);
+    /// ```
+    pub fn new_arc(data: T) -> Arc<Self> {
+        Arc::new(Self::new(data))
+    }
+}
+
+impl<T> Debug for Mutex<T> 
+where 
+    T: ?Sized {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Mutex")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T> Display for Mutex<T> 
+where 
+    T: ?Sized {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Mutex {{ inner: {} }}", self.inner)
+    }   
+}
+
+/// RAII guard returned by `Mutex::lock`
+pub struct MutexGuard<'a, T: ?Sized + 'a> {
+    mutex: &'a Mutex<T>,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: ?Sized> Deref for MutexGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<'a, T: ?Sized> DerefMut for MutexGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.data.get() }
+    }
+}
+
+impl<'a, T: ?Sized> Drop for MutexGuard<'a, T> {
+    fn drop(&mut self) {
+        self.mutex.inner.unlock();
+    }
+}
+
+impl<'a, T: ?Sized> MutexGuardFn<'a, T> for MutexGuard<'a, T> {}
+
+pub struct RecursiveMutex<T: ?Sized> {
+    inner: RawRecursiveMutex,
+    data: UnsafeCell<T>
+}
+
+unsafe impl<T: ?Sized + Send> Send for RecursiveMutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for RecursiveMutex<T> {}
+
+impl<T: ?Sized> RecursiveMutexFn<T> for RecursiveMutex<T> {
+    type Guard<'a> = RecursiveMutexGuard<'a, T> where Self: 'a, T: 'a;
+
+    fn new(data: T) -> Self
+    where 
+        T: Sized
+    {
+        Self {
+            inner: RawRecursiveMutex::new().unwrap(),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    fn lock(&self) -> Result<Self::Guard<'_>> {
+        match self.inner.lock() {
+            OsalRsBool::True => Ok(RecursiveMutexGuard {
+                mutex: self,
+                _phantom: PhantomData,
+            }),
+            OsalRsBool::False => Err(Error::MutexLockFailed),
+        }
+    }
+
+    fn try_lock(&self) -> Result<Self::Guard<'_>> {
+        match self.inner.try_lock() {
+            OsalRsBool::True => Ok(RecursiveMutexGuard {
+                mutex: self,
+                _phantom: PhantomData,
+            }),
+            OsalRsBool::False => Err(Error::MutexLockFailed),
+        }
+    }
+
+    fn into_inner(self) -> Result<T> 
+    where 
+        Self: Sized, 
+        T: Sized 
+    {
+        Ok(self.data.into_inner())
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+}
+
+impl<T> RecursiveMutex<T> {
+    /// Creates a new recursive mutex wrapped in an Arc, like `Mutex::new_arc`
+    pub fn new_arc(data: T) -> Arc<Self> {
+        Arc::new(Self::new(data))
+    }
+}
+
+impl<T> Debug for RecursiveMutex<T> 
+where 
+    T: ?Sized {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RecursiveMutex")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T> Display for RecursiveMutex<T> 
+where 
+    T: ?Sized {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "RecursiveMutex {{ inner: {} }}", self.inner)
+    }   
+}
+
+/// RAII guard returned by `RecursiveMutex::lock`, shared access only
+pub struct RecursiveMutexGuard<'a, T: ?Sized + 'a> {
+    mutex: &'a RecursiveMutex<T>,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T: ?Sized> Deref for RecursiveMutexGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<'a, T: ?Sized> Drop for RecursiveMutexGuard<'a, T> {
+    fn drop(&mut self) {
+        self.mutex.inner.unlock();
+    }
+}
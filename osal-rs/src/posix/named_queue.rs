@@ -0,0 +1,502 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+//! Named message queues shared between processes, built on POSIX `mq_*`.
+//!
+//! Any process opening the same name reaches the same kernel queue, so the
+//! message types of `QueueStreamed<T>` can travel between processes through
+//! `NamedQueueStreamed<T>`. Timeouts are measured on the real clock, also
+//! with the `virtual_time` feature.
+
+use core::ffi::{c_char, c_int, c_uint};
+use core::fmt::{Debug, Display};
+use core::marker::PhantomData;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use alloc::borrow::Cow;
+use alloc::ffi::CString;
+use alloc::format;
+use alloc::vec;
+
+use super::ffi::{CLOCK_REALTIME, EACCES, EAGAIN, EEXIST, EINTR, EINVAL, EMFILE, EMSGSIZE, ENFILE, ENOENT, ENOMEM, ENOSPC, ETIMEDOUT, O_CREAT, O_EXCL, O_RDWR, __errno_location, getpid, mode_t, mq_attr, mq_close, mq_getattr, mq_open, mq_receive, mq_send, mq_timedreceive, mq_timedsend, mq_unlink, mqd_t};
+use super::isr::Interrupt;
+use super::sync::{WAIT_FOREVER, deadline_on};
+use super::system::System;
+use super::types::{TickType, UBaseType};
+use crate::traits::{ToTick, QueueFn, QueueStreamedFn, SystemFn, ToBytes, BytesHasLen, FromBytes};
+use crate::utils::{Result, Error};
+
+/// Permissions of a queue created by `NamedQueue::create`: owner and group
+const QUEUE_MODE: mode_t = 0o660;
+
+/// `mq_*` priority of the messages posted by `post`
+const PRIORITY_BACK: c_uint = 0;
+
+/// `mq_*` priority of the messages posted by `post_to_front`, the kernel
+/// delivers them before any message of `PRIORITY_BACK`
+const PRIORITY_FRONT: c_uint = 1;
+
+/// Source of unique names for the queues created by `NamedQueue::new`
+static ANONYMOUS_ID: AtomicU32 = AtomicU32::new(0);
+
+#[inline]
+fn errno() -> c_int {
+    unsafe { *__errno_location() }
+}
+
+fn to_error(errno: c_int) -> Error {
+    match errno {
+        ETIMEDOUT | EAGAIN => Error::Timeout,
+        ENOENT => Error::NotFound,
+        EINVAL | EMSGSIZE => Error::InvalidQueueSize,
+        ENOMEM | ENOSPC | EMFILE | ENFILE => Error::OutOfMemory,
+        EEXIST => Error::Unhandled("Message queue already exists"),
+        EACCES => Error::Unhandled("Message queue permission denied"),
+        _ => Error::Unhandled("Message queue error"),
+    }
+}
+
+/// Queue names are a single path component, the leading `/` is optional
+fn to_queue_name(name: &str) -> Result<CString> {
+    let name = name.strip_prefix('/').unwrap_or(name);
+    if name.is_empty() || name.contains('/') {
+        return Err(Error::Unhandled("Invalid message queue name"));
+    }
+    CString::new(format!("/{}", name))
+        .map_err(|_| Error::Unhandled("Failed to convert string to CString"))
+}
+
+/// Message queue reachable by name from every process of the host.
+///
+/// Messages are `message_size` bytes: shorter items are zero padded and
+/// longer ones truncated, fetched messages are truncated to the buffer, as
+/// for `Queue`. Dropping the queue closes it, the kernel object lives until
+/// `NamedQueue::unlink` is called and every process has closed it.
+pub struct NamedQueue {
+    handle: mqd_t,
+    message_size: usize,
+}
+
+unsafe impl Send for NamedQueue {}
+unsafe impl Sync for NamedQueue {}
+
+impl NamedQueue {
+    /// Opens the queue `name`, creating it with `size` messages of
+    /// `message_size` bytes if it does not exist yet. Fails with
+    /// `Error::InvalidQueueSize` if it exists with a different geometry or
+    /// the geometry exceeds the system limits (`/proc/sys/fs/mqueue`).
+    pub fn create(name: &str, size: UBaseType, message_size: UBaseType) -> Result<Self> {
+        let queue = Self::open_with(&to_queue_name(name)?, O_RDWR | O_CREAT, size, message_size)?;
+        if queue.capacity()? != size as usize || queue.message_size != message_size as usize {
+            return Err(Error::InvalidQueueSize);
+        }
+        Ok(queue)
+    }
+
+    /// Opens the existing queue `name`, fails with `Error::NotFound` if no
+    /// process created it
+    pub fn open(name: &str) -> Result<Self> {
+        let name = to_queue_name(name)?;
+        let handle = unsafe { mq_open(name.as_ptr(), O_RDWR) };
+        Self::from_descriptor(handle)
+    }
+
+    /// Removes the name of the queue: processes that opened it keep using
+    /// it, the queue is destroyed when the last one closes it
+    pub fn unlink(name: &str) -> Result<()> {
+        let name = to_queue_name(name)?;
+        if unsafe { mq_unlink(name.as_ptr()) } != 0 {
+            return Err(to_error(errno()));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn fetch_with_to_tick(&self, buffer: &mut [u8], time: impl ToTick) -> Result<()> {
+        self.fetch(buffer, time.to_ticks())
+    }
+
+    #[inline]
+    pub fn post_with_to_tick(&self, item: &[u8], time: impl ToTick) -> Result<()> {
+        self.post(item, time.to_ticks())
+    }
+
+    /// Size in bytes of every message
+    #[inline]
+    pub fn message_size(&self) -> usize {
+        self.message_size
+    }
+
+    /// Number of messages waiting in the queue, sent by any process
+    pub fn count(&self) -> Result<usize> {
+        Ok(self.attributes()?.mq_curmsgs as usize)
+    }
+
+    fn capacity(&self) -> Result<usize> {
+        Ok(self.attributes()?.mq_maxmsg as usize)
+    }
+
+    fn attributes(&self) -> Result<mq_attr> {
+        let mut attr = mq_attr::default();
+        if unsafe { mq_getattr(self.handle, &mut attr) } != 0 {
+            return Err(to_error(errno()));
+        }
+        Ok(attr)
+    }
+
+    fn open_with(name: &CString, flags: c_int, size: UBaseType, message_size: UBaseType) -> Result<Self> {
+        if size == 0 || message_size == 0 {
+            return Err(Error::InvalidQueueSize);
+        }
+
+        let attr = mq_attr {
+            mq_maxmsg: size as _,
+            mq_msgsize: message_size as _,
+            ..Default::default()
+        };
+        let handle = unsafe { mq_open(name.as_ptr(), flags, QUEUE_MODE as c_uint, &attr as *const mq_attr) };
+        Self::from_descriptor(handle)
+    }
+
+    fn from_descriptor(handle: mqd_t) -> Result<Self> {
+        if handle < 0 {
+            return Err(to_error(errno()));
+        }
+
+        let mut queue = Self { handle, message_size: 0 };
+        queue.message_size = queue.attributes()?.mq_msgsize as usize;
+        Ok(queue)
+    }
+
+    fn receive(&self, buffer: &mut [u8], time: TickType) -> Result<()> {
+        let time = if Interrupt::is_in_isr() { 0 } else { time };
+        // The kernel rejects buffers shorter than the message size
+        let mut message = vec![0u8; self.message_size];
+        let deadline = deadline_on(CLOCK_REALTIME, if time == WAIT_FOREVER { 0 } else { time });
+
+        loop {
+            let ptr = message.as_mut_ptr() as *mut c_char;
+            let received = unsafe {
+                if time == WAIT_FOREVER {
+                    mq_receive(self.handle, ptr, message.len(), null_mut())
+                } else {
+                    mq_timedreceive(self.handle, ptr, message.len(), null_mut(), &deadline)
+                }
+            };
+
+            if received >= 0 {
+                let len = buffer.len().min(received as usize);
+                buffer[..len].copy_from_slice(&message[..len]);
+                buffer[len..].fill(0);
+                return Ok(());
+            }
+
+            match errno() {
+                // Interrupted by the suspend signal of `Thread`
+                EINTR => continue,
+                errno => return Err(to_error(errno)),
+            }
+        }
+    }
+
+    fn send(&self, item: &[u8], time: TickType, priority: c_uint) -> Result<()> {
+        let time = if Interrupt::is_in_isr() { 0 } else { time };
+        let message = if item.len() == self.message_size {
+            Cow::Borrowed(item)
+        } else {
+            let mut padded = vec![0u8; self.message_size];
+            let len = item.len().min(self.message_size);
+            padded[..len].copy_from_slice(&item[..len]);
+            Cow::Owned(padded)
+        };
+        let deadline = deadline_on(CLOCK_REALTIME, if time == WAIT_FOREVER { 0 } else { time });
+
+        loop {
+            let ptr = message.as_ptr() as *const c_char;
+            let sent = unsafe {
+                if time == WAIT_FOREVER {
+                    mq_send(self.handle, ptr, message.len(), priority)
+                } else {
+                    mq_timedsend(self.handle, ptr, message.len(), priority, &deadline)
+                }
+            };
+
+            if sent == 0 {
+                return Ok(());
+            }
+
+            match errno() {
+                EINTR => continue,
+                errno => return Err(to_error(errno)),
+            }
+        }
+    }
+}
+
+impl QueueFn for NamedQueue {
+    /// Creates a queue private to the process: its name is removed at once
+    fn new (size: UBaseType, message_size: UBaseType) -> Result<Self> {
+        let name = format!("/osal-rs-{}-{}", unsafe { getpid() }, ANONYMOUS_ID.fetch_add(1, Ordering::Relaxed));
+        let name = CString::new(name).map_err(|_| Error::Unhandled("Failed to convert string to CString"))?;
+
+        let queue = Self::open_with(&name, O_RDWR | O_CREAT | O_EXCL, size, message_size)?;
+        unsafe { mq_unlink(name.as_ptr()); }
+        Ok(queue)
+    }
+
+    #[inline]
+    fn fetch(&self, buffer: &mut [u8], time: TickType) -> Result<()> {
+        self.receive(buffer, time)
+    }
+
+    /// Never blocks: fails with `Error::Timeout` if the queue is empty
+    fn fetch_from_isr(&self, buffer: &mut [u8]) -> Result<()> {
+        self.receive(buffer, 0)?;
+        // The kernel does not tell whether a sender was woken
+        System::yield_from_isr(0);
+        Ok(())
+    }
+
+    #[inline]
+    fn post(&self, item: &[u8], time: TickType) -> Result<()> {
+        self.send(item, time, PRIORITY_BACK)
+    }
+
+    /// Never blocks: fails with `Error::Timeout` if the queue is full
+    fn post_from_isr(&self, item: &[u8]) -> Result<()> {
+        self.send(item, 0, PRIORITY_BACK)?;
+        System::yield_from_isr(0);
+        Ok(())
+    }
+
+    /// The message goes ahead of those sent by `post`, but after those
+    /// already sent by `post_to_front`: the kernel keeps them in order
+    #[inline]
+    fn post_to_front(&self, item: &[u8], time: TickType) -> Result<()> {
+        self.send(item, time, PRIORITY_FRONT)
+    }
+
+    fn post_to_front_from_isr(&self, item: &[u8]) -> Result<()> {
+        self.send(item, 0, PRIORITY_FRONT)?;
+        System::yield_from_isr(0);
+        Ok(())
+    }
+
+    /// On a full queue the oldest message is dropped first. Not atomic: a
+    /// process sending at the same time can fill the queue again, then
+    /// `Error::Timeout` is returned
+    fn overwrite(&self, item: &[u8]) -> Result<()> {
+        if self.count()? >= self.capacity()? {
+            let mut dropped = vec![0u8; self.message_size];
+            match self.receive(&mut dropped, 0) {
+                Ok(()) | Err(Error::Timeout) => {}
+                Err(error) => return Err(error),
+            }
+        }
+        self.send(item, 0, PRIORITY_BACK)
+    }
+
+    fn overwrite_from_isr(&self, item: &[u8]) -> Result<()> {
+        self.overwrite(item)?;
+        System::yield_from_isr(0);
+        Ok(())
+    }
+
+    /// POSIX message queues cannot be read without removing the message
+    fn peek(&self, _buffer: &mut [u8], _time: TickType) -> Result<()> {
+        Err(Error::Unhandled("Message queues cannot be peeked"))
+    }
+
+    fn peek_from_isr(&self, _buffer: &mut [u8]) -> Result<()> {
+        Err(Error::Unhandled("Message queues cannot be peeked"))
+    }
+
+    /// Drops the messages waiting, sent by any process
+    fn reset(&self) -> Result<()> {
+        let mut dropped = vec![0u8; self.message_size];
+        loop {
+            match self.receive(&mut dropped, 0) {
+                Ok(()) => continue,
+                Err(Error::Timeout) => return Ok(()),
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    fn delete(&mut self) {
+        unsafe { mq_close(self.handle); }
+        self.handle = -1;
+    }
+}
+
+impl Drop for NamedQueue {
+    fn drop(&mut self) {
+        if self.handle < 0 {
+            return;
+        }
+        self.delete();
+    }
+}
+
+impl Debug for NamedQueue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NamedQueue")
+            .field("handle", &self.handle)
+            .field("message_size", &self.message_size)
+            .finish()
+    }
+}
+
+impl Display for NamedQueue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NamedQueue {{ handle: {}, message_size: {} }}", self.handle, self.message_size)
+    }
+}
+
+/// Typed view of a `NamedQueue`, the counterpart of `QueueStreamed<T>`
+pub struct NamedQueueStreamed<T: ToBytes + BytesHasLen + FromBytes> (NamedQueue, PhantomData<T>);
+
+unsafe impl<T: ToBytes + BytesHasLen + FromBytes> Send for NamedQueueStreamed<T> {}
+unsafe impl<T: ToBytes + BytesHasLen + FromBytes> Sync for NamedQueueStreamed<T> {}
+
+impl<T> NamedQueueStreamed<T> 
+where 
+    T: ToBytes + BytesHasLen + FromBytes {
+
+    #[inline]
+    pub fn create(name: &str, size: UBaseType, message_size: UBaseType) -> Result<Self> {
+        Ok(Self (NamedQueue::create(name, size, message_size)?, PhantomData))
+    }
+
+    #[inline]
+    pub fn open(name: &str) -> Result<Self> {
+        Ok(Self (NamedQueue::open(name)?, PhantomData))
+    }
+
+    #[inline]
+    pub fn unlink(name: &str) -> Result<()> {
+        NamedQueue::unlink(name)
+    }
+
+    #[inline]
+    pub fn fetch_with_to_tick(&self, buffer: &mut T, time: impl ToTick) -> Result<()> {
+        self.fetch(buffer, time.to_ticks())
+    }
+
+    #[inline]
+    pub fn post_with_to_tick(&self, item: &T, time: impl ToTick) -> Result<()> {
+        self.post(item, time.to_ticks())
+    }
+}
+
+impl<T> QueueStreamedFn<T> for NamedQueueStreamed<T> 
+where 
+    T: ToBytes + BytesHasLen + FromBytes {
+
+    #[inline]
+    fn new (size: UBaseType, message_size: UBaseType) -> Result<Self> {
+        Ok(Self (NamedQueue::new(size, message_size)?, PhantomData))
+    }
+
+    fn fetch(&self, buffer: &mut T, time: TickType) -> Result<()> {
+        let mut buf_bytes = vec![0u8; buffer.len()];
+        self.0.fetch(&mut buf_bytes, time)?;
+        *buffer = T::from_bytes(&buf_bytes)?;
+        Ok(())
+    }
+
+    fn fetch_from_isr(&self, buffer: &mut T) -> Result<()> {
+        let mut buf_bytes = vec![0u8; buffer.len()];
+        self.0.fetch_from_isr(&mut buf_bytes)?;
+        *buffer = T::from_bytes(&buf_bytes)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn post(&self, item: &T, time: TickType) -> Result<()> {
+        self.0.post(item.to_bytes(), time)
+    }
+
+    #[inline]
+    fn post_from_isr(&self, item: &T) -> Result<()> {
+        self.0.post_from_isr(item.to_bytes())
+    }
+
+    #[inline]
+    fn post_to_front(&self, item: &T, time: TickType) -> Result<()> {
+        self.0.post_to_front(item.to_bytes(), time)
+    }
+
+    #[inline]
+    fn post_to_front_from_isr(&self, item: &T) -> Result<()> {
+        self.0.post_to_front_from_isr(item.to_bytes())
+    }
+
+    #[inline]
+    fn overwrite(&self, item: &T) -> Result<()> {
+        self.0.overwrite(item.to_bytes())
+    }
+
+    #[inline]
+    fn overwrite_from_isr(&self, item: &T) -> Result<()> {
+        self.0.overwrite_from_isr(item.to_bytes())
+    }
+
+    fn peek(&self, buffer: &mut T, time: TickType) -> Result<()> {
+        let mut buf_bytes = vec![0u8; buffer.len()];
+        self.0.peek(&mut buf_bytes, time)?;
+        *buffer = T::from_bytes(&buf_bytes)?;
+        Ok(())
+    }
+
+    fn peek_from_isr(&self, buffer: &mut T) -> Result<()> {
+        let mut buf_bytes = vec![0u8; buffer.len()];
+        self.0.peek_from_isr(&mut buf_bytes)?;
+        *buffer = T::from_bytes(&buf_bytes)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn reset(&self) -> Result<()> {
+        self.0.reset()
+    }
+
+    #[inline]
+    fn delete(&mut self) {
+        self.0.delete()
+    }
+}
+
+impl<T> Debug for NamedQueueStreamed<T> 
+where 
+    T: ToBytes + BytesHasLen + FromBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NamedQueueStreamed")
+            .field("handle", &self.0.handle)
+            .finish()
+    }
+}
+
+impl<T> Display for NamedQueueStreamed<T> 
+where 
+    T: ToBytes + BytesHasLen + FromBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NamedQueueStreamed {{ handle: {} }}", self.0.handle)
+    }
+}
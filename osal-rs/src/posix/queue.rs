@@ -0,0 +1,436 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::fmt::{Debug, Display};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::ptr::null_mut;
+
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::ffi::QueueHandle;
+use super::queue_set;
+use super::sync::Monitor;
+use super::system::System;
+use super::types::{BaseType, UBaseType, TickType};
+use crate::traits::{ToTick, QueueFn, QueueStreamedFn, SystemFn, ToBytes, BytesHasLen, FromBytes};
+use crate::utils::{Result, Error};
+
+/// Where `Queue::send` places an item, as `xCopyPosition` of FreeRTOS
+#[derive(Clone, Copy, PartialEq)]
+enum Position {
+    Back,
+    Front,
+    /// Back, replacing the newest item of a full queue
+    Overwrite,
+}
+
+/// Fixed-size ring buffer of `size` slots, `message_size` bytes each
+struct Ring {
+    buffer: Vec<u8>,
+    message_size: usize,
+    size: usize,
+    head: usize,
+    count: usize,
+}
+
+impl Ring {
+    #[inline]
+    fn is_full(&self) -> bool {
+        self.count == self.size
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Copies `item` into slot `index`, a shorter item is zero padded
+    fn write(&mut self, index: usize, item: &[u8]) {
+        let start = index * self.message_size;
+        let slot = &mut self.buffer[start..start + self.message_size];
+        let len = item.len().min(self.message_size);
+        slot[..len].copy_from_slice(&item[..len]);
+        slot[len..].fill(0);
+    }
+
+    /// Adds `item` at `position`, the ring must not be full unless overwriting
+    fn push(&mut self, item: &[u8], position: Position) {
+        match position {
+            Position::Front => {
+                self.head = (self.head + self.size - 1) % self.size;
+                self.write(self.head, item);
+                self.count += 1;
+            }
+            Position::Overwrite if self.is_full() => {
+                let newest = (self.head + self.count - 1) % self.size;
+                self.write(newest, item);
+            }
+            _ => {
+                self.write((self.head + self.count) % self.size, item);
+                self.count += 1;
+            }
+        }
+    }
+
+    /// Copies the head slot into `buffer`, truncated to the shorter of the two
+    fn peek(&self, buffer: &mut [u8]) {
+        let start = self.head * self.message_size;
+        let len = buffer.len().min(self.message_size);
+        buffer[..len].copy_from_slice(&self.buffer[start..start + len]);
+    }
+
+    fn pop(&mut self, buffer: &mut [u8]) {
+        self.peek(buffer);
+        self.head = (self.head + 1) % self.size;
+        self.count -= 1;
+    }
+}
+
+/// Bounded message queue, the handle points to the `Monitor` holding the ring buffer
+pub struct Queue (QueueHandle);
+
+unsafe impl Send for Queue {}
+unsafe impl Sync for Queue {}
+
+impl Queue {
+    #[inline]
+    pub fn fetch_with_to_tick(&self, buffer: &mut [u8], time: impl ToTick) -> Result<()> {
+        self.fetch(buffer, time.to_ticks())
+    }
+
+    #[inline]
+    pub fn post_with_to_tick(&self, item: &[u8], time: impl ToTick) -> Result<()> {
+        self.post(item, time.to_ticks())
+    }
+
+    #[inline]
+    fn inner(&self) -> &Monitor<Ring> {
+        unsafe { &*(self.0 as *const Monitor<Ring>) }
+    }
+
+    /// Returns whether a thread waiting on the queue was woken, a peek
+    /// leaves the message in place and wakes nobody
+    fn receive(&self, buffer: &mut [u8], time: TickType, peek: bool) -> Result<bool> {
+        let mut ring = self.inner().lock();
+        if !ring.wait_while(time, |ring| ring.is_empty()) {
+            return Err(Error::Timeout);
+        }
+
+        if peek {
+            ring.peek(buffer);
+            return Ok(false);
+        }
+
+        ring.pop(buffer);
+        // Producers and consumers share the condition variable
+        ring.notify_all();
+        Ok(ring.has_waiters())
+    }
+
+    /// Returns whether a thread waiting on the queue was woken
+    fn send(&self, item: &[u8], time: TickType, position: Position) -> Result<bool> {
+        let mut ring = self.inner().lock();
+        if position != Position::Overwrite && !ring.wait_while(time, |ring| ring.is_full()) {
+            return Err(Error::Timeout);
+        }
+
+        // Replacing an item makes nothing new available to the set
+        let added = position != Position::Overwrite || !ring.is_full();
+        ring.push(item, position);
+        ring.notify_all();
+        let woken = ring.has_waiters();
+        drop(ring);
+
+        if added {
+            return Ok(queue_set::notify(self.inner().queue_set(), self.0) || woken);
+        }
+        Ok(woken)
+    }
+
+    /// Never blocks: fails with `Error::Timeout` if the queue is full
+    fn send_from_isr(&self, item: &[u8], position: Position) -> Result<()> {
+        let woken = self.send(item, 0, position)?;
+        System::yield_from_isr(woken as BaseType);
+        Ok(())
+    }
+}
+
+impl QueueFn for Queue {
+    fn new (size: UBaseType, message_size: UBaseType) -> Result<Self> {
+        if size == 0 {
+            return Err(Error::Unhandled("Invalid queue size"));
+        }
+
+        let size = size as usize;
+        let message_size = message_size as usize;
+        let buffer_len = size.checked_mul(message_size).ok_or(Error::OutOfMemory)?;
+
+        let monitor = Monitor::new(Ring {
+            buffer: vec![0u8; buffer_len],
+            message_size,
+            size,
+            head: 0,
+            count: 0,
+        })?;
+        Ok(Self (Box::into_raw(Box::new(monitor)) as QueueHandle))
+    }
+
+    #[inline]
+    fn fetch(&self, buffer: &mut [u8], time: TickType) -> Result<()> {
+        self.receive(buffer, time, false).map(|_| ())
+    }
+
+    /// Never blocks: fails with `Error::Timeout` if the queue is empty
+    fn fetch_from_isr(&self, buffer: &mut [u8]) -> Result<()> {
+        let woken = self.receive(buffer, 0, false)?;
+        System::yield_from_isr(woken as BaseType);
+        Ok(())
+    }
+
+    #[inline]
+    fn post(&self, item: &[u8], time: TickType) -> Result<()> {
+        self.send(item, time, Position::Back).map(|_| ())
+    }
+
+    #[inline]
+    fn post_from_isr(&self, item: &[u8]) -> Result<()> {
+        self.send_from_isr(item, Position::Back)
+    }
+
+    #[inline]
+    fn post_to_front(&self, item: &[u8], time: TickType) -> Result<()> {
+        self.send(item, time, Position::Front).map(|_| ())
+    }
+
+    #[inline]
+    fn post_to_front_from_isr(&self, item: &[u8]) -> Result<()> {
+        self.send_from_isr(item, Position::Front)
+    }
+
+    /// On a full queue longer than 1, the newest message is replaced
+    #[inline]
+    fn overwrite(&self, item: &[u8]) -> Result<()> {
+        self.send(item, 0, Position::Overwrite).map(|_| ())
+    }
+
+    #[inline]
+    fn overwrite_from_isr(&self, item: &[u8]) -> Result<()> {
+        self.send_from_isr(item, Position::Overwrite)
+    }
+
+    #[inline]
+    fn peek(&self, buffer: &mut [u8], time: TickType) -> Result<()> {
+        self.receive(buffer, time, true).map(|_| ())
+    }
+
+    /// Never blocks: fails with `Error::Timeout` if the queue is empty
+    #[inline]
+    fn peek_from_isr(&self, buffer: &mut [u8]) -> Result<()> {
+        self.receive(buffer, 0, true).map(|_| ())
+    }
+
+    fn reset(&self) -> Result<()> {
+        let mut ring = self.inner().lock();
+        ring.head = 0;
+        ring.count = 0;
+        // Wakes the producers waiting for room
+        ring.notify_all();
+        Ok(())
+    }
+
+    fn delete(&mut self) {
+        drop(unsafe { Box::from_raw(self.0 as *mut Monitor<Ring>) });
+        self.0 = null_mut();
+    }
+}
+
+impl Drop for Queue {
+    fn drop(&mut self) {
+        if self.0.is_null() {
+            return;
+        }
+        self.delete();
+    }
+}
+
+impl Deref for Queue {
+    type Target = QueueHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for Queue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Queue")
+            .field("handle", &self.0)
+            .finish()
+    }
+}
+
+impl Display for Queue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Queue {{ handle: {:?} }}", self.0)
+    }
+}
+
+pub struct QueueStreamed<T: ToBytes + BytesHasLen + FromBytes> (Queue, PhantomData<T>);
+
+unsafe impl<T: ToBytes + BytesHasLen + FromBytes> Send for QueueStreamed<T> {}
+unsafe impl<T: ToBytes + BytesHasLen + FromBytes> Sync for QueueStreamed<T> {}
+
+impl<T> QueueStreamed<T> 
+where 
+    T: ToBytes + BytesHasLen + FromBytes {
+    #[inline]
+    fn fetch_with_to_tick(&self, buffer: &mut T, time: impl ToTick) -> Result<()> {
+        self.fetch(buffer, time.to_ticks())
+    }
+
+    #[inline]
+    fn post_with_to_tick(&self, item: &T, time: impl ToTick) -> Result<()> {
+        self.post(item, time.to_ticks())
+    }
+}
+
+impl<T> QueueStreamedFn<T> for QueueStreamed<T> 
+where 
+    T: ToBytes + BytesHasLen + FromBytes {
+
+    #[inline]
+    fn new (size: UBaseType, message_size: UBaseType) -> Result<Self> {
+        Ok(Self (Queue::new(size, message_size)?, PhantomData))
+    }
+
+    fn fetch(&self, buffer: &mut T, time: TickType) -> Result<()> {
+        let mut buf_bytes = vec![0u8; buffer.len()];        
+
+        if let Ok(()) = self.0.fetch(&mut buf_bytes, time) {
+            *buffer = T::from_bytes(&buf_bytes)?;
+            Ok(())
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+
+    fn fetch_from_isr(&self, buffer: &mut T) -> Result<()> {
+        let mut buf_bytes = vec![0u8; buffer.len()];        
+
+        if let Ok(()) = self.0.fetch_from_isr(&mut buf_bytes) {
+            *buffer = T::from_bytes(&buf_bytes)?;
+            Ok(())
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+
+    #[inline]
+    fn post(&self, item: &T, time: TickType) -> Result<()> {
+        self.0.post(item.to_bytes(), time)
+    }
+
+    #[inline]
+    fn post_from_isr(&self, item: &T) -> Result<()> {
+        self.0.post_from_isr(item.to_bytes())
+    }
+
+    #[inline]
+    fn post_to_front(&self, item: &T, time: TickType) -> Result<()> {
+        self.0.post_to_front(item.to_bytes(), time)
+    }
+
+    #[inline]
+    fn post_to_front_from_isr(&self, item: &T) -> Result<()> {
+        self.0.post_to_front_from_isr(item.to_bytes())
+    }
+
+    #[inline]
+    fn overwrite(&self, item: &T) -> Result<()> {
+        self.0.overwrite(item.to_bytes())
+    }
+
+    #[inline]
+    fn overwrite_from_isr(&self, item: &T) -> Result<()> {
+        self.0.overwrite_from_isr(item.to_bytes())
+    }
+
+    fn peek(&self, buffer: &mut T, time: TickType) -> Result<()> {
+        let mut buf_bytes = vec![0u8; buffer.len()];        
+
+        if let Ok(()) = self.0.peek(&mut buf_bytes, time) {
+            *buffer = T::from_bytes(&buf_bytes)?;
+            Ok(())
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+
+    fn peek_from_isr(&self, buffer: &mut T) -> Result<()> {
+        let mut buf_bytes = vec![0u8; buffer.len()];        
+
+        if let Ok(()) = self.0.peek_from_isr(&mut buf_bytes) {
+            *buffer = T::from_bytes(&buf_bytes)?;
+            Ok(())
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+
+    #[inline]
+    fn reset(&self) -> Result<()> {
+        self.0.reset()
+    }
+
+    #[inline]
+    fn delete(&mut self) {
+        self.0.delete()
+    }
+}
+
+impl<T> Deref for QueueStreamed<T> 
+where 
+    T: ToBytes + BytesHasLen + FromBytes {
+    type Target = QueueHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.0
+    }   
+}
+
+impl<T> Debug for QueueStreamed<T> 
+where 
+    T: ToBytes + BytesHasLen + FromBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("QueueStreamed")
+            .field("handle", &self.0.0)
+            .finish()
+    }
+}
+
+impl<T> Display for QueueStreamed<T> 
+where 
+    T: ToBytes + BytesHasLen + FromBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "QueueStreamed {{ handle: {:?} }}", self.0.0)
+    }
+}
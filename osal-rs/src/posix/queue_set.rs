@@ -0,0 +1,196 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::fmt::{Debug, Display};
+use core::ops::Deref;
+use core::ptr::null_mut;
+use core::sync::atomic::Ordering;
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+
+use super::ffi::{QueueSetHandle, QueueSetMemberHandle};
+use super::queue::{Queue, QueueStreamed};
+use super::semaphore::{BinarySemaphore, Semaphore};
+use super::sync::{Monitor, queue_set_link};
+use super::types::{TickType, UBaseType};
+use crate::traits::{QueueSetFn, QueueSetMember, ToTick, ToBytes, BytesHasLen, FromBytes};
+use crate::utils::{Result, Error};
+
+/// Members that gained an item, oldest first, one entry per item
+struct Ready {
+    members: VecDeque<QueueSetMemberHandle>,
+    size: usize,
+}
+
+/// Queue set, the handle points to the `Monitor` holding the ready members.
+///
+/// Members record the set in their own `Monitor`, and report every item
+/// they gain through `notify`.
+pub struct QueueSet (QueueSetHandle);
+
+unsafe impl Send for QueueSet {}
+unsafe impl Sync for QueueSet {}
+
+impl QueueSet {
+    #[inline]
+    pub fn select_with_to_tick(&self, time: impl ToTick) -> Result<QueueSetMemberHandle> {
+        self.select(time.to_ticks())
+    }
+
+    #[inline]
+    fn inner(&self) -> &Monitor<Ready> {
+        unsafe { &*(self.0 as *const Monitor<Ready>) }
+    }
+}
+
+/// Reports an item gained by `member` to `set`, if not null. Returns
+/// whether a thread blocked in `select` was woken.
+///
+/// Called without the lock of the member held: with `virtual_time` every
+/// monitor shares the same lock.
+pub(crate) fn notify(set: QueueSetHandle, member: QueueSetMemberHandle) -> bool {
+    if set.is_null() {
+        return false;
+    }
+
+    let mut ready = unsafe { &*(set as *const Monitor<Ready>) }.lock();
+    // Cannot overflow when the set is sized for its members
+    if ready.members.len() < ready.size {
+        ready.members.push_back(member);
+    }
+    ready.notify_all();
+    ready.has_waiters()
+}
+
+impl QueueSetFn for QueueSet {
+    fn new(size: UBaseType) -> Result<Self> {
+        if size == 0 {
+            return Err(Error::InvalidQueueSize);
+        }
+
+        let monitor = Monitor::new(Ready {
+            members: VecDeque::with_capacity(size as usize),
+            size: size as usize,
+        })?;
+        Ok(Self (Box::into_raw(Box::new(monitor)) as QueueSetHandle))
+    }
+
+    /// Fails if the member already belongs to a set. Unlike FreeRTOS, items
+    /// already waiting in the member are not checked: they are not reported
+    fn add(&self, member: &impl QueueSetMember) -> Result<()> {
+        let link = unsafe { queue_set_link(member.member_handle()) };
+        link.compare_exchange(null_mut(), self.0 as *mut _, Ordering::AcqRel, Ordering::Acquire)
+            .map(|_| ())
+            .map_err(|_| Error::Unhandled("Failed to add to the queue set"))
+    }
+
+    /// Fails if the member does not belong to the set, the items it still
+    /// holds are no longer reported
+    fn remove(&self, member: &impl QueueSetMember) -> Result<()> {
+        let handle = member.member_handle();
+        let link = unsafe { queue_set_link(handle) };
+        link.compare_exchange(self.0 as *mut _, null_mut(), Ordering::AcqRel, Ordering::Acquire)
+            .map_err(|_| Error::Unhandled("Failed to remove from the queue set"))?;
+
+        self.inner().lock().members.retain(|&member| member != handle);
+        Ok(())
+    }
+
+    fn select(&self, time: TickType) -> Result<QueueSetMemberHandle> {
+        let mut ready = self.inner().lock();
+        if !ready.wait_while(time, |ready| ready.members.is_empty()) {
+            return Err(Error::Timeout);
+        }
+
+        ready.members.pop_front().ok_or(Error::Timeout)
+    }
+
+    /// Never blocks: fails with `Error::Timeout` if no member is ready
+    #[inline]
+    fn select_from_isr(&self) -> Result<QueueSetMemberHandle> {
+        self.select(0)
+    }
+
+    fn delete(&mut self) {
+        drop(unsafe { Box::from_raw(self.0 as *mut Monitor<Ready>) });
+        self.0 = null_mut();
+    }
+}
+
+impl Drop for QueueSet {
+    fn drop(&mut self) {
+        if self.0.is_null() {
+            return;
+        }
+        self.delete();
+    }
+}
+
+impl Deref for QueueSet {
+    type Target = QueueSetHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for QueueSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("QueueSet")
+            .field("handle", &self.0)
+            .finish()
+    }
+}
+
+impl Display for QueueSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "QueueSet {{ handle: {:?} }}", self.0)
+    }
+}
+
+impl QueueSetMember for Queue {
+    #[inline]
+    fn member_handle(&self) -> QueueSetMemberHandle {
+        **self
+    }
+}
+
+impl<T> QueueSetMember for QueueStreamed<T> 
+where 
+    T: ToBytes + BytesHasLen + FromBytes {
+    #[inline]
+    fn member_handle(&self) -> QueueSetMemberHandle {
+        **self
+    }
+}
+
+impl QueueSetMember for Semaphore {
+    #[inline]
+    fn member_handle(&self) -> QueueSetMemberHandle {
+        **self
+    }
+}
+
+impl QueueSetMember for BinarySemaphore {
+    #[inline]
+    fn member_handle(&self) -> QueueSetMemberHandle {
+        **self
+    }
+}
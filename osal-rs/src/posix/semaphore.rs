@@ -0,0 +1,204 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::fmt::{Debug, Display};
+use core::ops::Deref;
+use core::ptr::null_mut;
+
+use alloc::boxed::Box;
+
+use super::ffi::SemaphoreHandle;
+use super::queue_set;
+use super::sync::Monitor;
+use super::system::System;
+use super::types::{BaseType, TickType, UBaseType};
+use crate::traits::{BinarySemaphoreFn, SemaphoreFn, SystemFn, ToTick};
+use crate::utils::{Error, Result, OsalRsBool};
+
+struct Counter {
+    count: UBaseType,
+    max_count: UBaseType,
+}
+
+/// Counting semaphore, the handle points to the `Monitor` holding the count
+pub struct Semaphore (SemaphoreHandle);
+
+unsafe impl Send for Semaphore {}
+unsafe impl Sync for Semaphore {}
+
+impl Semaphore {
+    fn create(max_count: UBaseType, initial_count: UBaseType) -> Result<Self> {
+        if max_count == 0 || initial_count > max_count {
+            return Err(Error::Unhandled("Invalid semaphore count"));
+        }
+
+        let monitor = Monitor::new(Counter { count: initial_count, max_count })?;
+        Ok(Self (Box::into_raw(Box::new(monitor)) as SemaphoreHandle))
+    }
+
+    #[inline]
+    fn inner(&self) -> &Monitor<Counter> {
+        unsafe { &*(self.0 as *const Monitor<Counter>) }
+    }
+
+    /// Increments the count, returns whether a waiting thread was woken
+    fn give(&self) -> (OsalRsBool, bool) {
+        let mut counter = self.inner().lock();
+        if counter.count < counter.max_count {
+            counter.count += 1;
+            counter.notify_one();
+            let woken = counter.has_waiters();
+            drop(counter);
+
+            (OsalRsBool::True, queue_set::notify(self.inner().queue_set(), self.0) || woken)
+        } else {
+            (OsalRsBool::False, false)
+        }
+    }
+
+    fn take(&self, ticks_to_wait: TickType) -> OsalRsBool {
+        let mut counter = self.inner().lock();
+        if counter.wait_while(ticks_to_wait, |counter| counter.count == 0) {
+            counter.count -= 1;
+            OsalRsBool::True
+        } else {
+            OsalRsBool::False
+        }
+    }
+}
+
+impl SemaphoreFn for Semaphore {
+    fn new(max_count: UBaseType, initial_count: UBaseType) -> Result<Self> {
+        Self::create(max_count, initial_count)
+    }
+
+    fn new_with_count(initial_count: UBaseType) -> Result<Self> {
+        Self::create(UBaseType::MAX, initial_count)
+    }
+
+    fn wait(&self, ticks_to_wait: impl ToTick) -> OsalRsBool {
+        self.take(ticks_to_wait.to_ticks())
+    }
+
+    /// Never blocks: fails immediately if the count is zero
+    fn wait_from_isr(&self) -> OsalRsBool {
+        self.take(0)
+    }
+    
+    fn signal(&self) -> OsalRsBool {
+        self.give().0
+    }
+    
+    /// Never blocks, requests a context switch if a waiting thread was woken
+    fn signal_from_isr(&self) -> OsalRsBool {
+        let (ret, woken) = self.give();
+        System::yield_from_isr(woken as BaseType);
+        ret
+    }
+    
+    fn delete(&mut self) {
+        drop(unsafe { Box::from_raw(self.0 as *mut Monitor<Counter>) });
+        self.0 = null_mut();
+    }
+
+
+}
+
+
+impl Drop for Semaphore {
+    fn drop(&mut self) {
+        if self.0.is_null() {
+            return;
+        }
+        self.delete();
+    }
+}
+
+impl Deref for Semaphore {
+    type Target = SemaphoreHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for Semaphore {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Semaphore")
+            .field("handle", &self.0)
+            .finish()
+    }
+}
+
+impl Display for Semaphore {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Semaphore {{ handle: {:?} }}", self.0)
+    }
+}
+
+/// Binary semaphore, a counting `Semaphore` with a maximum count of one
+pub struct BinarySemaphore (Semaphore);
+
+impl BinarySemaphoreFn for BinarySemaphore {
+    fn new() -> Result<Self> {
+        Ok(Self (Semaphore::create(1, 0)?))
+    }
+
+    fn wait(&self, ticks_to_wait: impl ToTick) -> OsalRsBool {
+        self.0.wait(ticks_to_wait)
+    }
+
+    fn wait_from_isr(&self) -> OsalRsBool {
+        self.0.wait_from_isr()
+    }
+
+    fn signal(&self) -> OsalRsBool {
+        self.0.signal()
+    }
+
+    fn signal_from_isr(&self) -> OsalRsBool {
+        self.0.signal_from_isr()
+    }
+
+    fn delete(&mut self) {
+        self.0.delete();
+    }
+}
+
+impl Deref for BinarySemaphore {
+    type Target = SemaphoreHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.0
+    }
+}
+
+impl Debug for BinarySemaphore {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BinarySemaphore")
+            .field("handle", &self.0.0)
+            .finish()
+    }
+}
+
+impl Display for BinarySemaphore {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "BinarySemaphore {{ handle: {:?} }}", self.0.0)
+    }
+}
@@ -0,0 +1,350 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::fmt::{Debug, Display};
+use core::ops::Deref;
+use core::ptr::null_mut;
+
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::ffi::StreamBufferHandle;
+use super::sync::Monitor;
+use super::system::System;
+use super::types::{BaseType, TickType};
+use crate::traits::{StreamBufferFn, SystemFn, ToTick};
+use crate::utils::{Result, Error, MAX_DELAY};
+
+/// Circular byte buffer, also holding the messages of a `MessageBuffer`
+pub(crate) struct Stream {
+    buffer: Vec<u8>,
+    head: usize,
+    count: usize,
+    trigger_level: usize,
+}
+
+impl Stream {
+    pub(crate) fn new(size: usize, trigger_level: usize) -> Self {
+        Self {
+            buffer: vec![0u8; size],
+            head: 0,
+            count: 0,
+            trigger_level: trigger_level.max(1),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.count
+    }
+
+    #[inline]
+    pub(crate) fn spaces(&self) -> usize {
+        self.buffer.len() - self.count
+    }
+
+    /// Appends as much of `data` as fits, returns the number of bytes written
+    pub(crate) fn write(&mut self, data: &[u8]) -> usize {
+        let size = self.buffer.len();
+        let len = data.len().min(self.spaces());
+        let tail = (self.head + self.count) % size;
+        let first = len.min(size - tail);
+        self.buffer[tail..tail + first].copy_from_slice(&data[..first]);
+        self.buffer[..len - first].copy_from_slice(&data[first..len]);
+        self.count += len;
+        len
+    }
+
+    /// Copies as many bytes as fit into `buffer` without removing them,
+    /// returns their number
+    pub(crate) fn peek(&self, buffer: &mut [u8]) -> usize {
+        let size = self.buffer.len();
+        let len = buffer.len().min(self.count);
+        let first = len.min(size - self.head);
+        buffer[..first].copy_from_slice(&self.buffer[self.head..self.head + first]);
+        buffer[first..len].copy_from_slice(&self.buffer[..len - first]);
+        len
+    }
+
+    /// Moves as many bytes as fit into `buffer`, returns their number
+    pub(crate) fn read(&mut self, buffer: &mut [u8]) -> usize {
+        let len = self.peek(buffer);
+        self.discard(len);
+        len
+    }
+
+    /// Drops the `len` oldest bytes
+    pub(crate) fn discard(&mut self, len: usize) {
+        self.head = (self.head + len) % self.buffer.len();
+        self.count -= len;
+    }
+
+    /// Empties the buffer
+    pub(crate) fn clear(&mut self) {
+        self.head = 0;
+        self.count = 0;
+    }
+}
+
+/// Stream buffer, the handle points to the `Monitor` holding the bytes.
+/// Usable through `embedded_io::Read` and `Write`
+pub struct StreamBuffer (StreamBufferHandle);
+
+unsafe impl Send for StreamBuffer {}
+unsafe impl Sync for StreamBuffer {}
+
+impl StreamBuffer {
+    #[inline]
+    fn inner(&self) -> &Monitor<Stream> {
+        unsafe { &*(self.0 as *const Monitor<Stream>) }
+    }
+
+    /// Returns the bytes written and whether a thread waiting on the buffer
+    /// was woken
+    fn transmit(&self, data: &[u8], time: TickType) -> (usize, bool) {
+        let mut stream = self.inner().lock();
+        // As FreeRTOS, data larger than the buffer waits for an empty buffer
+        let required = data.len().min(stream.buffer.len());
+        stream.wait_while(time, |stream| stream.spaces() < required);
+
+        let sent = stream.write(data);
+        if sent == 0 {
+            return (0, false);
+        }
+        stream.notify_all();
+        (sent, stream.has_waiters())
+    }
+
+    /// Returns the bytes read and whether a thread waiting on the buffer
+    /// was woken
+    fn collect(&self, buffer: &mut [u8], time: TickType) -> (usize, bool) {
+        if buffer.is_empty() {
+            return (0, false);
+        }
+
+        let mut stream = self.inner().lock();
+        // Only an empty buffer waits for the trigger level, on timeout the
+        // bytes that arrived meanwhile are returned
+        if stream.count == 0 {
+            stream.wait_while(time, |stream| stream.count < stream.trigger_level);
+        }
+
+        let received = stream.read(buffer);
+        if received == 0 {
+            return (0, false);
+        }
+        stream.notify_all();
+        (received, stream.has_waiters())
+    }
+}
+
+impl StreamBufferFn for StreamBuffer {
+    fn new(size: usize, trigger_level: usize) -> Result<Self> {
+        if size == 0 || trigger_level > size {
+            return Err(Error::InvalidQueueSize);
+        }
+
+        let monitor = Monitor::new(Stream::new(size, trigger_level))?;
+        Ok(Self (Box::into_raw(Box::new(monitor)) as StreamBufferHandle))
+    }
+
+    #[inline]
+    fn send(&self, data: &[u8], ticks_to_wait: impl ToTick) -> usize {
+        self.transmit(data, ticks_to_wait.to_ticks()).0
+    }
+
+    /// Never blocks: writes as much of `data` as fits
+    fn send_from_isr(&self, data: &[u8]) -> usize {
+        let (sent, woken) = self.transmit(data, 0);
+        System::yield_from_isr(woken as BaseType);
+        sent
+    }
+
+    #[inline]
+    fn receive(&self, buffer: &mut [u8], ticks_to_wait: impl ToTick) -> usize {
+        self.collect(buffer, ticks_to_wait.to_ticks()).0
+    }
+
+    /// Never blocks: returns 0 if the buffer is empty
+    fn receive_from_isr(&self, buffer: &mut [u8]) -> usize {
+        let (received, woken) = self.collect(buffer, 0);
+        System::yield_from_isr(woken as BaseType);
+        received
+    }
+
+    #[inline]
+    fn bytes_available(&self) -> usize {
+        self.inner().lock().count
+    }
+
+    #[inline]
+    fn spaces_available(&self) -> usize {
+        self.inner().lock().spaces()
+    }
+
+    fn set_trigger_level(&self, trigger_level: usize) -> Result<()> {
+        let mut stream = self.inner().lock();
+        if trigger_level > stream.buffer.len() {
+            return Err(Error::InvalidQueueSize);
+        }
+        stream.trigger_level = trigger_level.max(1);
+        Ok(())
+    }
+
+    fn reset(&self) -> Result<()> {
+        let mut stream = self.inner().lock();
+        if stream.has_waiters() {
+            return Err(Error::Unhandled("Stream buffer reset while a task is blocked on it"));
+        }
+        stream.clear();
+        Ok(())
+    }
+
+    fn delete(&mut self) {
+        drop(unsafe { Box::from_raw(self.0 as *mut Monitor<Stream>) });
+        self.0 = null_mut();
+    }
+}
+
+impl Drop for StreamBuffer {
+    fn drop(&mut self) {
+        if self.0.is_null() {
+            return;
+        }
+        self.delete();
+    }
+}
+
+impl Deref for StreamBuffer {
+    type Target = StreamBufferHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for StreamBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StreamBuffer")
+            .field("handle", &self.0)
+            .finish()
+    }
+}
+
+impl Display for StreamBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "StreamBuffer {{ handle: {:?} }}", self.0)
+    }
+}
+
+impl embedded_io::ErrorType for &StreamBuffer {
+    type Error = Error;
+}
+
+/// Blocks until at least one byte is available
+impl embedded_io::Read for &StreamBuffer {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let received = self.receive(buf, MAX_DELAY);
+            if received > 0 {
+                return Ok(received);
+            }
+        }
+    }
+}
+
+impl embedded_io::ReadReady for &StreamBuffer {
+    fn read_ready(&mut self) -> Result<bool> {
+        Ok(self.bytes_available() > 0)
+    }
+}
+
+/// Blocks until at least one byte is written
+impl embedded_io::Write for &StreamBuffer {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let sent = self.send(buf, MAX_DELAY);
+            if sent > 0 {
+                return Ok(sent);
+            }
+        }
+    }
+
+    /// The data is handed to the reader as soon as it is written
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl embedded_io::WriteReady for &StreamBuffer {
+    fn write_ready(&mut self) -> Result<bool> {
+        Ok(self.spaces_available() > 0)
+    }
+}
+
+impl embedded_io::ErrorType for StreamBuffer {
+    type Error = Error;
+}
+
+impl embedded_io::Read for StreamBuffer {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        (&*self).read(buf)
+    }
+}
+
+impl embedded_io::ReadReady for StreamBuffer {
+    #[inline]
+    fn read_ready(&mut self) -> Result<bool> {
+        (&*self).read_ready()
+    }
+}
+
+impl embedded_io::Write for StreamBuffer {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        (&*self).write(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        (&*self).flush()
+    }
+}
+
+impl embedded_io::WriteReady for StreamBuffer {
+    #[inline]
+    fn write_ready(&mut self) -> Result<bool> {
+        (&*self).write_ready()
+    }
+}
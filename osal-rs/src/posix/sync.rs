@@ -0,0 +1,315 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::cell::UnsafeCell;
+use core::ffi::{c_long, c_void};
+use core::ops::{Deref, DerefMut};
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use alloc::boxed::Box;
+
+use super::ffi::{CLOCK_MONOTONIC, NSEC_PER_SEC, clock_gettime, clockid_t, pthread_cond_broadcast, pthread_cond_destroy, pthread_cond_init, pthread_cond_signal, pthread_cond_t, pthread_cond_timedwait, pthread_cond_wait, pthread_condattr_destroy, pthread_condattr_init, pthread_condattr_setclock, pthread_condattr_t, pthread_equal, pthread_mutex_destroy, pthread_mutex_init, pthread_mutex_lock, pthread_mutex_t, pthread_mutex_unlock, pthread_self, pthread_t, time_t, timespec};
+use super::isr::Interrupt;
+use super::thread::{hold_suspend, release_suspend};
+use super::types::TickType;
+use super::virtual_time;
+use crate::tick_rate_hz;
+use crate::utils::{Error, Result};
+
+/// Timeout meaning "block forever", as `portMAX_DELAY` with `INCLUDE_vTaskSuspend`
+pub(crate) const WAIT_FOREVER: TickType = TickType::MAX;
+
+/// A value protected by a mutex, with a condition variable to wait for it
+/// to change. Every blocking POSIX primitive is built on top of it.
+///
+/// With the `virtual_time` feature every monitor uses the lock of the virtual
+/// clock instead of its own mutex and condition variable.
+///
+/// A thread holding a monitor is not suspended until it releases it.
+///
+/// The queue set link comes first, so that `queue_set_link` reaches it from
+/// the handle of any object whatever its `T`.
+#[repr(C)]
+pub(crate) struct Monitor<T> {
+    /// `QueueSet` notified when the value gains an item, null if none
+    set: AtomicPtr<c_void>,
+    mutex: Box<UnsafeCell<pthread_mutex_t>>,
+    cond: Box<UnsafeCell<pthread_cond_t>>,
+    /// Threads blocked in `wait_while`, only accessed with the mutex held
+    waiters: UnsafeCell<u32>,
+    value: UnsafeCell<T>,
+}
+
+unsafe impl<T: Send> Send for Monitor<T> {}
+unsafe impl<T: Send> Sync for Monitor<T> {}
+
+impl<T> Monitor<T> {
+    pub(crate) fn new(value: T) -> Result<Self> {
+        let monitor = Self {
+            set: AtomicPtr::new(null_mut()),
+            mutex: Box::new(UnsafeCell::new(pthread_mutex_t::new())),
+            cond: Box::new(UnsafeCell::new(pthread_cond_t::new())),
+            waiters: UnsafeCell::new(0),
+            value: UnsafeCell::new(value),
+        };
+
+        let mut attr = pthread_condattr_t::new();
+        let ret = unsafe {
+            pthread_mutex_init(monitor.mutex.get(), core::ptr::null());
+            pthread_condattr_init(&mut attr);
+            // Timed waits are measured on the same clock as the tick count
+            pthread_condattr_setclock(&mut attr, CLOCK_MONOTONIC);
+            let ret = pthread_cond_init(monitor.cond.get(), &attr);
+            pthread_condattr_destroy(&mut attr);
+            ret
+        };
+
+        if ret != 0 {
+            return Err(Error::OutOfMemory);
+        }
+        Ok(monitor)
+    }
+
+    pub(crate) fn lock(&self) -> MonitorGuard<'_, T> {
+        if virtual_time::ENABLED {
+            virtual_time::lock();
+        } else {
+            hold_suspend();
+            unsafe { pthread_mutex_lock(self.mutex.get()); }
+        }
+        MonitorGuard { monitor: self }
+    }
+
+    /// Handle of the `QueueSet` the object belongs to, null if none
+    #[inline]
+    pub(crate) fn queue_set(&self) -> *const c_void {
+        self.set.load(Ordering::Acquire)
+    }
+}
+
+/// Queue set link of the object whose handle points to a `Monitor`
+///
+/// # Safety
+/// `handle` must point to a live `Monitor<T>`, of any `T`
+pub(crate) unsafe fn queue_set_link<'a>(handle: *const c_void) -> &'a AtomicPtr<c_void> {
+    unsafe { &*(handle as *const AtomicPtr<c_void>) }
+}
+
+impl<T> Drop for Monitor<T> {
+    fn drop(&mut self) {
+        unsafe {
+            pthread_cond_destroy(self.cond.get());
+            pthread_mutex_destroy(self.mutex.get());
+        }
+    }
+}
+
+pub(crate) struct MonitorGuard<'a, T> {
+    monitor: &'a Monitor<T>,
+}
+
+impl<T> MonitorGuard<'_, T> {
+    /// Wakes one thread blocked in `wait_while`
+    #[inline]
+    pub(crate) fn notify_one(&self) {
+        if virtual_time::ENABLED {
+            virtual_time::wake_all();
+        } else {
+            unsafe { pthread_cond_signal(self.monitor.cond.get()); }
+        }
+    }
+
+    /// Wakes every thread blocked in `wait_while`
+    #[inline]
+    pub(crate) fn notify_all(&self) {
+        if virtual_time::ENABLED {
+            virtual_time::wake_all();
+        } else {
+            unsafe { pthread_cond_broadcast(self.monitor.cond.get()); }
+        }
+    }
+
+    /// Whether any thread is blocked in `wait_while`, i.e. whether a
+    /// notification would wake somebody up
+    #[inline]
+    pub(crate) fn has_waiters(&self) -> bool {
+        unsafe { *self.monitor.waiters.get() > 0 }
+    }
+
+    /// Blocks while `condition` holds, for at most `ticks` ticks.
+    ///
+    /// Returns `true` if the condition was released and `false` on timeout.
+    /// A timeout of 0 never blocks and `WAIT_FOREVER` never times out.
+    /// Blocking is forbidden in interrupt context: there every timeout is 0.
+    pub(crate) fn wait_while<F>(&mut self, ticks: TickType, mut condition: F) -> bool
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        if !condition(&mut **self) {
+            return true;
+        }
+        if ticks == 0 || Interrupt::is_in_isr() {
+            return false;
+        }
+        if virtual_time::ENABLED {
+            return self.wait_while_virtual(ticks, condition);
+        }
+
+        let deadline = if ticks == WAIT_FOREVER { None } else { Some(deadline_after(ticks)) };
+
+        unsafe { *self.monitor.waiters.get() += 1; }
+        let mut released = true;
+        while condition(&mut **self) {
+            match &deadline {
+                None => unsafe {
+                    pthread_cond_wait(self.monitor.cond.get(), self.monitor.mutex.get());
+                },
+                Some(deadline) => {
+                    let ret = unsafe { pthread_cond_timedwait(self.monitor.cond.get(), self.monitor.mutex.get(), deadline) };
+                    if ret != 0 {
+                        released = !condition(&mut **self);
+                        break;
+                    }
+                }
+            }
+        }
+        unsafe { *self.monitor.waiters.get() -= 1; }
+        released
+    }
+
+    /// `wait_while` on the virtual clock, every notification wakes all the
+    /// threads blocked on any monitor and they check their condition again
+    fn wait_while_virtual<F>(&mut self, ticks: TickType, mut condition: F) -> bool
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let deadline = if ticks == WAIT_FOREVER { None } else { Some(virtual_time::now() + ticks as u64) };
+
+        unsafe { *self.monitor.waiters.get() += 1; }
+        let mut released = true;
+        while condition(&mut **self) {
+            if deadline.is_some_and(|deadline| virtual_time::now() >= deadline) {
+                released = false;
+                break;
+            }
+            virtual_time::wait(deadline);
+        }
+        unsafe { *self.monitor.waiters.get() -= 1; }
+        released
+    }
+}
+
+impl<T> Deref for MonitorGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.monitor.value.get() }
+    }
+}
+
+impl<T> DerefMut for MonitorGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.monitor.value.get() }
+    }
+}
+
+impl<T> Drop for MonitorGuard<'_, T> {
+    fn drop(&mut self) {
+        if virtual_time::ENABLED {
+            virtual_time::unlock();
+        } else {
+            unsafe { pthread_mutex_unlock(self.monitor.mutex.get()); }
+            release_suspend();
+        }
+    }
+}
+
+/// Owner of an `OwnerLock` and its nesting depth, free when 0
+struct Ownership {
+    owner: pthread_t,
+    depth: u32,
+    recursive: bool,
+}
+
+/// Lock owned by the thread that takes it, built on `Monitor` so that a
+/// thread waiting for it blocks as on any other primitive (the virtual clock
+/// sees it as blocked). A non recursive lock taken again by its owner fails
+/// instead of deadlocking
+pub(crate) struct OwnerLock(Monitor<Ownership>);
+
+impl OwnerLock {
+    pub(crate) fn new(recursive: bool) -> Result<Self> {
+        Ok(Self(Monitor::new(Ownership { owner: 0, depth: 0, recursive })?))
+    }
+
+    /// Takes the lock, waiting at most `ticks` while another thread holds it
+    pub(crate) fn lock(&self, ticks: TickType) -> bool {
+        let me = unsafe { pthread_self() };
+        let mut ownership = self.0.lock();
+        if ownership.depth > 0 && unsafe { pthread_equal(ownership.owner, me) } != 0 {
+            if !ownership.recursive {
+                return false;
+            }
+            ownership.depth += 1;
+            return true;
+        }
+
+        if !ownership.wait_while(ticks, |ownership| ownership.depth > 0) {
+            return false;
+        }
+        ownership.owner = me;
+        ownership.depth = 1;
+        true
+    }
+
+    /// Fails if the calling thread does not hold the lock
+    pub(crate) fn unlock(&self) -> bool {
+        let mut ownership = self.0.lock();
+        if ownership.depth == 0 || unsafe { pthread_equal(ownership.owner, pthread_self()) } == 0 {
+            return false;
+        }
+
+        ownership.depth -= 1;
+        if ownership.depth == 0 {
+            ownership.notify_one();
+        }
+        true
+    }
+}
+
+/// Absolute `CLOCK_MONOTONIC` time `ticks` ticks from now
+#[inline]
+pub(crate) fn deadline_after(ticks: TickType) -> timespec {
+    deadline_on(CLOCK_MONOTONIC, ticks)
+}
+
+/// Absolute time of `clock` `ticks` ticks from now
+pub(crate) fn deadline_on(clock: clockid_t, ticks: TickType) -> timespec {
+    let mut now = timespec::default();
+    unsafe { clock_gettime(clock, &mut now); }
+
+    let delay_ns = ticks as u64 * NSEC_PER_SEC as u64 / tick_rate_hz!() as u64;
+    let nsec = now.tv_nsec as u64 + delay_ns % NSEC_PER_SEC as u64;
+
+    timespec {
+        tv_sec: now.tv_sec + (delay_ns / NSEC_PER_SEC as u64 + nsec / NSEC_PER_SEC as u64) as time_t,
+        tv_nsec: (nsec % NSEC_PER_SEC as u64) as c_long,
+    }
+}
@@ -0,0 +1,279 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::fmt::Debug;
+use core::ops::Deref;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use super::ffi::{CLOCK_MONOTONIC, EINTR, NSEC_PER_SEC, PTHREAD_ONCE_INIT, TIMER_ABSTIME, clock_gettime, clock_nanosleep, pthread_once, pthread_once_t, sched_yield, time_t, timespec};
+use super::isr::{Interrupt, request_yield};
+use super::sync::{OwnerLock, WAIT_FOREVER};
+use super::thread::{ThreadControl, ThreadState, ThreadMetadata, registered_threads};
+use super::types::{BaseType, ThreadHandle, TickType};
+use super::virtual_time;
+use crate::tick_rate_hz;
+use crate::traits::{SystemFn, ToTick};
+use crate::utils::{CpuRegisterSize::*, register_bit_size, OsalRsBool};
+
+#[derive(Debug, Clone)]
+pub struct SystemState {
+    pub tasks: Vec<ThreadMetadata>,
+    pub total_run_time: u32
+}
+
+impl Deref for SystemState {
+    type Target = [ThreadMetadata];
+
+    fn deref(&self) -> &Self::Target {
+        &self.tasks
+    }
+}
+
+static mut CRITICAL_SECTION: *const OwnerLock = core::ptr::null();
+
+static mut CRITICAL_SECTION_ONCE: pthread_once_t = PTHREAD_ONCE_INIT;
+
+/// `CLOCK_MONOTONIC` time in ns at which the tick count was 0, 0 until first use
+static EPOCH_NS: AtomicU64 = AtomicU64::new(0);
+
+extern "C" fn init_critical_section() {
+    // Nested critical sections are allowed, as with `taskENTER_CRITICAL`
+    if let Ok(lock) = OwnerLock::new(true) {
+        unsafe { CRITICAL_SECTION = Box::into_raw(Box::new(lock)); }
+    }
+}
+
+fn critical_section() -> &'static OwnerLock {
+    unsafe {
+        pthread_once(&raw mut CRITICAL_SECTION_ONCE, init_critical_section);
+        CRITICAL_SECTION.as_ref().expect("Critical section allocation failed")
+    }
+}
+
+pub struct System;
+
+impl System {
+    #[inline]
+    pub fn delay_with_to_tick(ticks: impl ToTick){
+        Self::delay(ticks.to_ticks());
+    }
+
+    #[inline]
+    pub fn delay_until_with_to_tick(previous_wake_time: &mut TickType, time_increment: impl ToTick) { 
+        Self::delay_until(previous_wake_time, time_increment.to_ticks());
+    }
+
+    fn monotonic_ns() -> u64 {
+        let mut now = timespec::default();
+        unsafe { clock_gettime(CLOCK_MONOTONIC, &mut now); }
+        now.tv_sec as u64 * NSEC_PER_SEC as u64 + now.tv_nsec as u64
+    }
+
+    fn epoch_ns() -> u64 {
+        let epoch = EPOCH_NS.load(Ordering::Acquire);
+        if epoch != 0 {
+            return epoch;
+        }
+
+        let now = Self::monotonic_ns();
+        match EPOCH_NS.compare_exchange(0, now, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => now,
+            Err(epoch) => epoch,
+        }
+    }
+
+    /// Time elapsed since the epoch in ns, the epoch is read first so that
+    /// the very first call returns 0
+    fn elapsed_ns() -> u64 {
+        if virtual_time::ENABLED {
+            return (virtual_time::now() as u128 * NSEC_PER_SEC as u128 / tick_rate_hz!() as u128) as u64;
+        }
+        let epoch = Self::epoch_ns();
+        Self::monotonic_ns() - epoch
+    }
+
+    /// Ticks elapsed since the epoch, without wrapping to `TickType`
+    fn elapsed_ticks() -> u64 {
+        if virtual_time::ENABLED {
+            return virtual_time::now();
+        }
+        let elapsed = Self::elapsed_ns();
+        (elapsed as u128 * tick_rate_hz!() as u128 / NSEC_PER_SEC as u128) as u64
+    }
+
+    /// Monotonic time at which tick `ticks` starts, rounded up to the next ns
+    fn tick_deadline_ns(ticks: u64) -> u64 {
+        let rate = tick_rate_hz!() as u128;
+        Self::epoch_ns() + (ticks as u128 * NSEC_PER_SEC as u128).div_ceil(rate) as u64
+    }
+
+    /// Sleeps until an absolute `CLOCK_MONOTONIC` time: interrupted sleeps
+    /// (e.g. by a suspend request) are resumed without accumulating drift
+    fn sleep_until(deadline_ns: u64) {
+        let deadline = timespec {
+            tv_sec: (deadline_ns / NSEC_PER_SEC as u64) as time_t,
+            tv_nsec: (deadline_ns % NSEC_PER_SEC as u64) as _,
+        };
+        while unsafe { clock_nanosleep(CLOCK_MONOTONIC, TIMER_ABSTIME, &deadline, null_mut()) } == EINTR {}
+    }
+}
+
+impl SystemFn for System {
+    /// The host OS is already scheduling threads: only the tick count epoch
+    /// is fixed here, otherwise it is fixed by the first timing call
+    fn start() {
+        Self::epoch_ns();
+    }
+
+    fn get_state() -> ThreadState {
+        ThreadControl::current().state()
+    }
+
+    fn suspend_all() {
+        Self::critical_section_enter();
+    }
+
+    fn resume_all() -> BaseType {
+        Self::critical_section_exit();
+        0
+    }
+
+    fn stop() {}
+
+    /// Wraps around like the FreeRTOS tick count
+    fn get_tick_count() -> TickType {
+        Self::elapsed_ticks() as TickType
+    }
+
+    fn get_current_time_us () -> Duration {
+        Duration::from_nanos(Self::elapsed_ns())
+    }
+
+    fn get_ticks_from_duration(duration: &Duration) -> TickType {
+        duration.to_ticks()
+    }
+
+    /// Threads spawned through osal-rs that have not returned yet, threads
+    /// created outside of it (e.g. `main`) are not counted
+    fn count_threads() -> usize {
+        registered_threads().len()
+    }
+
+    /// Threads spawned through osal-rs that have not returned yet, threads
+    /// created outside of it (e.g. `main`) are not reported
+    fn get_all_thread() -> SystemState {
+        SystemState {
+            tasks: registered_threads()
+                .iter()
+                .map(|control| control.metadata(alloc::sync::Arc::as_ptr(control) as ThreadHandle))
+                .collect(),
+            total_run_time: 0
+        }
+    }
+
+    /// Blocks for at least `ticks` ticks, 0 only yields. Returns immediately
+    /// in interrupt context, where blocking is forbidden.
+    fn delay(ticks: TickType){
+        if Interrupt::is_in_isr() {
+            return;
+        }
+        if ticks == 0 {
+            unsafe { sched_yield(); }
+        } else if virtual_time::ENABLED {
+            virtual_time::sleep_until(virtual_time::now() + ticks as u64);
+        } else {
+            let delay_ns = (ticks as u128 * NSEC_PER_SEC as u128).div_ceil(tick_rate_hz!() as u128) as u64;
+            Self::sleep_until(Self::monotonic_ns() + delay_ns);
+        }
+    }
+
+    /// Wakes at `previous_wake_time + time_increment` on the tick timeline, so
+    /// the time spent between calls does not accumulate
+    fn delay_until(previous_wake_time: &mut TickType, time_increment: TickType) {
+        let now = Self::elapsed_ticks();
+        let wake_time = previous_wake_time.wrapping_add(time_increment);
+        let remaining = wake_time.wrapping_sub(now as TickType);
+        // Same as FreeRTOS: no block if the wake time has already passed
+        if remaining != 0 && remaining <= time_increment && !Interrupt::is_in_isr() {
+            if virtual_time::ENABLED {
+                virtual_time::sleep_until(now + remaining as u64);
+            } else {
+                Self::sleep_until(Self::tick_deadline_ns(now + remaining as u64));
+            }
+        }
+        *previous_wake_time = wake_time;
+    }
+
+    /// Recursive lock shared by every thread, the counterpart of disabling
+    /// interrupts: threads that don't enter it are not held back
+    fn critical_section_enter() {
+        critical_section().lock(WAIT_FOREVER);
+    }
+    
+    fn critical_section_exit() {
+        critical_section().unlock();
+    }
+    
+    fn check_timer(timestamp: &Duration, time: &Duration) -> OsalRsBool {
+        let temp_tick_time = Self::get_current_time_us();
+        
+        let time_passing = if temp_tick_time >= *timestamp {
+            temp_tick_time - *timestamp
+        } else {
+            if register_bit_size() == Bit32 {
+                // Handle tick count overflow for 32-bit TickType
+                let overflow_correction = Duration::from_micros(0xff_ff_ff_ff_u64);
+                overflow_correction - *timestamp + temp_tick_time
+            } else {
+                // Handle tick count overflow for 64-bit TickType
+                let overflow_correction = Duration::from_micros(0xff_ff_ff_ff_ff_ff_ff_ff_u64);
+                overflow_correction - *timestamp + temp_tick_time
+            }
+        };
+
+        if time_passing >= *time {
+            OsalRsBool::True
+        } else {
+            OsalRsBool::False
+        }
+    }
+
+    /// In a simulated interrupt handler, requests a context switch once the
+    /// handler returns. Outside of it there is nothing to do.
+    fn yield_from_isr(higher_priority_task_woken: BaseType) {
+        if higher_priority_task_woken != 0 {
+            request_yield();
+        }
+    }
+
+    fn end_switching_isr( switch_required: BaseType ) {
+        Self::yield_from_isr(switch_required);
+    }
+
+    /// The host heap is not bounded by a FreeRTOS heap size
+    fn get_free_heap_size() -> usize {
+        usize::MAX
+    }
+
+}
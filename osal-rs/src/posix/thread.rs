@@ -0,0 +1,1115 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::cell::UnsafeCell;
+use core::ffi::{c_char, c_int, c_void};
+use core::fmt::{Debug, Display, Formatter};
+use core::ops::Deref;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicBool, AtomicU8, AtomicU32, AtomicUsize, Ordering};
+
+use alloc::boxed::Box;
+use alloc::ffi::CString;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use super::config::{self, MAX_TASK_NAME_LEN, TASK_NOTIFICATION_ARRAY_ENTRIES};
+use super::ffi::{
+    PTHREAD_ONCE_INIT, SA_RESTART, SIG_BLOCK, SIG_SETMASK, SIG_UNBLOCK, SignalHandler, ThreadHandle, ThreadStartRoutine, pthread_attr_destroy, pthread_attr_init, pthread_attr_setstacksize, pthread_attr_t, pthread_create, pthread_detach, pthread_getname_np, pthread_getspecific, pthread_join, pthread_key_create, pthread_key_t, pthread_kill, pthread_mutex_lock, pthread_mutex_t, pthread_mutex_unlock, pthread_once, pthread_once_t, pthread_self, pthread_setname_np, pthread_setspecific, pthread_sigmask, pthread_t, sched_yield, sigaddset, sigdelset, sigemptyset, sigaction, sighandler_t, sigset_t, sigsuspend
+};
+use super::isr::Interrupt;
+use super::sync::{Monitor, MonitorGuard, WAIT_FOREVER};
+use super::types::{StackType, UBaseType, BaseType, TickType};
+use super::thread::ThreadState::*;
+use super::virtual_time;
+use crate::traits::{JoinHandleFn, ThreadFn, ThreadParam, ThreadFnPtr, ThreadNotification, ToTick, ToPriority};
+use crate::utils::{Result, Error, DoublePtr};
+use crate::from_c_str;
+
+/// Smallest stack given to a pthread: host code (formatting, logging, debug
+/// builds) needs far more room than the word count sized for the firmware.
+const MIN_STACK_SIZE: usize = 256 * 1024;
+
+/// Entry used by the notifications without an index, as `tskDEFAULT_INDEX_TO_NOTIFY`
+const DEFAULT_INDEX_TO_NOTIFY: UBaseType = 0;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u8)]
+pub enum ThreadState {
+    Running = 0,
+    Ready = 1,
+    Blocked = 2,
+    Suspended = 3,
+    Deleted = 4,
+    Invalid,
+}
+
+impl From<u8> for ThreadState {
+    fn from(state: u8) -> Self {
+        match state {
+            0 => Running,
+            1 => Ready,
+            2 => Blocked,
+            3 => Suspended,
+            4 => Deleted,
+            _ => Invalid,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ThreadMetadata {
+    pub thread: ThreadHandle,
+    pub name: String,
+    pub stack_depth: StackType,
+    pub priority: UBaseType,
+    pub thread_number: UBaseType,
+    pub state: ThreadState,
+    pub current_priority: UBaseType,
+    pub base_priority: UBaseType,
+    pub run_time_counter: UBaseType,
+    pub stack_high_water_mark: StackType,
+}
+
+unsafe impl Send for ThreadMetadata {}
+unsafe impl Sync for ThreadMetadata {}
+
+impl Default for ThreadMetadata {
+    fn default() -> Self {
+        ThreadMetadata {
+            thread: null_mut(),
+            name: String::new(),
+            stack_depth: 0,
+            priority: 0,
+            thread_number: 0,
+            state: Invalid,
+            current_priority: 0,
+            base_priority: 0,
+            run_time_counter: 0,
+            stack_high_water_mark: 0,
+        }
+    }
+}
+
+/// Notification value and pending state, as `ulNotifiedValue` and
+/// `ucNotifyState` in the FreeRTOS TCB
+#[derive(Default, Clone, Copy)]
+struct Notification {
+    value: u32,
+    pending: bool,
+    /// Set while the thread is blocked on this entry
+    waiting: bool,
+}
+
+/// Entries of the notification array of a thread
+type Notifications = [Notification; TASK_NOTIFICATION_ARRAY_ENTRIES as usize];
+
+/// Per-thread bookkeeping, the POSIX counterpart of the FreeRTOS TCB.
+///
+/// A `ThreadHandle` on POSIX is a pointer to this structure.
+pub(crate) struct ThreadControl {
+    pthread: AtomicUsize,
+    name: String,
+    stack_depth: StackType,
+    priority: UBaseType,
+    thread_number: UBaseType,
+    state: AtomicU8,
+    suspended: AtomicBool,
+    /// Set while the thread sleeps in `park`, cleared by whoever wakes it up
+    parked: AtomicBool,
+    /// Nesting of `hold_suspend`, only changed by the thread itself
+    holds: AtomicU32,
+    /// Set once the callback has returned
+    exited: Monitor<bool>,
+    /// Set once the pthread has been joined or detached, threads not created
+    /// by osal-rs (e.g. `main`) start released so they are never detached
+    released: AtomicBool,
+    notification: Monitor<Notifications>,
+}
+
+static THREAD_NUMBER: AtomicU32 = AtomicU32::new(1);
+
+static mut ONCE: pthread_once_t = PTHREAD_ONCE_INIT;
+
+static mut CURRENT_KEY: pthread_key_t = 0;
+
+/// Set once `CURRENT_KEY` exists, before any control block is bound
+static KEY_READY: AtomicBool = AtomicBool::new(false);
+
+/// Set while an interrupt handler runs, the spawned threads park meanwhile
+static STOPPED: AtomicBool = AtomicBool::new(false);
+
+/// Threads spawned through osal-rs that have not terminated yet
+static mut REGISTRY: Vec<Arc<ThreadControl>> = Vec::new();
+
+static mut REGISTRY_LOCK: pthread_mutex_t = pthread_mutex_t::new();
+
+fn with_registry<R>(f: impl FnOnce(&mut Vec<Arc<ThreadControl>>) -> R) -> R {
+    hold_suspend();
+    let ret = unsafe {
+        let registry = &raw mut REGISTRY;
+        pthread_mutex_lock(&raw mut REGISTRY_LOCK);
+        let ret = f(&mut *registry);
+        pthread_mutex_unlock(&raw mut REGISTRY_LOCK);
+        ret
+    };
+    release_suspend();
+    ret
+}
+
+fn register(control: &Arc<ThreadControl>) {
+    with_registry(|registry| registry.push(control.clone()));
+}
+
+fn unregister(control: &Arc<ThreadControl>) {
+    with_registry(|registry| registry.retain(|entry| !Arc::ptr_eq(entry, control)));
+}
+
+/// Snapshot of the threads spawned through osal-rs that are still running
+pub(crate) fn registered_threads() -> Vec<Arc<ThreadControl>> {
+    with_registry(|registry| registry.clone())
+}
+
+extern "C" fn init_once() {
+    let (suspend, resume) = config::claim_thread_signals();
+    unsafe {
+        pthread_key_create(&raw mut CURRENT_KEY, Some(release_current));
+        install_handler(suspend, suspend_handler);
+        install_handler(resume, resume_handler);
+    }
+    KEY_READY.store(true, Ordering::SeqCst);
+}
+
+/// The system calls interrupted by a suspension are restarted, as if the
+/// thread had only been descheduled
+unsafe fn install_handler(signum: c_int, handler: SignalHandler) {
+    let mut action = sigaction {
+        sa_handler: handler as sighandler_t,
+        sa_mask: sigset_t::new(),
+        sa_flags: SA_RESTART,
+        sa_restorer: 0,
+    };
+    unsafe {
+        sigemptyset(&mut action.sa_mask);
+        sigaction(signum, &action, null_mut());
+    }
+}
+
+unsafe extern "C" fn release_current(value: *mut c_void) {
+    if !value.is_null() {
+        drop(unsafe { Arc::from_raw(value as *const ThreadControl) });
+    }
+}
+
+extern "C" fn suspend_handler(_signum: c_int) {
+    let control = unsafe { pthread_getspecific(CURRENT_KEY) } as *const ThreadControl;
+    if !control.is_null() {
+        unsafe { (*control).park() };
+    }
+}
+
+extern "C" fn resume_handler(_signum: c_int) {}
+
+fn current_key() -> pthread_key_t {
+    unsafe {
+        pthread_once(&raw mut ONCE, init_once);
+        CURRENT_KEY
+    }
+}
+
+/// Control block bound to the calling thread, null for a thread that osal-rs
+/// cannot suspend. Never allocates, so the allocator can use it.
+fn bound_control() -> *const ThreadControl {
+    if !KEY_READY.load(Ordering::SeqCst) {
+        return null_mut();
+    }
+    unsafe { pthread_getspecific(CURRENT_KEY) as *const ThreadControl }
+}
+
+fn suspend_set() -> sigset_t {
+    let mut set = sigset_t::new();
+    unsafe {
+        sigemptyset(&mut set);
+        sigaddset(&mut set, config::suspend_signal());
+    }
+    set
+}
+
+/// Holds back the suspension of the calling thread, and with it the simulated
+/// interrupts, while it is inside osal-rs: stopped with an internal lock held,
+/// it would block every thread and handler needing that lock. Calls nest.
+pub(crate) fn hold_suspend() {
+    let control = bound_control();
+    if control.is_null() {
+        return;
+    }
+    if unsafe { (*control).holds.fetch_add(1, Ordering::SeqCst) } == 0 {
+        let set = suspend_set();
+        unsafe { pthread_sigmask(SIG_BLOCK, &set, null_mut()); }
+    }
+}
+
+/// Undoes `hold_suspend`, a suspension requested meanwhile happens here
+pub(crate) fn release_suspend() {
+    let control = bound_control();
+    if control.is_null() {
+        return;
+    }
+    let holds = unsafe { &(*control).holds };
+    match holds.load(Ordering::SeqCst) {
+        // Bound while holding, e.g. `main` adopted inside a `Monitor`
+        0 => {}
+        1 => {
+            let set = suspend_set();
+            unsafe { pthread_sigmask(SIG_UNBLOCK, &set, null_mut()); }
+            holds.store(0, Ordering::SeqCst);
+        }
+        _ => {
+            holds.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+/// Stops every thread spawned through osal-rs before an interrupt handler
+/// runs, as the CPU leaves the running task for the ISR. Returns once each of
+/// them is parked or inside osal-rs, where it parks on the way out.
+pub(crate) fn stop_threads() -> Vec<Arc<ThreadControl>> {
+    current_key();
+    STOPPED.store(true, Ordering::SeqCst);
+
+    // Registered threads have not terminated: they can be signalled. One not
+    // started yet sees `STOPPED` and parks first thing
+    let threads = with_registry(|registry| {
+        for control in registry.iter() {
+            if control.pthread() != 0 {
+                unsafe { pthread_kill(control.pthread(), config::suspend_signal()); }
+            }
+        }
+        registry.clone()
+    });
+
+    while !threads.iter().all(|control| control.pthread() == 0 || control.parked.load(Ordering::SeqCst) || control.holds.load(Ordering::SeqCst) > 0) {
+        unsafe { sched_yield(); }
+    }
+    threads
+}
+
+/// Lets the threads stopped by `stop_threads` run again, except the ones
+/// suspended meanwhile
+pub(crate) fn restart_threads(threads: &[Arc<ThreadControl>]) {
+    STOPPED.store(false, Ordering::SeqCst);
+    for control in threads {
+        if !control.suspended.load(Ordering::SeqCst) && control.parked.swap(false, Ordering::SeqCst) {
+            virtual_time::enter_runnable();
+            unsafe { pthread_kill(control.pthread(), config::resume_signal()); }
+        }
+    }
+}
+
+impl ThreadControl {
+    fn new(name: String, stack_depth: StackType, priority: UBaseType, owned: bool) -> Result<Self> {
+        Ok(Self {
+            pthread: AtomicUsize::new(0),
+            name,
+            stack_depth,
+            priority,
+            thread_number: THREAD_NUMBER.fetch_add(1, Ordering::Relaxed),
+            state: AtomicU8::new(Ready as u8),
+            suspended: AtomicBool::new(false),
+            parked: AtomicBool::new(false),
+            holds: AtomicU32::new(0),
+            exited: Monitor::new(false)?,
+            released: AtomicBool::new(!owned),
+            notification: Monitor::new([Notification::default(); TASK_NOTIFICATION_ARRAY_ENTRIES as usize])?,
+        })
+    }
+
+    /// Returns the control block of the calling thread, adopting threads
+    /// that were not spawned through osal-rs (e.g. `main`) on first use
+    pub(crate) fn current() -> Arc<Self> {
+        let key = current_key();
+        let ptr = unsafe { pthread_getspecific(key) } as *const ThreadControl;
+        if !ptr.is_null() {
+            unsafe {
+                Arc::increment_strong_count(ptr);
+                return Arc::from_raw(ptr);
+            }
+        }
+
+        let pthread = unsafe { pthread_self() };
+        let mut name_buf = [0 as c_char; MAX_TASK_NAME_LEN as usize];
+        let name = if unsafe { pthread_getname_np(pthread, name_buf.as_mut_ptr(), name_buf.len()) } == 0 {
+            from_c_str!(name_buf.as_ptr())
+        } else {
+            String::new()
+        };
+
+        let control = Arc::new(Self::new(name, 0, 0, false).unwrap());
+        control.bind();
+        control
+    }
+
+    /// Attaches the control block to the calling pthread
+    fn bind(self: &Arc<Self>) {
+        self.pthread.store(unsafe { pthread_self() } as usize, Ordering::SeqCst);
+        self.state.store(Running as u8, Ordering::SeqCst);
+        unsafe {
+            pthread_setspecific(current_key(), Arc::into_raw(self.clone()) as *const c_void);
+        }
+    }
+
+    /// First thing run by a spawned thread: takes the suspend signal, that the
+    /// creating thread may have held back, and parks if it is already due
+    fn start(self: &Arc<Self>) {
+        self.bind();
+        let set = suspend_set();
+        unsafe { pthread_sigmask(SIG_UNBLOCK, &set, null_mut()); }
+        self.park();
+    }
+
+    #[inline]
+    pub(crate) fn pthread(&self) -> pthread_t {
+        self.pthread.load(Ordering::SeqCst) as pthread_t
+    }
+
+    #[inline]
+    pub(crate) fn state(&self) -> ThreadState {
+        if self.suspended.load(Ordering::SeqCst) {
+            Suspended
+        } else {
+            ThreadState::from(self.state.load(Ordering::SeqCst))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set_state(&self, state: ThreadState) {
+        self.state.store(state as u8, Ordering::SeqCst);
+    }
+
+    /// Blocks the calling thread while a suspension is pending or an
+    /// interrupt handler runs.
+    ///
+    /// The resume signal is kept blocked between the flag check and
+    /// `sigsuspend` so a resume cannot slip in between and be lost. `parked`
+    /// is published before the flag is checked again, so `resume` knows
+    /// whether to signal.
+    fn park(&self) {
+        let mut resume_set = sigset_t::new();
+        let mut old_mask = sigset_t::new();
+        unsafe {
+            sigemptyset(&mut resume_set);
+            sigaddset(&mut resume_set, config::resume_signal());
+            pthread_sigmask(SIG_BLOCK, &resume_set, &mut old_mask);
+
+            let mut wait_mask = old_mask;
+            sigdelset(&mut wait_mask, config::resume_signal());
+            while self.suspended.load(Ordering::SeqCst) || STOPPED.load(Ordering::SeqCst) {
+                if !self.parked.swap(true, Ordering::SeqCst) {
+                    virtual_time::leave_runnable();
+                    continue;
+                }
+                sigsuspend(&wait_mask);
+            }
+            if self.parked.swap(false, Ordering::SeqCst) {
+                virtual_time::enter_runnable();
+            }
+
+            pthread_sigmask(SIG_SETMASK, &old_mask, null_mut());
+        }
+    }
+
+    fn suspend(&self) {
+        if self.state() == Deleted {
+            return;
+        }
+        current_key();
+        if !self.suspended.swap(true, Ordering::SeqCst) {
+            unsafe { pthread_kill(self.pthread(), config::suspend_signal()); }
+        }
+    }
+
+    /// Only a parked thread is signalled: one that has not parked yet sees
+    /// the flag cleared, and one that has terminated must not be signalled
+    fn resume(&self) {
+        if self.suspended.swap(false, Ordering::SeqCst) && self.parked.swap(false, Ordering::SeqCst) {
+            virtual_time::enter_runnable();
+            unsafe { pthread_kill(self.pthread(), config::resume_signal()); }
+        }
+    }
+
+    /// Marks the callback as returned and wakes up the joining thread
+    fn exit(&self) {
+        let mut exited = self.exited.lock();
+        *exited = true;
+        exited.notify_all();
+    }
+
+    /// Waits for the callback to return, `false` on timeout
+    fn wait_exit(&self, timeout_ticks: TickType) -> bool {
+        self.exited.lock().wait_while(timeout_ticks, |exited| !*exited)
+    }
+
+    /// Detaches the pthread so its resources are reclaimed when it terminates
+    fn detach(&self) {
+        if !self.released.swap(true, Ordering::SeqCst) {
+            unsafe { pthread_detach(self.pthread()); }
+        }
+    }
+
+    /// Same contract as `xTaskGenericNotify`: only `SetValueWithoutOverwrite`
+    /// can fail, when the previous notification is still pending.
+    ///
+    /// Returns the previous value and whether the thread was blocked waiting
+    /// for the notification. `index` is checked by the caller.
+    fn notify(&self, index: usize, notification: ThreadNotification) -> Result<(u32, bool)> {
+        let mut entries = self.notification.lock();
+        let state = &mut entries[index];
+        let previous = state.value;
+        match notification {
+            ThreadNotification::NoAction => {}
+            ThreadNotification::SetBits(bits) => state.value |= bits,
+            ThreadNotification::Increment => state.value = state.value.wrapping_add(1),
+            ThreadNotification::SetValueWithOverwrite(value) => state.value = value,
+            ThreadNotification::SetValueWithoutOverwrite(value) => {
+                if state.pending {
+                    return Err(Error::QueueFull);
+                }
+                state.value = value;
+            }
+        }
+        state.pending = true;
+        let woken = state.waiting;
+        entries.notify_all();
+        Ok((previous, woken))
+    }
+
+    /// Same contract as `xTaskNotifyWait`: the entry bits are cleared only if
+    /// no notification is pending, the exit bits only if one was received
+    fn wait_notification(&self, index: usize, bits_to_clear_on_entry: u32, bits_to_clear_on_exit: u32, timeout_ticks: TickType) -> Result<u32> {
+        let mut entries = self.notification.lock();
+        if !entries[index].pending {
+            entries[index].value &= !bits_to_clear_on_entry;
+        }
+
+        if !self.block_on(&mut entries, index, timeout_ticks, |state| !state.pending) {
+            return Err(Error::Timeout);
+        }
+
+        let state = &mut entries[index];
+        let value = state.value;
+        state.value &= !bits_to_clear_on_exit;
+        state.pending = false;
+        Ok(value)
+    }
+
+    /// Same contract as `ulTaskNotifyTake`: waits for a value that is not
+    /// zero, then decrements it or clears it
+    fn notify_take(&self, index: usize, clear_on_exit: bool, timeout_ticks: TickType) -> Result<u32> {
+        let mut entries = self.notification.lock();
+        if !self.block_on(&mut entries, index, timeout_ticks, |state| state.value == 0) {
+            return Err(Error::Timeout);
+        }
+
+        let state = &mut entries[index];
+        let count = state.value;
+        state.value = if clear_on_exit { 0 } else { count - 1 };
+        state.pending = false;
+        Ok(count)
+    }
+
+    /// Same contract as `xTaskNotifyStateClear`
+    fn clear_state(&self, index: usize) -> bool {
+        let mut entries = self.notification.lock();
+        core::mem::take(&mut entries[index].pending)
+    }
+
+    /// Blocks while `condition` holds for entry `index`, marking the thread
+    /// as waiting on it. Returns `false` on timeout
+    fn block_on<F>(&self, entries: &mut MonitorGuard<'_, Notifications>, index: usize, timeout_ticks: TickType, mut condition: F) -> bool
+    where
+        F: FnMut(&Notification) -> bool,
+    {
+        let blocking = condition(&entries[index]) && timeout_ticks != 0;
+        if blocking {
+            entries[index].waiting = true;
+            self.set_state(Blocked);
+        }
+        let released = entries.wait_while(timeout_ticks, |entries| condition(&entries[index]));
+        if blocking {
+            entries[index].waiting = false;
+            self.set_state(Running);
+        }
+        released
+    }
+
+    pub(crate) fn metadata(&self, handle: ThreadHandle) -> ThreadMetadata {
+        ThreadMetadata {
+            thread: handle,
+            name: self.name.clone(),
+            stack_depth: self.stack_depth,
+            priority: self.priority,
+            thread_number: self.thread_number,
+            state: self.state(),
+            current_priority: self.priority,
+            base_priority: self.priority,
+            run_time_counter: 0,
+            stack_high_water_mark: 0,
+        }
+    }
+}
+
+impl Drop for ThreadControl {
+    fn drop(&mut self) {
+        // Nobody can join the thread anymore: let the system reclaim it
+        self.detach();
+    }
+}
+
+/// Thread backed by a pthread.
+///
+/// Suspending a thread, and stopping it while a simulated interrupt runs,
+/// relies on two signals reserved by osal-rs: `SIGUSR1` parks the thread
+/// and `SIGUSR2` wakes it up. The application must not install handlers for
+/// them nor block them; `config::set_thread_signals` moves them elsewhere,
+/// e.g. to real-time signals, before the first thread is used.
+#[derive(Clone)]
+pub struct Thread {
+    handle: ThreadHandle,
+    control: Option<Arc<ThreadControl>>,
+    name: String,
+    stack_depth: StackType,
+    priority: UBaseType,
+    callback: Option<Arc<ThreadFnPtr>>,
+    param: Option<ThreadParam>
+}
+
+unsafe impl Send for Thread {}
+unsafe impl Sync for Thread {}
+
+impl Thread {
+
+    pub fn new_with_to_priority(name: &str, stack_depth: StackType, priority: impl ToPriority) -> Self 
+    {
+        Self { 
+            handle: null_mut(), 
+            control: None,
+            name: name.to_string(), 
+            stack_depth, 
+            priority: priority.to_priority(), 
+            callback: None,
+            param: None 
+        }
+    }
+
+    pub fn new_with_handle_and_to_priority(handle: ThreadHandle, name: &str, stack_depth: StackType, priority: impl ToPriority) -> Result<Self> {
+        Self::new_with_handle(handle, name, stack_depth, priority.to_priority())
+    }
+
+    /// Reads the metadata of a thread from its handle.
+    ///
+    /// The handle must belong to a thread that is still referenced by a `Thread`.
+    pub fn get_metadata_from_handle(handle: ThreadHandle) -> ThreadMetadata {
+        if handle.is_null() {
+            return ThreadMetadata::default();
+        }
+        unsafe { (*(handle as *const ThreadControl)).metadata(handle) }
+    }
+
+    pub fn get_metadata(thread: &Thread) -> ThreadMetadata {
+        if thread.handle.is_null() {
+            return ThreadMetadata::default();
+        }
+        Self::get_metadata_from_handle(thread.handle)
+    }
+
+    /// Control block of the thread, once `index` is checked against the
+    /// notification array
+    fn notification_control(&self, index: UBaseType) -> Result<&ThreadControl> {
+        let control = self.control.as_deref().ok_or(Error::NullPtr)?;
+        if index >= TASK_NOTIFICATION_ARRAY_ENTRIES as UBaseType {
+            return Err(Error::OutOfIndex);
+        }
+        Ok(control)
+    }
+
+    #[inline]
+    pub fn wait_notification_with_to_tick(&self, bits_to_clear_on_entry: u32, bits_to_clear_on_exit: u32 , timeout_ticks: impl ToTick) -> Result<u32> {
+        if self.handle.is_null() {
+            return Err(Error::NullPtr);
+        }
+        self.wait_notification(bits_to_clear_on_entry, bits_to_clear_on_exit, timeout_ticks.to_ticks())
+    }
+
+    fn with_control(&self, control: Arc<ThreadControl>) -> Self {
+        Self {
+            handle: Arc::as_ptr(&control) as ThreadHandle,
+            control: Some(control),
+            ..self.clone()
+        }
+    }
+
+    /// Spawns the pthread running `entry` once
+    fn spawn_entry(&self, entry: Box<dyn FnOnce() + Send>) -> Result<Self> {
+        let control = Arc::new(ThreadControl::new(self.name.clone(), self.stack_depth, self.priority, true)?);
+
+        let boxed: *mut Entry = Box::into_raw(Box::new((control.clone(), entry)));
+
+        if let Err(err) = Self::create(&control, simple_callback_wrapper, boxed as *mut c_void) {
+            drop(unsafe { Box::from_raw(boxed) });
+            return Err(err);
+        }
+
+        Ok(self.with_control(control))
+    }
+
+    /// Creates the pthread running `start_routine(arg)` for `control` and
+    /// adds it to the registry until its callback returns
+    fn create(control: &Arc<ThreadControl>, start_routine: ThreadStartRoutine, arg: *mut c_void) -> Result<()> {
+        let stack_size = (control.stack_depth.max(0) as usize * size_of::<usize>()).max(MIN_STACK_SIZE);
+
+        // Registered before the thread starts so that a short-lived callback
+        // cannot unregister it first
+        register(control);
+        virtual_time::thread_created();
+
+        let mut pthread: pthread_t = 0;
+        let mut attr = pthread_attr_t::new();
+        let ret = unsafe {
+            pthread_attr_init(&mut attr);
+            pthread_attr_setstacksize(&mut attr, stack_size);
+            let ret = pthread_create(&mut pthread, &attr, start_routine, arg);
+            pthread_attr_destroy(&mut attr);
+            ret
+        };
+
+        if ret != 0 {
+            virtual_time::thread_not_created();
+            unregister(control);
+            control.released.store(true, Ordering::SeqCst);
+            return Err(Error::OutOfMemory);
+        }
+
+        control.pthread.store(pthread as usize, Ordering::SeqCst);
+
+        // Linux limits thread names to 15 characters plus the terminator
+        let name_len = control.name.len().min(MAX_TASK_NAME_LEN as usize - 1);
+        if let Ok(c_name) = CString::new(&control.name.as_bytes()[..name_len]) {
+            unsafe { pthread_setname_np(pthread, c_name.as_ptr()); }
+        }
+
+        Ok(())
+    }
+}
+
+/// Value returned by the callback of a joinable thread, written by the thread
+/// before it marks itself exited and taken by `join` once the exit is seen
+struct ResultSlot<T>(UnsafeCell<Option<T>>);
+
+unsafe impl<T: Send> Send for ResultSlot<T> {}
+unsafe impl<T: Send> Sync for ResultSlot<T> {}
+
+impl<T> ResultSlot<T> {
+    fn new() -> Self {
+        Self(UnsafeCell::new(None))
+    }
+
+    fn set(&self, value: T) {
+        unsafe { *self.0.get() = Some(value) };
+    }
+
+    fn take(&self) -> Option<T> {
+        unsafe { (*self.0.get()).take() }
+    }
+}
+
+/// Thread spawned with `spawn_joinable`, `join` yields the value returned by
+/// its callback.
+///
+/// A handle dropped without `join` detaches the pthread.
+pub struct JoinHandle<T> {
+    thread: Thread,
+    result: Arc<ResultSlot<T>>,
+}
+
+impl<T> JoinHandle<T> {
+    /// The running thread, e.g. to notify it
+    pub fn thread(&self) -> &Thread {
+        &self.thread
+    }
+
+    #[inline]
+    pub fn join_with_to_tick(&mut self, timeout_ticks: impl ToTick) -> Result<T> {
+        self.join(timeout_ticks.to_ticks())
+    }
+}
+
+impl<T> JoinHandleFn<T> for JoinHandle<T> {
+    fn join(&mut self, timeout_ticks: TickType) -> Result<T> {
+        let control = self.thread.control.as_ref().ok_or(Error::NullPtr)?;
+
+        if Interrupt::is_in_isr() {
+            return Err(Error::Unhandled("Cannot join a thread from an interrupt"));
+        }
+
+        if !control.wait_exit(timeout_ticks) {
+            return Err(Error::Timeout);
+        }
+        let value = self.result.take().ok_or(Error::Unhandled("Thread already joined"))?;
+
+        // The callback has returned, so the pthread is about to terminate
+        if !control.released.swap(true, Ordering::SeqCst) {
+            unsafe { pthread_join(control.pthread(), null_mut()) };
+        }
+        Ok(value)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.thread.control.as_ref().is_some_and(|control| *control.exited.lock())
+    }
+}
+
+impl<T> Debug for JoinHandle<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("JoinHandle")
+            .field("thread", &self.thread)
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+/// Control block of a spawned thread and the closure it runs once
+type Entry = (Arc<ThreadControl>, Box<dyn FnOnce() + Send>);
+
+extern "C" fn callback_c_wrapper(param_ptr: *mut c_void) -> *mut c_void {
+    if param_ptr.is_null() {
+        return null_mut();
+    }
+
+    let thread_instance: Box<Thread> = unsafe { Box::from_raw(param_ptr as *mut _) };
+
+    let control = thread_instance.control.clone();
+    virtual_time::thread_started();
+    if let Some(control) = &control {
+        control.start();
+    }
+
+    let param_arc: Option<ThreadParam> = thread_instance
+        .param
+        .clone();
+
+    if let Some(callback) = &thread_instance.callback.clone() {
+        let _ = callback(thread_instance, param_arc);
+    }
+
+    if let Some(control) = control {
+        // Never released: once unregistered the thread must not be stopped
+        hold_suspend();
+        control.set_state(Deleted);
+        unregister(&control);
+        control.exit();
+    }
+
+    null_mut()
+}
+
+extern "C" fn simple_callback_wrapper(param_ptr: *mut c_void) -> *mut c_void {
+    if param_ptr.is_null() {
+        return null_mut();
+    }
+
+    let boxed: Box<Entry> = unsafe { Box::from_raw(param_ptr as *mut _) };
+    let (control, func) = *boxed;
+
+    virtual_time::thread_started();
+    control.start();
+
+    func();
+
+    // Never released: once unregistered the thread must not be stopped
+    hold_suspend();
+    control.set_state(Deleted);
+    unregister(&control);
+    control.exit();
+
+    null_mut()
+}
+
+
+
+impl ThreadFn for Thread {
+    fn new(name: &str, stack_depth: StackType, priority: UBaseType) -> Self 
+    {
+        Self { 
+            handle: null_mut(), 
+            control: None,
+            name: name.to_string(), 
+            stack_depth, 
+            priority, 
+            callback: None,
+            param: None 
+        }
+    }
+
+    /// Wraps an existing handle.
+    ///
+    /// The handle must belong to a thread that is still referenced by a `Thread`.
+    fn new_with_handle(handle: ThreadHandle, name: &str, stack_depth: StackType, priority: UBaseType) -> Result<Self> {
+        if handle.is_null() {
+            return Err(Error::NullPtr);
+        }
+
+        let control = unsafe {
+            Arc::increment_strong_count(handle as *const ThreadControl);
+            Arc::from_raw(handle as *const ThreadControl)
+        };
+
+        Ok(Self { 
+            handle, 
+            control: Some(control),
+            name: name.to_string(), 
+            stack_depth, 
+            priority, 
+            callback: None,
+            param: None 
+        })
+    }
+
+    /// Spawns a new pthread running `callback`.
+    /// 
+    /// # Important
+    /// The callback must be `'static`, which means it cannot borrow local variables.
+    /// Use `move` in the closure to transfer ownership of any captured values.
+    /// The priority is only recorded: host threads are scheduled by the OS.
+    fn spawn<F>(&mut self, param: Option<ThreadParam>, callback: F) -> Result<Self> 
+        where 
+        F: Fn(Box<dyn ThreadFn>, Option<ThreadParam>) -> Result<ThreadParam>,
+        F: Send + Sync + 'static {
+
+        let func: Arc<ThreadFnPtr> = Arc::new(callback);
+        
+        self.callback = Some(func);
+        self.param = param;
+
+        let control = Arc::new(ThreadControl::new(self.name.clone(), self.stack_depth, self.priority, true)?);
+        let thread = self.with_control(control.clone());
+
+        let boxed_thread = Box::into_raw(Box::new(thread.clone()));
+
+        if let Err(err) = Self::create(&control, callback_c_wrapper, boxed_thread as *mut c_void) {
+            drop(unsafe { Box::from_raw(boxed_thread) });
+            return Err(err);
+        }
+
+        Ok(thread)
+    }
+
+    /// Spawns a new pthread with a simple closure, similar to `std::thread::spawn`.
+    fn spawn_simple<F>(&mut self, callback: F) -> Result<Self>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.spawn_entry(Box::new(callback))
+    }
+
+    fn spawn_joinable<F, R>(&mut self, callback: F) -> Result<JoinHandle<R>>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let result = Arc::new(ResultSlot::new());
+        let slot = Arc::clone(&result);
+        let thread = self.spawn_entry(Box::new(move || slot.set(callback())))?;
+        Ok(JoinHandle { thread, result })
+    }
+
+    fn spawn_with_param<P, F, R>(&mut self, param: P, callback: F) -> Result<JoinHandle<R>>
+    where
+        P: Send + 'static,
+        F: FnOnce(P) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.spawn_joinable(move || callback(param))
+    }
+
+    /// Marks the thread as deleted and detaches it.
+    ///
+    /// A pthread cannot be killed safely from outside: the thread keeps
+    /// running until its callback returns, then its resources are released.
+    fn delete(&self) {
+        if let Some(control) = &self.control {
+            control.set_state(Deleted);
+            control.resume();
+            control.detach();
+        }
+    }
+
+    /// Suspends the thread by parking it in a signal handler until `resume`.
+    ///
+    /// As with `vTaskSuspend`, suspending a thread that holds a lock blocks
+    /// every other thread waiting for that lock.
+    fn suspend(&self) {
+        if let Some(control) = &self.control {
+            control.suspend();
+        }
+    }
+
+    fn resume(&self) {
+        if let Some(control) = &self.control {
+            control.resume();
+        }
+    }
+
+    /// Blocks until the thread terminates, `retval` is left untouched: the
+    /// result of the callback is only delivered by a `JoinHandle`.
+    ///
+    /// `delete` detaches the pthread, joining it afterwards fails at once.
+    fn join(&self, _retval: DoublePtr) -> Result<i32> {
+        let control = self.control.as_ref().ok_or(Error::NullPtr)?;
+
+        if Interrupt::is_in_isr() {
+            return Err(Error::Unhandled("Cannot join a thread from an interrupt"));
+        }
+
+        if control.released.swap(true, Ordering::SeqCst) {
+            return Err(Error::Unhandled("Thread already joined or detached"));
+        }
+
+        // Waiting here rather than in `pthread_join` keeps the joining thread
+        // visible as blocked to the virtual clock
+        control.wait_exit(WAIT_FOREVER);
+
+        let ret = unsafe { pthread_join(control.pthread(), null_mut()) };
+        if ret != 0 {
+            return Err(Error::Unhandled("Failed to join thread"));
+        }
+
+        Ok(0)
+    }
+
+    fn get_metadata(&self) -> ThreadMetadata {
+        match &self.control {
+            Some(control) => control.metadata(self.handle),
+            None => ThreadMetadata {
+                name: self.name.clone(),
+                stack_depth: self.stack_depth,
+                priority: self.priority,
+                current_priority: self.priority,
+                base_priority: self.priority,
+                ..ThreadMetadata::default()
+            },
+        }
+    }
+
+    fn get_current() -> Self {
+        let control = ThreadControl::current();
+        Self {
+            handle: Arc::as_ptr(&control) as ThreadHandle,
+            name: control.name.clone(),
+            stack_depth: control.stack_depth,
+            priority: control.priority,
+            control: Some(control),
+            callback: None,
+            param: None,
+        }
+    }
+
+    fn notify(&self, notification: ThreadNotification) -> Result<()> {
+        self.notify_indexed(DEFAULT_INDEX_TO_NOTIFY, notification)
+    }
+
+    fn notify_from_isr(&self, notification: ThreadNotification, higher_priority_task_woken: &mut BaseType) -> Result<()> {
+        self.notify_indexed_from_isr(DEFAULT_INDEX_TO_NOTIFY, notification, higher_priority_task_woken)
+    }
+
+    /// Waits for a notification sent to the calling thread, as `xTaskNotifyWait`
+    fn wait_notification(&self, bits_to_clear_on_entry: u32, bits_to_clear_on_exit: u32 , timeout_ticks: TickType) -> Result<u32> {
+        self.wait_notification_indexed(DEFAULT_INDEX_TO_NOTIFY, bits_to_clear_on_entry, bits_to_clear_on_exit, timeout_ticks)
+    }
+
+    fn notify_indexed(&self, index: UBaseType, notification: ThreadNotification) -> Result<()> {
+        self.notify_and_query(index, notification).map(|_| ())
+    }
+
+    /// Never blocks. `higher_priority_task_woken` is set when the notified
+    /// thread was waiting, so that the caller can pass it to `yield_from_isr`.
+    fn notify_indexed_from_isr(&self, index: UBaseType, notification: ThreadNotification, higher_priority_task_woken: &mut BaseType) -> Result<()> {
+        let (_, woken) = self.notification_control(index)?.notify(index as usize, notification)?;
+        if woken {
+            *higher_priority_task_woken = 1;
+        }
+        Ok(())
+    }
+
+    fn notify_and_query(&self, index: UBaseType, notification: ThreadNotification) -> Result<u32> {
+        let (previous, _) = self.notification_control(index)?.notify(index as usize, notification)?;
+        Ok(previous)
+    }
+
+    fn wait_notification_indexed(&self, index: UBaseType, bits_to_clear_on_entry: u32, bits_to_clear_on_exit: u32, timeout_ticks: TickType) -> Result<u32> {
+        self.notification_control(index)?;
+        ThreadControl::current().wait_notification(index as usize, bits_to_clear_on_entry, bits_to_clear_on_exit, timeout_ticks)
+    }
+
+    fn notify_give(&self, index: UBaseType) -> Result<()> {
+        self.notify_indexed(index, ThreadNotification::Increment)
+    }
+
+    fn notify_give_from_isr(&self, index: UBaseType, higher_priority_task_woken: &mut BaseType) -> Result<()> {
+        self.notify_indexed_from_isr(index, ThreadNotification::Increment, higher_priority_task_woken)
+    }
+
+    /// Takes from the notification of the calling thread, as `ulTaskNotifyTake`
+    fn notify_take(&self, index: UBaseType, clear_on_exit: bool, timeout_ticks: TickType) -> Result<u32> {
+        self.notification_control(index)?;
+        ThreadControl::current().notify_take(index as usize, clear_on_exit, timeout_ticks)
+    }
+
+    fn clear_state(&self, index: UBaseType) -> Result<bool> {
+        Ok(self.notification_control(index)?.clear_state(index as usize))
+    }
+
+}
+
+impl Deref for Thread {
+    type Target = ThreadHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl Debug for Thread {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Thread")
+            .field("handle", &self.handle)
+            .field("name", &self.name)
+            .field("stack_depth", &self.stack_depth)
+            .field("priority", &self.priority)
+            .field("callback", &self.callback.as_ref().map(|_| "Some(...)"))
+            .field("param", &self.param)
+            .finish()
+    }
+}
+
+impl Display for Thread {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Thread {{ handle: {:?}, name: {}, priority: {}, stack_depth: {} }}", self.handle, self.name, self.priority, self.stack_depth)
+    }
+}
@@ -0,0 +1,369 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use core::any::Any;
+use core::fmt::{Debug, Display};
+use core::ops::Deref;
+use core::ptr::null_mut;
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use super::config::{TIMER_QUEUE_LENGTH, TIMER_TASK_PRIORITY, TIMER_TASK_STACK_DEPTH};
+use super::ffi::{PTHREAD_ONCE_INIT, TimerHandle, pthread_once, pthread_once_t};
+use super::sync::{Monitor, WAIT_FOREVER};
+use super::system::System;
+use super::thread::Thread;
+use super::types::{TickType};
+use crate::traits::{ToTick, TimerParam, TimerFn, TimerFnPtr, SystemFn, ThreadFn};
+use crate::utils::{OsalRsBool, Result, Error};
+
+/// Timer state owned by the service thread, the handle points to it
+struct TimerControl {
+    name: String,
+    period: TickType,
+    auto_reload: bool,
+    callback: Arc<TimerFnPtr>,
+    param: Option<TimerParam>,
+}
+
+enum Command {
+    Start(TimerHandle, TickType),
+    Stop(TimerHandle),
+    ChangePeriod(TimerHandle, TickType, TickType),
+    Delete(TimerHandle),
+}
+
+unsafe impl Send for Command {}
+
+/// Running timer, kept by the service thread sorted by expiry
+struct Active {
+    handle: TimerHandle,
+    expiry: TickType,
+}
+
+static mut ONCE: pthread_once_t = PTHREAD_ONCE_INIT;
+
+static mut COMMANDS: *const Monitor<VecDeque<Command>> = null_mut();
+
+extern "C" fn init_once() {
+    let commands = match Monitor::new(VecDeque::with_capacity(TIMER_QUEUE_LENGTH)) {
+        Ok(commands) => commands,
+        Err(_) => return,
+    };
+
+    unsafe { COMMANDS = Box::into_raw(Box::new(commands)); }
+
+    let mut thread = Thread::new("Tmr Svc", TIMER_TASK_STACK_DEPTH, TIMER_TASK_PRIORITY);
+    if thread.spawn_simple(timer_service).is_err() {
+        unsafe {
+            drop(Box::from_raw(COMMANDS as *mut Monitor<VecDeque<Command>>));
+            COMMANDS = null_mut();
+        }
+    }
+}
+
+/// Command queue of the timer service, started on first use
+fn commands() -> Option<&'static Monitor<VecDeque<Command>>> {
+    unsafe {
+        pthread_once(&raw mut ONCE, init_once);
+        COMMANDS.as_ref()
+    }
+}
+
+/// Signed distance between two tick counts, robust to wrap-around
+#[inline]
+fn ticks_until(expiry: TickType, now: TickType) -> i64 {
+    expiry.wrapping_sub(now) as i32 as i64
+}
+
+fn insert_active(active: &mut Vec<Active>, handle: TimerHandle, expiry: TickType, now: TickType) {
+    active.retain(|timer| timer.handle != handle);
+    let index = active.partition_point(|timer| ticks_until(timer.expiry, now) <= ticks_until(expiry, now));
+    active.insert(index, Active { handle, expiry });
+}
+
+fn process_command(active: &mut Vec<Active>, command: Command, now: TickType) {
+    match command {
+        // As in FreeRTOS, the period is counted from when the command was issued
+        Command::Start(handle, issued_at) => {
+            let period = unsafe { (*(handle as *const TimerControl)).period };
+            insert_active(active, handle, issued_at.wrapping_add(period), now);
+        }
+        Command::Stop(handle) => active.retain(|timer| timer.handle != handle),
+        Command::ChangePeriod(handle, period, issued_at) => {
+            unsafe { (*(handle as *mut TimerControl)).period = period; }
+            insert_active(active, handle, issued_at.wrapping_add(period), now);
+        }
+        Command::Delete(handle) => {
+            active.retain(|timer| timer.handle != handle);
+            drop(unsafe { Box::from_raw(handle as *mut TimerControl) });
+        }
+    }
+}
+
+/// Body of the timer service thread, the equivalent of `prvTimerTask`
+fn timer_service() {
+    let commands = match commands() {
+        Some(commands) => commands,
+        None => return,
+    };
+    let mut active: Vec<Active> = Vec::new();
+
+    loop {
+        let now = System::get_tick_count();
+
+        if let Some(next) = active.first() {
+            if ticks_until(next.expiry, now) <= 0 {
+                let Active { handle, expiry } = active.remove(0);
+                let control = unsafe { &*(handle as *const TimerControl) };
+
+                if control.auto_reload {
+                    insert_active(&mut active, handle, expiry.wrapping_add(control.period), now);
+                }
+
+                let timer = Timer {
+                    handle,
+                    name: control.name.clone(),
+                    callback: Some(control.callback.clone()),
+                    param: control.param.clone(),
+                    owned: false,
+                };
+                let _ = (control.callback)(Box::new(timer), control.param.clone());
+                continue;
+            }
+        }
+
+        let timeout = match active.first() {
+            Some(next) => ticks_until(next.expiry, now) as TickType,
+            None => WAIT_FOREVER,
+        };
+
+        let mut queue = commands.lock();
+        if !queue.wait_while(timeout, |queue| queue.is_empty()) {
+            continue;
+        }
+        let pending: Vec<Command> = queue.drain(..).collect();
+        queue.notify_all();
+        drop(queue);
+
+        let now = System::get_tick_count();
+        for command in pending {
+            process_command(&mut active, command, now);
+        }
+    }
+}
+
+/// Sends a command to the timer service, waiting for room in the queue
+fn send_command(command: Command, ticks_to_wait: TickType) -> OsalRsBool {
+    let commands = match commands() {
+        Some(commands) => commands,
+        None => return OsalRsBool::False,
+    };
+
+    let mut queue = commands.lock();
+    if !queue.wait_while(ticks_to_wait, |queue| queue.len() >= TIMER_QUEUE_LENGTH) {
+        return OsalRsBool::False;
+    }
+    queue.push_back(command);
+    queue.notify_all();
+    OsalRsBool::True
+}
+
+pub struct Timer {
+    pub handle: TimerHandle,
+    name: String, 
+    callback: Option<Arc<TimerFnPtr>>,
+    param: Option<TimerParam>, 
+    /// `false` for the instance handed to the callback, which must not
+    /// delete the timer when dropped
+    owned: bool,
+}
+
+unsafe impl Send for Timer {}
+unsafe impl Sync for Timer {}
+
+impl Timer {
+    #[inline]
+    pub fn new_with_to_tick<F>(name: &str, timer_period_in_ticks: impl ToTick, auto_reload: bool, param: Option<TimerParam>, callback: F) -> Result<Self>
+    where
+        F: Fn(Box<dyn TimerFn>, Option<TimerParam>) -> Result<TimerParam> + Send + Sync + Clone + 'static {
+            Self::new(name, timer_period_in_ticks.to_ticks(), auto_reload, param, callback)
+        }
+
+    #[inline]
+    pub fn start_with_to_tick(&self, ticks_to_wait: impl ToTick) -> OsalRsBool {
+        self.start(ticks_to_wait.to_ticks())
+    }
+
+    #[inline]
+    pub fn stop_with_to_tick(&self, ticks_to_wait: impl ToTick)  -> OsalRsBool {
+        self.stop(ticks_to_wait.to_ticks())
+    }
+
+    #[inline]
+    pub fn reset_with_to_tick(&self, ticks_to_wait: impl ToTick) -> OsalRsBool {
+        self.reset(ticks_to_wait.to_ticks())
+    }
+
+    #[inline]
+    pub fn change_period_with_to_tick(&self, new_period_in_ticks: impl ToTick, new_period_ticks: impl ToTick) -> OsalRsBool {
+        self.change_period(new_period_in_ticks.to_ticks(), new_period_ticks.to_ticks())
+    }
+
+    #[inline]
+    pub fn delete_with_to_tick(&mut self, ticks_to_wait: impl ToTick) -> OsalRsBool {
+        self.delete(ticks_to_wait.to_ticks())
+    }
+}
+
+impl TimerFn for Timer {
+    fn new<F>(name: &str, timer_period_in_ticks: TickType, auto_reload: bool, param: Option<TimerParam>, callback: F) -> Result<Self>
+    where
+        F: Fn(Box<dyn TimerFn>, Option<TimerParam>) -> Result<TimerParam> + Send + Sync + Clone + 'static {
+
+            if timer_period_in_ticks == 0 {
+                return Err(Error::Unhandled("Timer period must be greater than zero"));
+            }
+
+            if commands().is_none() {
+                return Err(Error::NullPtr);
+            }
+
+            let callback: Arc<TimerFnPtr> = Arc::new(callback);
+
+            let control = Box::new(TimerControl {
+                name: name.to_string(),
+                period: timer_period_in_ticks,
+                auto_reload,
+                callback: callback.clone(),
+                param: param.clone(),
+            });
+
+            Ok(Self {
+                handle: Box::into_raw(control) as TimerHandle,
+                name: name.to_string(),
+                callback: Some(callback),
+                param,
+                owned: true,
+            })
+    }
+
+    fn new_with_param<P, F>(name: &str, timer_period_in_ticks: TickType, auto_reload: bool, param: P, callback: F) -> Result<Self>
+    where
+        P: Send + Sync + 'static,
+        F: Fn(&P) + Send + Sync + 'static {
+
+            let param = Arc::new(param);
+            let callback = Arc::new(callback);
+            Self::new(name, timer_period_in_ticks, auto_reload, None, move |_timer, _param| {
+                callback(&param);
+                Ok(param.clone() as TimerParam)
+            })
+    }
+
+    fn start(&self, ticks_to_wait: TickType) -> OsalRsBool {
+        if self.handle.is_null() {
+            return OsalRsBool::False;
+        }
+        send_command(Command::Start(self.handle, System::get_tick_count()), ticks_to_wait)
+    }
+
+    fn stop(&self, ticks_to_wait: TickType)  -> OsalRsBool {
+        if self.handle.is_null() {
+            return OsalRsBool::False;
+        }
+        send_command(Command::Stop(self.handle), ticks_to_wait)
+    }
+
+    /// Restarts the period from now, starting the timer if it was dormant
+    fn reset(&self, ticks_to_wait: TickType) -> OsalRsBool {
+        self.start(ticks_to_wait)
+    }
+
+    /// Sets the new period and starts the timer if it was dormant
+    fn change_period(&self, new_period_in_ticks: TickType, new_period_ticks: TickType) -> OsalRsBool {
+        if self.handle.is_null() || new_period_in_ticks == 0 {
+            return OsalRsBool::False;
+        }
+        send_command(Command::ChangePeriod(self.handle, new_period_in_ticks, System::get_tick_count()), new_period_ticks)
+    }
+
+    /// The timer is released by the service thread once the command is processed
+    fn delete(&mut self, ticks_to_wait: TickType) -> OsalRsBool {
+        if self.handle.is_null() {
+            return OsalRsBool::False;
+        }
+
+        // A timer whose command could not be queued is still running
+        let ret = send_command(Command::Delete(self.handle), ticks_to_wait);
+        if ret == OsalRsBool::True {
+            self.handle = null_mut();
+        }
+        ret
+    }
+}
+
+/// Clones share the timer but never delete it, only the instance returned by
+/// `new` does, so a clone must not be used once that instance is dropped
+impl Clone for Timer {
+    fn clone(&self) -> Self {
+        Self {
+            handle: self.handle,
+            name: self.name.clone(),
+            callback: self.callback.clone(),
+            param: self.param.clone(),
+            owned: false,
+        }
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        if self.owned {
+            self.delete(0);
+        }
+    }
+}
+
+impl Deref for Timer {
+    type Target = TimerHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl Debug for Timer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Timer")
+            .field("handle", &self.handle)
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl Display for Timer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Timer {{ name: {}, handle: {:?} }}", self.name, self.handle)
+    }
+}
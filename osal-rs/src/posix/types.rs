@@ -0,0 +1,29 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+// Same widths FreeRTOS uses on 32-bit ports, so application code behaves
+// identically (including tick wrap-around) on both backends.
+pub type TickType = u32;
+pub type UBaseType = u32;
+pub type BaseType = i32;
+pub type StackType = i32;
+
+pub use super::ffi::{ThreadHandle, QueueHandle, SemaphoreHandle, EventGroupHandle, TimerHandle, MutexHandle, QueueSetHandle, QueueSetMemberHandle, StreamBufferHandle, MessageBufferHandle};
+
+pub type EventBits = TickType;
@@ -0,0 +1,284 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+//! Deterministic virtual clock, enabled by the `virtual_time` feature.
+//!
+//! The tick count, every timeout, `System::delay` and the timer service run on
+//! a virtual clock instead of `CLOCK_MONOTONIC`. The clock never moves while a
+//! thread can run: once every thread is blocked it jumps straight to the
+//! earliest pending timeout, so timing tests take no real time and always see
+//! the same tick counts. `VirtualTime::advance` moves it explicitly.
+//!
+//! Every `Monitor` shares a single lock in this mode, which lets the clock
+//! account for blocked threads exactly: a thread woken by a notification is
+//! counted as runnable by the notifier, before it gets to run. Threads blocked
+//! outside osal-rs (e.g. on a pipe or a libc lock) are seen as running.
+//!
+//! Suspend requests are held back while a thread holds the lock, so that a
+//! thread is only ever suspended while it is running: a thread suspended while
+//! blocked stops as soon as it wakes up.
+
+use core::ffi::c_void;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicIsize, AtomicU64, Ordering};
+
+use alloc::collections::BTreeMap;
+
+use super::ffi::{
+    CLOCK_REALTIME, NSEC_PER_SEC, PTHREAD_ONCE_INIT, clock_gettime, pthread_cond_broadcast, pthread_cond_signal, pthread_cond_t, pthread_cond_timedwait, pthread_cond_wait, pthread_create, pthread_detach, pthread_getspecific, pthread_key_create, pthread_key_t, pthread_mutex_lock, pthread_mutex_t, pthread_mutex_unlock, pthread_once, pthread_once_t, pthread_setspecific, pthread_t, time_t, timespec
+};
+use super::thread::{hold_suspend, release_suspend};
+use crate::traits::ToTick;
+
+/// Whether the backend runs on the virtual clock
+pub(crate) const ENABLED: bool = cfg!(feature = "virtual_time");
+
+/// Real time the clock thread sleeps between two checks, to catch threads
+/// that block without taking the lock (i.e. suspended threads)
+const POLL_NS: u64 = 1_000_000;
+
+struct Clock {
+    /// Threads blocked in `wait` since the last broadcast
+    waiting: isize,
+    /// Bumped by every broadcast, tells notified waiters from spurious wake-ups
+    generation: u64,
+    /// Deadlines of the blocked threads, with their multiplicity
+    deadlines: BTreeMap<u64, usize>,
+}
+
+static mut ONCE: pthread_once_t = PTHREAD_ONCE_INIT;
+
+/// Lock shared by every `Monitor`, it also protects `CLOCK`
+static mut LOCK: pthread_mutex_t = pthread_mutex_t::new();
+
+/// Signalled on every broadcast, waited on by the blocked threads
+static mut WAKE: pthread_cond_t = pthread_cond_t::new();
+
+/// Signalled when the last runnable thread blocks
+static mut IDLE: pthread_cond_t = pthread_cond_t::new();
+
+static mut CLOCK: Clock = Clock {
+    waiting: 0,
+    generation: 0,
+    deadlines: BTreeMap::new(),
+};
+
+/// Marks the threads already counted in `RUNNABLE`
+static mut PARTICIPANT_KEY: pthread_key_t = 0;
+
+/// Current virtual tick, only written with `LOCK` held
+static NOW: AtomicU64 = AtomicU64::new(0);
+
+/// Threads using osal-rs that are not blocked
+static RUNNABLE: AtomicIsize = AtomicIsize::new(0);
+
+extern "C" fn init_once() {
+    let mut pthread: pthread_t = 0;
+    unsafe {
+        pthread_key_create(&raw mut PARTICIPANT_KEY, Some(leave));
+        if pthread_create(&mut pthread, core::ptr::null(), clock_thread, null_mut()) == 0 {
+            pthread_detach(pthread);
+        }
+    }
+}
+
+fn init() {
+    unsafe { pthread_once(&raw mut ONCE, init_once); }
+}
+
+/// Clock state, `LOCK` must be held
+unsafe fn clock() -> &'static mut Clock {
+    let clock = &raw mut CLOCK;
+    unsafe { &mut *clock }
+}
+
+unsafe extern "C" fn leave(_value: *mut c_void) {
+    if RUNNABLE.fetch_sub(1, Ordering::SeqCst) == 1 {
+        unsafe { pthread_cond_signal(&raw mut IDLE); }
+    }
+}
+
+/// Counts the calling thread as runnable on its first call
+fn enter() {
+    unsafe {
+        if pthread_getspecific(PARTICIPANT_KEY).is_null() {
+            RUNNABLE.fetch_add(1, Ordering::SeqCst);
+            pthread_setspecific(PARTICIPANT_KEY, core::ptr::dangling::<c_void>());
+        }
+    }
+}
+
+/// Advances the clock to the earliest deadline whenever no thread can run
+extern "C" fn clock_thread(_arg: *mut c_void) -> *mut c_void {
+    unsafe {
+        pthread_mutex_lock(&raw mut LOCK);
+        loop {
+            let next = clock().deadlines.first_key_value().map(|(deadline, _)| *deadline);
+            match next {
+                Some(deadline) if RUNNABLE.load(Ordering::SeqCst) <= 0 => {
+                    NOW.fetch_max(deadline, Ordering::SeqCst);
+                    wake_all();
+                }
+                _ => {
+                    let mut timeout = timespec::default();
+                    clock_gettime(CLOCK_REALTIME, &mut timeout);
+                    let nsec = timeout.tv_nsec as u64 + POLL_NS;
+                    timeout.tv_sec += (nsec / NSEC_PER_SEC as u64) as time_t;
+                    timeout.tv_nsec = (nsec % NSEC_PER_SEC as u64) as _;
+                    pthread_cond_timedwait(&raw mut IDLE, &raw mut LOCK, &timeout);
+                }
+            }
+        }
+    }
+}
+
+/// Takes the lock shared by every `Monitor`
+pub(crate) fn lock() {
+    init();
+    enter();
+    hold_suspend();
+    unsafe { pthread_mutex_lock(&raw mut LOCK); }
+}
+
+pub(crate) fn unlock() {
+    unsafe { pthread_mutex_unlock(&raw mut LOCK); }
+    release_suspend();
+}
+
+/// Current virtual tick, without wrapping to `TickType`
+#[inline]
+pub(crate) fn now() -> u64 {
+    NOW.load(Ordering::SeqCst)
+}
+
+/// Wakes every blocked thread, counting them as runnable right away so that
+/// the clock cannot move before they had a chance to run. `LOCK` must be held.
+pub(crate) fn wake_all() {
+    unsafe {
+        let clock = clock();
+        RUNNABLE.fetch_add(clock.waiting, Ordering::SeqCst);
+        clock.waiting = 0;
+        clock.generation = clock.generation.wrapping_add(1);
+        pthread_cond_broadcast(&raw mut WAKE);
+    }
+}
+
+/// Blocks until the next broadcast, which happens at the latest when the
+/// clock reaches `deadline`. `LOCK` must be held.
+pub(crate) fn wait(deadline: Option<u64>) {
+    unsafe {
+        let clock = clock();
+        if let Some(deadline) = deadline {
+            *clock.deadlines.entry(deadline).or_insert(0) += 1;
+        }
+
+        clock.waiting += 1;
+        let generation = clock.generation;
+        if RUNNABLE.fetch_sub(1, Ordering::SeqCst) == 1 {
+            pthread_cond_signal(&raw mut IDLE);
+        }
+
+        pthread_cond_wait(&raw mut WAKE, &raw mut LOCK);
+
+        let clock = self::clock();
+        if clock.generation == generation {
+            // Spurious wake-up: nobody counted this thread as runnable
+            clock.waiting -= 1;
+            RUNNABLE.fetch_add(1, Ordering::SeqCst);
+        }
+
+        if let Some(deadline) = deadline
+            && let Some(count) = clock.deadlines.get_mut(&deadline)
+        {
+            *count -= 1;
+            if *count == 0 {
+                clock.deadlines.remove(&deadline);
+            }
+        }
+    }
+}
+
+/// Blocks the calling thread until the clock reaches `tick`
+pub(crate) fn sleep_until(tick: u64) {
+    lock();
+    while now() < tick {
+        wait(Some(tick));
+    }
+    unlock();
+}
+
+/// Counts a thread about to be created as runnable, so that the clock does not
+/// move before it starts. The creating thread is counted as well.
+pub(crate) fn thread_created() {
+    if ENABLED {
+        init();
+        enter();
+        RUNNABLE.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Undoes `thread_created` when the thread could not be created
+pub(crate) fn thread_not_created() {
+    if ENABLED {
+        leave_runnable();
+    }
+}
+
+/// Called first thing by a thread counted by `thread_created`
+pub(crate) fn thread_started() {
+    if ENABLED {
+        unsafe { pthread_setspecific(PARTICIPANT_KEY, core::ptr::dangling::<c_void>()); }
+    }
+}
+
+/// The calling thread blocks outside of a `Monitor` (it is being suspended).
+/// Only touches atomics, so it is safe in a signal handler.
+pub(crate) fn leave_runnable() {
+    if ENABLED {
+        RUNNABLE.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Undoes `leave_runnable`, possibly on behalf of the blocked thread
+pub(crate) fn enter_runnable() {
+    if ENABLED {
+        RUNNABLE.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Handle on the virtual clock, available with the `virtual_time` feature.
+pub struct VirtualTime;
+
+impl VirtualTime {
+    /// Ticks elapsed on the virtual clock, without wrapping to `TickType`
+    pub fn now() -> u64 {
+        now()
+    }
+
+    /// Moves the clock forward by `ticks`, even if some threads can still run.
+    ///
+    /// Every thread whose timeout expires on the way is woken at once: to let
+    /// them run in deadline order, block the calling thread with
+    /// `System::delay` instead.
+    pub fn advance(ticks: impl ToTick) {
+        lock();
+        NOW.fetch_add(ticks.to_ticks() as u64, Ordering::SeqCst);
+        wake_all();
+        unlock();
+    }
+}
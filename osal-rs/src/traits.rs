@@ -19,20 +19,26 @@
 
 mod byte;
 mod event_group;
+mod message_buffer;
 mod mutex;
 mod queue;
+mod queue_set;
 mod semaphore;
+mod stream_buffer;
 mod system;
 mod thread;
 mod tick;
 mod timer;
 
 pub use crate::traits::byte::*;
-pub use crate::traits::event_group::EventGroup as EventGroupFn;
-pub use crate::traits::mutex::{Mutex as MutexFn, MutexGuard as MutexGuardFn, RawMutex as RawMutexFn};
+pub use crate::traits::event_group::{EventGroup as EventGroupFn, EventWaitOptions};
+pub use crate::traits::message_buffer::{MessageBuffer as MessageBufferFn, MessageBufferStreamed as MessageBufferStreamedFn};
+pub use crate::traits::mutex::{Mutex as MutexFn, MutexGuard as MutexGuardFn, RawMutex as RawMutexFn, RecursiveMutex as RecursiveMutexFn};
 pub use crate::traits::queue::{Queue as QueueFn, QueueStreamed as QueueStreamedFn};
-pub use crate::traits::semaphore::Semaphore as SemaphoreFn;
+pub use crate::traits::queue_set::{QueueSet as QueueSetFn, QueueSetMember};
+pub use crate::traits::semaphore::{BinarySemaphore as BinarySemaphoreFn, Semaphore as SemaphoreFn};
+pub use crate::traits::stream_buffer::StreamBuffer as StreamBufferFn;
 pub use crate::traits::system::System as SystemFn;
-pub use crate::traits::thread::{Thread as ThreadFn, ThreadParam, ThreadFnPtr, ThreadSimpleFnPtr, ThreadNotification, ToPriority};
+pub use crate::traits::thread::{JoinHandle as JoinHandleFn, Thread as ThreadFn, ThreadParam, ThreadFnPtr, ThreadSimpleFnPtr, ThreadNotification, ToPriority};
 pub use crate::traits::tick::*;
 pub use crate::traits::timer::{Timer as TimerFn, TimerParam, TimerFnPtr};
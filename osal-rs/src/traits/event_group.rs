@@ -19,6 +19,37 @@
 
 use crate::utils::Result;
 use crate::os::types::{EventBits, TickType};
+use super::ToTick;
+
+/// Condition of `EventGroup::wait_bits`: any or all the bits of the mask, and
+/// whether they are cleared when the condition is met
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct EventWaitOptions {
+    pub wait_for_all: bool,
+    pub clear_on_exit: bool,
+}
+
+impl EventWaitOptions {
+    /// Released by any bit of the mask, nothing is cleared
+    pub const ANY: Self = Self { wait_for_all: false, clear_on_exit: false };
+
+    /// Released once every bit of the mask is set, nothing is cleared
+    pub const ALL: Self = Self { wait_for_all: true, clear_on_exit: false };
+
+    /// Same condition, the bits of the mask are cleared atomically on release
+    pub const fn clear_on_exit(self) -> Self {
+        Self { clear_on_exit: true, ..self }
+    }
+
+    /// Whether `bits` meet the condition for `mask`
+    pub const fn is_met(&self, mask: EventBits, bits: EventBits) -> bool {
+        if self.wait_for_all {
+            bits & mask == mask
+        } else {
+            bits & mask != 0
+        }
+    }
+}
 
 pub trait EventGroup {
     fn new() -> Result<Self> 
@@ -37,7 +68,23 @@ pub trait EventGroup {
     
     fn clear_from_isr(&self, bits: EventBits) -> Result<()>;
 
+    /// Waits for any bit of `mask` without clearing it, returns the bits as
+    /// they were on release or timeout
     fn wait(&self, mask: EventBits, timeout_ticks: TickType) -> EventBits;
 
+    /// Waits until the bits of `mask` meet `options`.
+    ///
+    /// Returns the bits as they were when the condition was met, before any
+    /// clear on exit, or `Error::Timeout`
+    fn wait_bits(&self, mask: EventBits, options: EventWaitOptions, timeout: impl ToTick) -> Result<EventBits>;
+
+    /// Rendezvous: sets `set_bits` then waits for all of `wait_bits` as one
+    /// atomic step, the bits are cleared once every party arrived.
+    ///
+    /// Returns the bits as they were on release or `Error::Timeout`. Each
+    /// party sets its own bit, so a group of threads meets with:
+    /// `group.sync(MY_BIT, ALL_BITS, timeout)`
+    fn sync(&self, set_bits: EventBits, wait_bits: EventBits, timeout: impl ToTick) -> Result<EventBits>;
+
     fn delete(&mut self);
 }
\ No newline at end of file
@@ -0,0 +1,77 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use crate::os::{FromBytes, ToBytes};
+use crate::utils::Result;
+use super::ToTick;
+
+/// Variable-length messages from one writer to one reader.
+///
+/// Each message is stored after its length, `size_of::<usize>()` bytes, and
+/// is always sent and received whole.
+pub trait MessageBuffer {
+    /// `size` is the room for the messages and their length prefixes
+    fn new(size: usize) -> Result<Self>
+    where 
+        Self: Sized;
+
+    /// Waits for room for the whole of `message`, fails with
+    /// `Error::Timeout` if it was not sent
+    fn send(&self, message: &[u8], ticks_to_wait: impl ToTick) -> Result<()>;
+
+    fn send_from_isr(&self, message: &[u8]) -> Result<()>;
+
+    /// Copies the oldest message into `buffer` and returns its length. A
+    /// message longer than `buffer` is left in place and reported as an error
+    fn receive(&self, buffer: &mut [u8], ticks_to_wait: impl ToTick) -> Result<usize>;
+
+    fn receive_from_isr(&self, buffer: &mut [u8]) -> Result<usize>;
+
+    /// Length of the oldest message, 0 if the buffer is empty
+    fn next_length(&self) -> usize;
+
+    fn spaces_available(&self) -> usize;
+
+    /// Discards every message, fails while a thread is blocked on it
+    fn reset(&self) -> Result<()>;
+
+    fn delete(&mut self);
+}
+
+/// `MessageBuffer` of `T`, each message holding the bytes of one value
+pub trait MessageBufferStreamed<T> 
+where 
+    T: ToBytes + FromBytes {
+
+    fn new(size: usize) -> Result<Self>
+    where 
+        Self: Sized;
+
+    fn send(&self, item: &T, ticks_to_wait: impl ToTick) -> Result<()>;
+
+    fn send_from_isr(&self, item: &T) -> Result<()>;
+
+    fn receive(&self, ticks_to_wait: impl ToTick) -> Result<T>;
+
+    fn receive_from_isr(&self) -> Result<T>;
+
+    fn reset(&self) -> Result<()>;
+
+    fn delete(&mut self);
+}
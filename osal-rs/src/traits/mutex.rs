@@ -17,8 +17,13 @@
  *
  ***************************************************************************/
 
+use core::ops::{Deref, DerefMut};
+
 use crate::utils::{OsalRsBool, Result};
 
+/// Lock owned by the thread that takes it, so that its priority can be
+/// inherited. Mutexes cannot be used from an ISR: signal a
+/// `BinarySemaphore` there instead
 pub trait RawMutex
 where
     Self: Sized,
@@ -29,20 +34,21 @@ where
 
     fn lock(&self) -> OsalRsBool;
 
-    fn lock_from_isr(&self) -> OsalRsBool;
+    /// Never blocks: fails if the mutex is held
+    fn try_lock(&self) -> OsalRsBool;
 
     fn unlock(&self) -> OsalRsBool;
 
-    fn unlock_from_isr(&self) -> OsalRsBool;
-
     fn delete(&mut self);
 }
 
-pub trait MutexGuard<'a, T: ?Sized + 'a> {}
+/// Gives access to the data while the mutex is held, released on drop
+pub trait MutexGuard<'a, T: ?Sized + 'a>: Deref<Target = T> + DerefMut {}
 
+/// Non-recursive mutex with priority inheritance: a thread must not lock a
+/// mutex it already holds
 pub trait Mutex<T: ?Sized> {
     type Guard<'a>: MutexGuard<'a, T> where Self: 'a, T: 'a;
-    type GuardFromIsr<'a>: MutexGuard<'a, T> where Self: 'a, T: 'a;
 
     /// Creates a new mutex wrapping the supplied data
     fn new(data: T) -> Self
@@ -53,8 +59,8 @@ pub trait Mutex<T: ?Sized> {
     /// Acquires the mutex, blocking the current thread until it is able to do so
     fn lock(&self) -> Result<Self::Guard<'_>>;
     
-    /// Acquires the mutex from ISR context
-    fn lock_from_isr(&self) -> Result<Self::GuardFromIsr<'_>>;
+    /// Acquires the mutex if it is free, never blocks
+    fn try_lock(&self) -> Result<Self::Guard<'_>>;
 
     /// Attempts to consume this mutex, returning the underlying data
     fn into_inner(self) -> Result<T> 
@@ -65,3 +71,28 @@ pub trait Mutex<T: ?Sized> {
     /// Returns a mutable reference to the underlying data
     fn get_mut(&mut self) -> &mut T;
 }
+
+/// Mutex with priority inheritance that the holding thread can lock again,
+/// it is released when every guard is dropped. As the guards can coexist
+/// they only give shared access: use a `Cell` or `RefCell` to modify the data
+pub trait RecursiveMutex<T: ?Sized> {
+    type Guard<'a>: Deref<Target = T> where Self: 'a, T: 'a;
+
+    fn new(data: T) -> Self
+    where 
+        Self: Sized,
+        T: Sized;
+
+    fn lock(&self) -> Result<Self::Guard<'_>>;
+
+    /// Acquires the mutex if it is free or held by the calling thread, never
+    /// blocks
+    fn try_lock(&self) -> Result<Self::Guard<'_>>;
+
+    fn into_inner(self) -> Result<T> 
+    where 
+        Self: Sized, 
+        T: Sized;
+
+    fn get_mut(&mut self) -> &mut T;
+}
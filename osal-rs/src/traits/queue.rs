@@ -35,6 +35,22 @@ pub trait Queue {
     fn post(&self, item: &[u8], time: TickType) -> Result<()>;
     fn post_from_isr(&self, item: &[u8]) -> Result<()>;
 
+    /// Posts `item` ahead of the messages already waiting
+    fn post_to_front(&self, item: &[u8], time: TickType) -> Result<()>;
+    fn post_to_front_from_isr(&self, item: &[u8]) -> Result<()>;
+
+    /// Mailbox write for queues of length 1: never blocks, replaces the
+    /// message of a full queue
+    fn overwrite(&self, item: &[u8]) -> Result<()>;
+    fn overwrite_from_isr(&self, item: &[u8]) -> Result<()>;
+
+    /// Copies the oldest message into `buffer` without removing it
+    fn peek(&self, buffer: &mut [u8], time: TickType) -> Result<()>;
+    fn peek_from_isr(&self, buffer: &mut [u8]) -> Result<()>;
+
+    /// Discards every waiting message
+    fn reset(&self) -> Result<()>;
+
     fn delete(&mut self);
 }
 
@@ -54,5 +70,19 @@ where
 
     fn post_from_isr(&self, item: &T) -> Result<()>;
 
+    fn post_to_front(&self, item: &T, time: TickType) -> Result<()>;
+
+    fn post_to_front_from_isr(&self, item: &T) -> Result<()>;
+
+    fn overwrite(&self, item: &T) -> Result<()>;
+
+    fn overwrite_from_isr(&self, item: &T) -> Result<()>;
+
+    fn peek(&self, buffer: &mut T, time: TickType) -> Result<()>;
+
+    fn peek_from_isr(&self, buffer: &mut T) -> Result<()>;
+
+    fn reset(&self) -> Result<()>;
+
     fn delete(&mut self);
 }
\ No newline at end of file
@@ -0,0 +1,50 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use crate::os::types::{QueueSetMemberHandle, TickType, UBaseType};
+use crate::utils::Result;
+
+/// Object that can be added to a `QueueSet`: queues and semaphores
+pub trait QueueSetMember {
+    /// Handle returned by `QueueSet::select` when the member is ready
+    fn member_handle(&self) -> QueueSetMemberHandle;
+}
+
+/// Lets one thread block on several queues and semaphores at once.
+///
+/// `select` returns the member holding an item, which is then fetched (or
+/// taken) with a timeout of 0. Members must be empty when added and must not
+/// be read without going through the set.
+pub trait QueueSet {
+    /// `size` is the total number of items the members can hold together:
+    /// the sum of the queue sizes and the semaphore maximum counts
+    fn new(size: UBaseType) -> Result<Self>
+    where 
+        Self: Sized;
+
+    fn add(&self, member: &impl QueueSetMember) -> Result<()>;
+
+    fn remove(&self, member: &impl QueueSetMember) -> Result<()>;
+
+    fn select(&self, time: TickType) -> Result<QueueSetMemberHandle>;
+
+    fn select_from_isr(&self) -> Result<QueueSetMemberHandle>;
+
+    fn delete(&mut self);
+}
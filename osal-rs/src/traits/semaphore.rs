@@ -44,3 +44,23 @@ pub trait Semaphore {
     fn delete(&mut self);
 
 }
+
+/// Semaphore holding at most one token, to signal events between threads
+/// and from ISRs. Unlike a `Mutex` it has no owner, so no priority inheritance
+pub trait BinarySemaphore {
+    /// Created empty: the first `wait` blocks until a `signal`
+    fn new() -> Result<Self>
+    where 
+        Self: Sized;
+
+    fn wait(&self, ticks_to_wait: impl ToTick) -> OsalRsBool;
+
+    fn wait_from_isr(&self) -> OsalRsBool;
+
+    /// Fails if the token is already available
+    fn signal(&self) -> OsalRsBool;
+
+    fn signal_from_isr(&self) -> OsalRsBool;
+
+    fn delete(&mut self);
+}
@@ -0,0 +1,57 @@
+/***************************************************************************
+ *
+ * osal-rs
+ * Copyright (C) 2023/2026 Antonio Salsi <passy.linux@zresa.it>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ *
+ ***************************************************************************/
+
+use crate::utils::Result;
+use super::ToTick;
+
+/// Byte stream from one writer to one reader, for UARTs and parsers.
+///
+/// Unlike a queue, data has no message boundaries: any number of bytes is
+/// written and read at a time.
+pub trait StreamBuffer {
+    /// A `receive` blocked on an empty buffer returns once `trigger_level`
+    /// bytes are available, 0 counts as 1
+    fn new(size: usize, trigger_level: usize) -> Result<Self>
+    where 
+        Self: Sized;
+
+    /// Waits for room for the whole of `data`, returns the number of bytes
+    /// written: less than `data.len()` on timeout
+    fn send(&self, data: &[u8], ticks_to_wait: impl ToTick) -> usize;
+
+    fn send_from_isr(&self, data: &[u8]) -> usize;
+
+    /// Waits for data if the buffer is empty, returns the number of bytes
+    /// copied into `buffer`: 0 on timeout
+    fn receive(&self, buffer: &mut [u8], ticks_to_wait: impl ToTick) -> usize;
+
+    fn receive_from_isr(&self, buffer: &mut [u8]) -> usize;
+
+    fn bytes_available(&self) -> usize;
+
+    fn spaces_available(&self) -> usize;
+
+    /// Fails if `trigger_level` is larger than the buffer
+    fn set_trigger_level(&self, trigger_level: usize) -> Result<()>;
+
+    /// Discards the buffered data, fails while a thread is blocked on it
+    fn reset(&self) -> Result<()>;
+
+    fn delete(&mut self);
+}
@@ -32,7 +32,7 @@ pub trait System {
     fn stop();
     fn get_tick_count() -> TickType;
     fn get_current_time_us () -> Duration;
-    fn get_us_from_tick(duration: &Duration) -> TickType;
+    fn get_ticks_from_duration(duration: &Duration) -> TickType;
     fn count_threads() -> usize;
     fn get_all_thread() -> SystemState;
     fn delay(ticks: TickType);
@@ -71,12 +71,41 @@ pub trait Thread {
         F: Fn() + Send + Sync + 'static,
         Self: Sized;
 
+    /// Spawns a thread running `callback` once, what it returns is collected
+    /// through the `JoinHandle`
+    fn spawn_joinable<F, R>(&mut self, callback: F) -> Result<crate::os::JoinHandle<R>>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+        Self: Sized;
+
+    /// `spawn_joinable` that moves `param` into the callback, with its type
+    /// checked at compile time instead of downcasting a `ThreadParam`
+    fn spawn_with_param<P, F, R>(&mut self, param: P, callback: F) -> Result<crate::os::JoinHandle<R>>
+    where
+        P: Send + 'static,
+        F: FnOnce(P) -> R + Send + 'static,
+        R: Send + 'static,
+        Self: Sized;
+
     fn delete(&self);
 
     fn suspend(&self);
 
     fn resume(&self);
 
+    /// Blocks until the callback of the thread has returned, then returns
+    /// `Ok(0)`.
+    ///
+    /// The wait cannot time out (`MAX_DELAY` on FreeRTOS), `JoinHandle::join`
+    /// takes a timeout. Joining does not delete the thread: it ends by itself
+    /// once its callback returns. A thread removed with `delete` is not
+    /// waited for, the call fails at once.
+    ///
+    /// The value returned by the callback is not collected here: `retval` is
+    /// left untouched and the `Result<ThreadParam>` of `spawn` is dropped.
+    /// Threads whose result is needed are spawned with `spawn_joinable` or
+    /// `spawn_with_param`, and joined through their `JoinHandle`.
     fn join(&self, retval: DoublePtr) -> Result<i32>;
 
     fn get_metadata(&self) -> ThreadMetadata;
@@ -91,7 +120,49 @@ pub trait Thread {
 
     fn wait_notification(&self, bits_to_clear_on_entry: u32, bits_to_clear_on_exit: u32 , timeout_ticks: TickType) -> Result<u32>; //no ToTick here to maintain dynamic dispatch
 
+    /// `notify` on entry `index` of the notification array, below
+    /// `task_notification_array_entries!()`. Each entry is an independent
+    /// channel: waiting on one never consumes another
+    fn notify_indexed(&self, index: UBaseType, notification: ThreadNotification) -> Result<()>;
+
+    fn notify_indexed_from_isr(&self, index: UBaseType, notification: ThreadNotification, higher_priority_task_woken: &mut BaseType) -> Result<()>;
+
+    /// `notify_indexed` that returns the value the entry had before
+    fn notify_and_query(&self, index: UBaseType, notification: ThreadNotification) -> Result<u32>;
+
+    /// `wait_notification` on entry `index` of the calling thread
+    fn wait_notification_indexed(&self, index: UBaseType, bits_to_clear_on_entry: u32, bits_to_clear_on_exit: u32, timeout_ticks: TickType) -> Result<u32>;
+
+    /// Increments entry `index`: the give of a counting semaphore without a
+    /// kernel object, taken by the thread with `notify_take`
+    fn notify_give(&self, index: UBaseType) -> Result<()>;
+
+    fn notify_give_from_isr(&self, index: UBaseType, higher_priority_task_woken: &mut BaseType) -> Result<()>;
+
+    /// Waits until entry `index` of the calling thread is not zero, then
+    /// decrements it, or clears it if `clear_on_exit` (binary semaphore).
+    ///
+    /// Returns the count before it was taken, or `Error::Timeout`
+    fn notify_take(&self, index: UBaseType, clear_on_exit: bool, timeout_ticks: TickType) -> Result<u32>;
+
+    /// Discards a pending notification of entry `index` without changing its
+    /// value, returns whether one was pending
+    fn clear_state(&self, index: UBaseType) -> Result<bool>;
+
+
+}
 
+/// Owner of a thread spawned with `spawn_joinable`, yields the value
+/// returned by its callback
+pub trait JoinHandle<T> {
+    /// Waits for the callback to return, then takes its value.
+    ///
+    /// `Error::Timeout` leaves the handle joinable, a handle that was already
+    /// joined fails
+    fn join(&mut self, timeout_ticks: TickType) -> Result<T>;
+
+    /// Whether the callback has returned, never blocks
+    fn is_finished(&self) -> bool;
 }
 
 pub trait ToPriority {
@@ -34,6 +34,13 @@ pub trait Timer {
         Self: Sized,
         F: Fn(Box<dyn Timer>, Option<TimerParam>) -> Result<TimerParam> + Send + Sync + Clone + 'static;
 
+    /// `new` with a parameter of type `P`, lent to `callback` on every expiry
+    fn new_with_param<P, F>(name: &str, timer_period_in_ticks: TickType, auto_reload: bool, param: P, callback: F) -> Result<Self>
+    where
+        Self: Sized,
+        P: Send + Sync + 'static,
+        F: Fn(&P) + Send + Sync + 'static;
+
     fn start(&self, ticks_to_wait: TickType) -> OsalRsBool;
     fn stop(&self, ticks_to_wait: TickType)  -> OsalRsBool;
     fn reset(&self, ticks_to_wait: TickType) -> OsalRsBool;
@@ -43,6 +43,19 @@ pub enum Error {
     Unhandled(&'static str)
 }
 
+/// Lets the `embedded_io` implementations report the errors of the OSAL
+impl embedded_io::Error for Error {
+    fn kind(&self) -> embedded_io::ErrorKind {
+        match self {
+            Error::OutOfMemory => embedded_io::ErrorKind::OutOfMemory,
+            Error::Timeout | Error::QueueSendTimeout | Error::QueueReceiveTimeout | Error::MutexTimeout => embedded_io::ErrorKind::TimedOut,
+            Error::InvalidQueueSize | Error::OutOfIndex | Error::InvalidType => embedded_io::ErrorKind::InvalidInput,
+            Error::NotFound | Error::TaskNotFound => embedded_io::ErrorKind::NotFound,
+            _ => embedded_io::ErrorKind::Other,
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum CpuRegisterSize {
     Bit64,
@@ -123,6 +136,26 @@ macro_rules! thread_extract_param {
     };
 }
 
+/// Waits on a `QueueSet` and runs the arm of the member that became ready,
+/// or the `else` arm on timeout (and for members without an arm).
+///
+/// ```ignore
+/// select!(set, MAX_DELAY.to_ticks(), {
+///     commands => commands.fetch(&mut command, 0)?,
+///     stop => { stop.wait(Duration::ZERO); return Ok(()); },
+///     else => log_warning!(TAG, "No event"),
+/// });
+/// ```
+#[macro_export]
+macro_rules! select {
+    ($set:expr, $time:expr, { $($member:expr => $body:expr),+ , else => $timeout:expr $(,)? }) => {
+        match $crate::os::QueueSetFn::select(&$set, $time) {
+            $(Ok(handle) if handle == $crate::os::QueueSetMember::member_handle(&$member) => $body,)+
+            _ => $timeout,
+        }
+    };
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Bytes<const SIZE: usize = 0> (pub [u8; SIZE]);
 